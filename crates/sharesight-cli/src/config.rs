@@ -0,0 +1,186 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+use sharesight_types::DEFAULT_API_HOST;
+
+/// The profile used when none is given or set as the default.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// The profiles in the config file, for example:
+///
+/// ```toml
+/// default_profile = "personal"
+///
+/// [profiles.personal]
+/// host = "api.sharesight.com"
+/// credentials_file = "/home/me/.config/sharesight/personal.json"
+/// portfolio = "Retirement"
+/// ```
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Config {
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Profile {
+    /// The host of the API, `api.sharesight.com` when not set.
+    pub host: Option<String>,
+    /// The file the credentials are kept in, `<profile>.json` next to the config file when not
+    /// set.
+    pub credentials_file: Option<PathBuf>,
+    /// The id or name of the portfolio used by commands given no portfolio.
+    pub portfolio: Option<String>,
+}
+
+impl Profile {
+    pub fn host(&self) -> &str {
+        self.host.as_deref().unwrap_or(DEFAULT_API_HOST)
+    }
+}
+
+/// `sharesight/config.toml` in the user's config directory.
+pub fn default_path() -> anyhow::Result<PathBuf> {
+    Ok(dirs::config_dir()
+        .context("There is no config directory, pass --config")?
+        .join("sharesight")
+        .join("config.toml"))
+}
+
+impl Config {
+    /// The config in the file, or an empty config if there is no file.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => {
+                toml::from_str(&text).with_context(|| format!("Invalid {}", path.display()))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e).with_context(|| format!("Could not read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, toml::to_string_pretty(self)?)
+            .with_context(|| format!("Could not write {}", path.display()))
+    }
+
+    /// The name of the profile to use, given the `--profile` option.
+    pub fn profile_name<'a>(&'a self, name: Option<&'a str>) -> &'a str {
+        name.or(self.default_profile.as_deref())
+            .unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// The profile with the name. Only the default profile can be used without being in the
+    /// config file.
+    pub fn profile(&self, name: &str) -> anyhow::Result<Profile> {
+        match self.profiles.get(name) {
+            Some(profile) => Ok(profile.clone()),
+            None if name == DEFAULT_PROFILE => Ok(Profile::default()),
+            None => anyhow::bail!(
+                "Unknown profile: {}, add it with `sharesight profiles set {}`",
+                name,
+                name
+            ),
+        }
+    }
+}
+
+/// The file the credentials of the profile are kept in.
+pub fn credentials_file(config_path: &Path, name: &str, profile: &Profile) -> PathBuf {
+    match &profile.credentials_file {
+        Some(path) => path.clone(),
+        None => config_path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(format!("{}.json", name)),
+    }
+}
+
+#[cfg(test)]
+mod config_tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{credentials_file, Config, Profile, DEFAULT_PROFILE};
+
+    fn config() -> Config {
+        let mut config = Config {
+            default_profile: Some("personal".to_string()),
+            ..Config::default()
+        };
+        config.profiles.insert(
+            "personal".to_string(),
+            Profile {
+                host: Some("api.example.com".to_string()),
+                credentials_file: None,
+                portfolio: Some("Retirement".to_string()),
+            },
+        );
+
+        config
+    }
+
+    #[test]
+    fn profiles() {
+        let config = config();
+        assert_eq!("work", config.profile_name(Some("work")));
+        assert_eq!("personal", config.profile_name(None));
+        assert_eq!(DEFAULT_PROFILE, Config::default().profile_name(None));
+
+        assert_eq!(
+            "api.example.com",
+            config.profile("personal").unwrap().host()
+        );
+        assert_eq!(
+            "api.sharesight.com",
+            config.profile(DEFAULT_PROFILE).unwrap().host()
+        );
+        assert_eq!(
+            "Unknown profile: work, add it with `sharesight profiles set work`",
+            config.profile("work").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn credentials_files() {
+        let config_path = Path::new("/home/me/.config/sharesight/config.toml");
+        assert_eq!(
+            PathBuf::from("/home/me/.config/sharesight/personal.json"),
+            credentials_file(config_path, "personal", &Profile::default())
+        );
+
+        let profile = Profile {
+            credentials_file: Some(PathBuf::from("/secrets/sharesight.json")),
+            ..Profile::default()
+        };
+        assert_eq!(
+            PathBuf::from("/secrets/sharesight.json"),
+            credentials_file(config_path, "personal", &profile)
+        );
+    }
+
+    #[test]
+    fn load_and_save() {
+        let dir = std::env::temp_dir().join(format!("sharesight-config-{}", std::process::id()));
+        let path = dir.join("config.toml");
+
+        assert!(Config::load(&path).unwrap().profiles.is_empty());
+
+        config().save(&path).unwrap();
+        let loaded = Config::load(&path).unwrap();
+        assert_eq!(Some("personal".to_string()), loaded.default_profile);
+        assert_eq!(
+            Some("Retirement".to_string()),
+            loaded.profile("personal").unwrap().portfolio
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,525 @@
+use std::{
+    io::Write as _,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use chrono::NaiveDate;
+use clap::{Parser, Subcommand};
+use serde_json::Value;
+use sharesight_reqwest::{
+    fresh_auth, manifest_parameters, AuthorizationCodeFlow, Client, FileTokenStore, OAuthClient,
+    TokenStore,
+};
+use sharesight_types::{
+    ApiEndpoint, CashAccountTransactionsList, CashAccountTransactionsListParameters,
+    CashAccountsList, CashAccountsListParameters, EndpointManifest, ListPortfolioPayouts,
+    ListPortfolioPayoutsParameters, Performance, PerformanceParameters, PortfolioList, Trades,
+    TradesParameters, Valuation, ValuationParameters, API_ENDPOINTS,
+};
+
+use config::{Config, Profile};
+use output::Output;
+
+mod config;
+mod output;
+
+/// How long an access token must still be valid for, or it is refreshed before use.
+const MIN_TOKEN_VALIDITY: Duration = Duration::from_secs(60);
+
+/// Access the Sharesight API from the command line
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Cli {
+    /// The config file with the profiles.
+    #[clap(long, global = true, env = "SHARESIGHT_CONFIG")]
+    config: Option<PathBuf>,
+    /// The profile to use, the config's default profile when not given.
+    #[clap(long, short, global = true, env = "SHARESIGHT_PROFILE")]
+    profile: Option<String>,
+    /// How to print responses.
+    #[clap(long, short, global = true, value_enum, default_value_t = Output::Table)]
+    output: Output,
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    #[clap(flatten)]
+    Api(ApiCommand),
+    /// Call any endpoint of the API and print the response, or list the endpoints when none is
+    /// given.
+    Call(Call),
+    /// Authorize access to Sharesight.
+    #[clap(subcommand)]
+    Auth(Auth),
+    /// Show and change the profiles.
+    #[clap(subcommand)]
+    Profiles(Profiles),
+}
+
+/// The commands printing what the API returns.
+#[derive(Subcommand, Debug)]
+enum ApiCommand {
+    /// List the portfolios.
+    Portfolios,
+    /// List the trades of a portfolio.
+    Trades {
+        #[clap(flatten)]
+        portfolio: PortfolioArg,
+        #[clap(flatten)]
+        dates: DateRange,
+    },
+    /// List the payouts of a portfolio.
+    Payouts {
+        #[clap(flatten)]
+        portfolio: PortfolioArg,
+        #[clap(flatten)]
+        dates: DateRange,
+    },
+    /// List the cash accounts of a portfolio, or the transactions of one of them.
+    CashAccounts {
+        #[clap(flatten)]
+        portfolio: PortfolioArg,
+        /// The id or name of the cash account to list the transactions of.
+        cash_account: Option<String>,
+        #[clap(flatten)]
+        dates: DateRange,
+    },
+    /// Report on a portfolio.
+    #[clap(subcommand)]
+    Report(Report),
+}
+
+#[derive(clap::Args, Debug)]
+struct Call {
+    /// The name of the endpoint, such as `HoldingMergesUpdate`.
+    endpoint: Option<String>,
+    /// A parameter of the endpoint, such as `--param trade.quantity=10`.
+    #[clap(long = "param", short = 'P', value_parser = parse_param)]
+    params: Vec<(String, String)>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Report {
+    /// The performance of the holdings over a period.
+    Performance {
+        #[clap(flatten)]
+        portfolio: PortfolioArg,
+        #[clap(flatten)]
+        dates: DateRange,
+    },
+    /// The value of the holdings on a day, today when not given.
+    Valuation {
+        #[clap(flatten)]
+        portfolio: PortfolioArg,
+        #[clap(long)]
+        date: Option<NaiveDate>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum Auth {
+    /// Authorize with the authorization code flow in a browser and save the credentials.
+    Login {
+        #[clap(long, env = "SHARESIGHT_CLIENT_ID")]
+        client_id: String,
+        #[clap(long, env = "SHARESIGHT_CLIENT_SECRET")]
+        client_secret: String,
+        /// The port of the redirect URI, which must be a redirect URI of the API application.
+        #[clap(long, default_value_t = 8912)]
+        redirect_port: u16,
+    },
+    /// Refresh the access token now, instead of when it is about to expire.
+    Refresh,
+    /// Revoke the access token, deleting the saved credentials.
+    Revoke,
+    /// Show when the access token expires.
+    Status,
+}
+
+#[derive(Subcommand, Debug)]
+enum Profiles {
+    /// List the profiles.
+    List,
+    /// Add a profile, or change the settings given.
+    Set {
+        name: String,
+        #[clap(long)]
+        host: Option<String>,
+        #[clap(long)]
+        credentials_file: Option<PathBuf>,
+        /// The id or name of the portfolio used by commands given no portfolio.
+        #[clap(long)]
+        portfolio: Option<String>,
+        /// Use the profile when no profile is given.
+        #[clap(long)]
+        default: bool,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+struct PortfolioArg {
+    /// The id or name of the portfolio, the profile's portfolio when not given.
+    #[clap(long = "portfolio")]
+    name: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+struct DateRange {
+    #[clap(long)]
+    from: Option<NaiveDate>,
+    #[clap(long)]
+    to: Option<NaiveDate>,
+}
+
+/// What the commands using the API need.
+struct Context {
+    client: Client,
+    profile: Profile,
+    output: Output,
+}
+
+impl Context {
+    /// The context for the profile, refreshing its access token when it is about to expire.
+    async fn new(
+        config: &Config,
+        config_path: &Path,
+        name: &str,
+        output: Output,
+    ) -> anyhow::Result<Self> {
+        let (profile, store) = profile_store(config, config_path, name)?;
+        let auth = fresh_auth(&store, MIN_TOKEN_VALIDITY)
+            .await
+            .with_context(|| {
+                format!(
+                    "Not logged in, run `sharesight --profile {} auth login`",
+                    name
+                )
+            })?;
+
+        Ok(Context {
+            client: Client::new_with_token_and_host(auth.auth.access_token, auth.host),
+            profile,
+            output,
+        })
+    }
+
+    async fn portfolio_id(&self, portfolio: &PortfolioArg) -> anyhow::Result<i64> {
+        let query = portfolio
+            .name
+            .as_deref()
+            .or(self.profile.portfolio.as_deref())
+            .context("No portfolio given, pass --portfolio or set one in the profile")?;
+
+        Ok(self.client.resolve_portfolio(query).await?.id)
+    }
+
+    /// Execute the endpoint and print the rows under the key of the response.
+    async fn print<'a, T: ApiEndpoint<'a>>(
+        &'a self,
+        parameters: &'a T::Parameters,
+        rows: &str,
+    ) -> anyhow::Result<()> {
+        let response = self.client.execute::<T, Value>(parameters).await?;
+
+        output::print(self.output, &response, Some(rows))
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    if Err(std::env::VarError::NotPresent) == std::env::var("RUST_LOG") {
+        std::env::set_var("RUST_LOG", "warn");
+    }
+    env_logger::init();
+
+    let cli = Cli::parse();
+    let config_path = match cli.config {
+        Some(path) => path,
+        None => config::default_path()?,
+    };
+    let mut config = Config::load(&config_path)?;
+
+    let name = config.profile_name(cli.profile.as_deref()).to_string();
+
+    match cli.command {
+        Command::Profiles(command) => profiles(command, &mut config, &config_path),
+        Command::Auth(command) => {
+            let (profile, store) = profile_store(&config, &config_path, &name)?;
+
+            auth(command, &store, &profile).await
+        }
+        Command::Call(Call { endpoint: None, .. }) => {
+            for endpoint in API_ENDPOINTS {
+                println!(
+                    "{:<40} {:?} {}",
+                    endpoint.name, endpoint.http_method, endpoint.url_path
+                );
+            }
+
+            Ok(())
+        }
+        Command::Call(Call {
+            endpoint: Some(endpoint),
+            params,
+        }) => {
+            let ctx = Context::new(&config, &config_path, &name, cli.output).await?;
+
+            ctx.call(&endpoint, &params).await
+        }
+        Command::Api(command) => {
+            let ctx = Context::new(&config, &config_path, &name, cli.output).await?;
+
+            ctx.run(command).await
+        }
+    }
+}
+
+impl Context {
+    async fn run(&self, command: ApiCommand) -> anyhow::Result<()> {
+        match command {
+            ApiCommand::Portfolios => self.print::<PortfolioList>(&(), "portfolios").await,
+            ApiCommand::Trades { portfolio, dates } => {
+                let parameters = TradesParameters {
+                    portfolio_id: self.portfolio_id(&portfolio).await?.to_string(),
+                    start_date: dates.from,
+                    end_date: dates.to,
+                    unique_identifier: None,
+                };
+
+                self.print::<Trades>(&parameters, "trades").await
+            }
+            ApiCommand::Payouts { portfolio, dates } => {
+                let parameters = ListPortfolioPayoutsParameters {
+                    portfolio_id: self.portfolio_id(&portfolio).await?,
+                    start_date: dates.from,
+                    end_date: dates.to,
+                    use_date: None,
+                };
+
+                self.print::<ListPortfolioPayouts>(&parameters, "payouts")
+                    .await
+            }
+            ApiCommand::CashAccounts {
+                portfolio,
+                cash_account: None,
+                ..
+            } => {
+                let portfolio_id = self.portfolio_id(&portfolio).await?;
+                let mut response = self
+                    .client
+                    .execute::<CashAccountsList, Value>(&CashAccountsListParameters { date: None })
+                    .await?;
+                if let Some(Value::Array(cash_accounts)) = response.get_mut("cash_accounts") {
+                    cash_accounts.retain(|a| a["portfolio_id"] == portfolio_id);
+                }
+
+                output::print(self.output, &response, Some("cash_accounts"))
+            }
+            ApiCommand::CashAccounts {
+                portfolio,
+                cash_account: Some(cash_account),
+                dates,
+            } => {
+                let portfolio_id = self.portfolio_id(&portfolio).await?;
+                let parameters = CashAccountTransactionsListParameters {
+                    cash_account_id: self
+                        .client
+                        .resolve_cash_account(portfolio_id, &cash_account)
+                        .await?
+                        .id,
+                    from: dates.from,
+                    to: dates.to,
+                    description: None,
+                    foreign_identifier: None,
+                };
+
+                self.print::<CashAccountTransactionsList>(&parameters, "cash_account_transactions")
+                    .await
+            }
+            ApiCommand::Report(Report::Performance { portfolio, dates }) => {
+                let parameters = PerformanceParameters {
+                    start_date: dates.from,
+                    end_date: dates.to,
+                    portfolio_id: self.portfolio_id(&portfolio).await?,
+                    consolidated: None,
+                    include_sales: None,
+                    grouping: None,
+                    custom_group_id: None,
+                };
+
+                self.print::<Performance>(&parameters, "holdings").await
+            }
+            ApiCommand::Report(Report::Valuation { portfolio, date }) => {
+                let parameters = ValuationParameters {
+                    balance_date: date,
+                    portfolio_id: self.portfolio_id(&portfolio).await?,
+                    consolidated: None,
+                    include_sales: None,
+                    grouping: None,
+                    custom_group_id: None,
+                };
+
+                self.print::<Valuation>(&parameters, "holdings").await
+            }
+        }
+    }
+
+    /// Execute the endpoint named in the manifest, printing a JSON response in the output format
+    /// and any other response as it is.
+    async fn call(&self, name: &str, params: &[(String, String)]) -> anyhow::Result<()> {
+        let endpoint = EndpointManifest::find(name).with_context(|| {
+            format!(
+                "Unknown endpoint: {}, run `sharesight call` to list them",
+                name
+            )
+        })?;
+        let parameters = manifest_parameters(endpoint, params)?;
+        let response = self.client.execute_manifest(endpoint, &parameters).await?;
+
+        match serde_json::from_slice::<Value>(&response.body) {
+            Ok(response) if !endpoint.file => {
+                output::print(self.output, &response, output::rows_key(&response))
+            }
+            _ => Ok(std::io::stdout().write_all(&response.body)?),
+        }
+    }
+}
+
+/// The profile and the store of its credentials.
+fn profile_store(
+    config: &Config,
+    config_path: &Path,
+    name: &str,
+) -> anyhow::Result<(Profile, FileTokenStore)> {
+    let profile = config.profile(name)?;
+    let store = FileTokenStore::new(config::credentials_file(config_path, name, &profile));
+
+    Ok((profile, store))
+}
+
+/// A `--param` given as `field=value`.
+fn parse_param(param: &str) -> Result<(String, String), String> {
+    match param.split_once('=') {
+        Some((field, value)) => Ok((field.to_string(), value.to_string())),
+        None => Err(format!("expected field=value, not {:?}", param)),
+    }
+}
+
+async fn auth(command: Auth, store: &FileTokenStore, profile: &Profile) -> anyhow::Result<()> {
+    match command {
+        Auth::Login {
+            client_id,
+            client_secret,
+            redirect_port,
+        } => {
+            let auth = AuthorizationCodeFlow::new(profile.host(), &client_id, &client_secret)
+                .with_redirect_port(redirect_port)
+                .run(|url| eprintln!("Open {} to authorize", url))
+                .await?;
+
+            let _lock = store.lock().await?;
+            store.save(&auth).await?;
+            eprintln!("Logged in");
+        }
+        Auth::Refresh => {
+            let _lock = store.lock().await?;
+            let auth = store.load().await?.context("Not logged in")?;
+            let refresh_token = auth
+                .auth
+                .refresh_token
+                .as_deref()
+                .context("The credentials have no refresh token")?;
+            let refreshed = OAuthClient::new(&auth.host, &auth.client_id, &auth.client_secret)
+                .refresh(refresh_token)
+                .await?;
+
+            store
+                .save(&sharesight_types::AuthWithDetails {
+                    auth: refreshed,
+                    ..auth
+                })
+                .await?;
+            eprintln!("Refreshed");
+        }
+        Auth::Revoke => {
+            let _lock = store.lock().await?;
+            let auth = store.load().await?.context("Not logged in")?;
+            OAuthClient::new(&auth.host, &auth.client_id, &auth.client_secret)
+                .revoke(&auth.auth.access_token)
+                .await?;
+
+            store.remove().await?;
+            eprintln!("Revoked");
+        }
+        Auth::Status => {
+            let auth = store.load().await?.context("Not logged in")?;
+            let expires_at = chrono::DateTime::from_timestamp(
+                auth.auth.created_at + i64::from(auth.auth.expires_in),
+                0,
+            )
+            .context("Invalid expiry")?;
+
+            println!("Host: {}", auth.host);
+            println!("Client id: {}", auth.client_id);
+            println!("Expires at: {}", expires_at);
+            println!(
+                "Refresh token: {}",
+                if auth.auth.refresh_token.is_some() {
+                    "yes"
+                } else {
+                    "no"
+                }
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn profiles(command: Profiles, config: &mut Config, config_path: &Path) -> anyhow::Result<()> {
+    match command {
+        Profiles::List => {
+            let default = config.profile_name(None).to_string();
+
+            for (name, profile) in &config.profiles {
+                println!(
+                    "{}{} host={} credentials_file={} portfolio={}",
+                    if *name == default { "* " } else { "  " },
+                    name,
+                    profile.host(),
+                    config::credentials_file(config_path, name, profile).display(),
+                    profile.portfolio.as_deref().unwrap_or("")
+                );
+            }
+        }
+        Profiles::Set {
+            name,
+            host,
+            credentials_file,
+            portfolio,
+            default,
+        } => {
+            let profile = config.profiles.entry(name.clone()).or_default();
+            if host.is_some() {
+                profile.host = host;
+            }
+            if credentials_file.is_some() {
+                profile.credentials_file = credentials_file;
+            }
+            if portfolio.is_some() {
+                profile.portfolio = portfolio;
+            }
+            if default {
+                config.default_profile = Some(name);
+            }
+
+            config.save(config_path)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,209 @@
+use std::io::Write;
+
+use serde_json::Value;
+
+/// The longest value shown in a table cell.
+const MAX_CELL_CHARS: usize = 40;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum Output {
+    /// Columns aligned for reading, leaving out nested values.
+    Table,
+    /// The whole response, pretty printed.
+    Json,
+    /// A header and a line for each row, with nested values as JSON.
+    Csv,
+    /// A JSON object on a line for each row.
+    Ndjson,
+}
+
+/// Print the response. The rows of the table, CSV and NDJSON formats are the array under the
+/// `rows` key when there is one, and the whole response otherwise.
+pub fn print(output: Output, response: &Value, rows: Option<&str>) -> anyhow::Result<()> {
+    let stdout = std::io::stdout();
+
+    write(&mut stdout.lock(), output, response, rows)
+}
+
+/// Write the response as `print` does.
+fn write<W: Write>(
+    out: &mut W,
+    output: Output,
+    response: &Value,
+    rows: Option<&str>,
+) -> anyhow::Result<()> {
+    let rows = match rows.and_then(|key| response.get(key)) {
+        Some(Value::Array(rows)) => rows.iter().collect::<Vec<_>>(),
+        Some(row) => vec![row],
+        None => vec![response],
+    };
+
+    match output {
+        Output::Json => writeln!(out, "{}", serde_json::to_string_pretty(response)?)?,
+        Output::Ndjson => {
+            for row in rows {
+                writeln!(out, "{}", row)?;
+            }
+        }
+        Output::Csv => {
+            let columns = columns(&rows, true);
+            let mut wtr = csv::Writer::from_writer(&mut *out);
+
+            wtr.write_record(&columns)?;
+            for row in &rows {
+                wtr.write_record(columns.iter().map(|column| cell(row.get(column))))?;
+            }
+            wtr.flush()?;
+        }
+        Output::Table => {
+            let columns = columns(&rows, false);
+            let cells = rows
+                .iter()
+                .map(|row| {
+                    columns
+                        .iter()
+                        .map(|column| truncate(cell(row.get(column))))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let widths = columns
+                .iter()
+                .enumerate()
+                .map(|(i, column)| {
+                    cells
+                        .iter()
+                        .map(|row| row[i].chars().count())
+                        .chain(Some(column.chars().count()))
+                        .max()
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+
+            for row in Some(&columns).into_iter().chain(&cells) {
+                let line = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                    .collect::<Vec<_>>()
+                    .join("  ");
+                writeln!(out, "{}", line.trim_end())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The key of the rows of a response whose shape isn't known beforehand, such as one from `call`:
+/// its only array of objects, if it has exactly one.
+pub fn rows_key(response: &Value) -> Option<&str> {
+    let mut keys = response.as_object()?.iter().filter(
+        |(_, value)| matches!(value, Value::Array(items) if items.iter().all(Value::is_object)),
+    );
+
+    match (keys.next(), keys.next()) {
+        (Some((key, _)), None) => Some(key),
+        _ => None,
+    }
+}
+
+/// The keys of the rows, in order of first appearance, leaving out keys whose values are all
+/// objects or arrays unless `nested` is set.
+fn columns(rows: &[&Value], nested: bool) -> Vec<String> {
+    let mut columns = Vec::<String>::new();
+
+    for row in rows {
+        if let Value::Object(row) = row {
+            for (key, value) in row {
+                let is_nested = matches!(value, Value::Object(_) | Value::Array(_));
+                if (nested || !is_nested) && !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+        }
+    }
+
+    columns
+}
+
+fn cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn truncate(text: String) -> String {
+    if text.chars().count() > MAX_CELL_CHARS {
+        text.chars().take(MAX_CELL_CHARS - 3).collect::<String>() + "..."
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod output_tests {
+    use serde_json::json;
+
+    use super::{cell, columns, rows_key, write, Output};
+
+    #[test]
+    fn columns_and_cells() {
+        let a = json!({"id": 1, "name": "Trading", "links": {"self": "x"}});
+        let b = json!({"id": 2, "currency": null});
+
+        assert_eq!(vec!["id", "name", "currency"], columns(&[&a, &b], false));
+        assert_eq!(
+            vec!["id", "links", "name", "currency"],
+            columns(&[&a, &b], true)
+        );
+        assert_eq!("Trading", cell(a.get("name")));
+        assert_eq!(r#"{"self":"x"}"#, cell(a.get("links")));
+        assert_eq!("", cell(b.get("currency")));
+    }
+
+    #[test]
+    fn rows_keys() {
+        assert_eq!(
+            Some("trades"),
+            rows_key(&json!({"trades": [{"id": 1}], "api_transaction": {"id": 2}}))
+        );
+        assert_eq!(
+            None,
+            rows_key(&json!({"trades": [{"id": 1}], "payouts": []}))
+        );
+        assert_eq!(None, rows_key(&json!({"trade": {"id": 1}})));
+        assert_eq!(None, rows_key(&json!({"codes": ["ASX", "NZX"]})));
+    }
+
+    fn written(output: Output) -> String {
+        let response = json!({
+            "holdings": [
+                {"id": 1, "symbol": "CBA", "instrument": {"market": "ASX"}},
+                {"id": 2, "symbol": "AIR", "quantity": 10.5},
+            ],
+            "links": {"self": "x"},
+        });
+        let mut out = Vec::new();
+        write(&mut out, output, &response, Some("holdings")).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(
+            "id  symbol  quantity\n1   CBA\n2   AIR     10.5\n",
+            written(Output::Table)
+        );
+        assert_eq!(
+            "id,instrument,symbol,quantity\n1,\"{\"\"market\"\":\"\"ASX\"\"}\",CBA,\n2,,AIR,10.5\n",
+            written(Output::Csv)
+        );
+        assert_eq!(
+            "{\"id\":1,\"instrument\":{\"market\":\"ASX\"},\"symbol\":\"CBA\"}\n{\"id\":2,\"quantity\":10.5,\"symbol\":\"AIR\"}\n",
+            written(Output::Ndjson)
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use clap::Parser;
+use sharesight_reqwest::AuthorizationCodeFlow;
 use sharesight_types::{Auth, AuthWithDetails, DEFAULT_API_HOST};
 
 /// Auth with an OAuth2 Authorization Code using the Sharesight API
@@ -15,8 +16,13 @@ struct Args {
     client_id: String,
     /// The client secret of the API application.
     client_secret: String,
-    /// The authorization code of the user to use to access the API.
-    authorization_code: String,
+    /// The port of the loopback redirect URI, `http://127.0.0.1:<port>/callback`, used when no
+    /// authorization code is given.
+    #[clap(long, default_value_t = 8912)]
+    redirect_port: u16,
+    /// The authorization code of the user to use to access the API. Without one, the user
+    /// authorizes in a browser and is redirected back to this program.
+    authorization_code: Option<String>,
     /// A file to write the output to.
     #[clap(long, short)]
     file: Option<std::path::PathBuf>,
@@ -25,11 +31,29 @@ struct Args {
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+
+    let authorization_code = match args.authorization_code {
+        Some(ref authorization_code) => authorization_code,
+        None => {
+            let auth =
+                AuthorizationCodeFlow::new(&args.api_host, &args.client_id, &args.client_secret)
+                    .with_redirect_port(args.redirect_port)
+                    .run(|url| eprintln!("Open this URL to authorize access:\n{}", url))
+                    .await?;
+
+            print_auth(&auth.auth);
+            if let Some(path) = args.file {
+                serde_json::to_writer_pretty(std::fs::File::create(path)?, &auth)?;
+            }
+
+            return Ok(());
+        }
+    };
     let client = reqwest::Client::new();
 
     let params = [
         ("grant_type", "authorization_code"),
-        ("code", &args.authorization_code),
+        ("code", authorization_code),
         ("redirect_uri", &args.redirect_uri),
         ("client_id", &args.client_id),
         ("client_secret", &args.client_secret),
@@ -43,12 +67,7 @@ async fn main() -> anyhow::Result<()> {
     if resp.status().is_success() {
         let auth = resp.json::<Auth>().await?;
 
-        println!("Access token: {}", auth.access_token);
-        if let Some(refresh_token) = &auth.refresh_token {
-            println!("Refresh token: {}", refresh_token);
-        }
-        println!("Expires in: {}s", auth.expires_in);
-        println!("Created at: {}", auth.created_at);
+        print_auth(&auth);
 
         if let Some(path) = args.file {
             let file = std::fs::File::create(path)?;
@@ -69,3 +88,12 @@ async fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+fn print_auth(auth: &Auth) {
+    println!("Access token: {}", auth.access_token);
+    if let Some(refresh_token) = &auth.refresh_token {
+        println!("Refresh token: {}", refresh_token);
+    }
+    println!("Expires in: {}s", auth.expires_in);
+    println!("Created at: {}", auth.created_at);
+}
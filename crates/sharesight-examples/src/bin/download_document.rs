@@ -0,0 +1,53 @@
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use sharesight_examples::init_logger;
+use sharesight_reqwest::Client;
+use sharesight_types::{DocumentShow, DocumentShowParameters, DEFAULT_API_HOST};
+
+/// Download a document, such as a contract note, using the Sharesight API
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    /// The host to use to access the API.
+    #[clap(long, default_value = DEFAULT_API_HOST)]
+    api_host: String,
+    /// The path to write the document to. Defaults to the document's filename.
+    #[clap(long)]
+    output: Option<PathBuf>,
+    /// The id of the document to download.
+    document_id: i64,
+    /// The access token to use the api.
+    access_token: String,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    init_logger();
+
+    let args = Args::parse();
+    let client = Client::new_with_token_and_host(args.access_token, args.api_host);
+    let document_id = args.document_id;
+
+    let download = client
+        .download_streaming::<DocumentShow>(&DocumentShowParameters { id: document_id })
+        .await?;
+
+    let output = args.output.unwrap_or_else(|| {
+        download
+            .filename()
+            .and_then(|filename| Path::new(&filename).file_name().map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(format!("document-{}", document_id)))
+    });
+    let content_type = download.content_type().unwrap_or("unknown").to_string();
+    let written = download.write_to(&output).await?;
+
+    eprintln!(
+        "Wrote {} bytes of {} to {}",
+        written,
+        content_type,
+        output.display()
+    );
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use clap::Parser;
 use sharesight_examples::init_logger;
 use sharesight_reqwest::Client;
-use sharesight_types::{PortfolioList, PortfolioListSuccess, DEFAULT_API_HOST};
+use sharesight_types::DEFAULT_API_HOST;
 
 /// List the portfolios using the Sharesight API
 #[derive(Parser, Debug)]
@@ -21,9 +21,7 @@ async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     let client = Client::new_with_token_and_host(args.access_token, args.api_host);
 
-    let result = client
-        .execute::<PortfolioList, PortfolioListSuccess>(&())
-        .await?;
+    let result = client.portfolios().list().await?;
 
     println!("{:#?}", result);
 
@@ -1,5 +1,6 @@
 use clap::Parser;
-use sharesight_types::{Auth, AuthWithDetails};
+use sharesight_reqwest::{FileTokenStore, OAuthClient, TokenStore};
+use sharesight_types::AuthWithDetails;
 
 /// Auth with an OAuth2 Authorization Code using the Sharesight API
 #[derive(Parser, Debug)]
@@ -14,45 +15,37 @@ struct Args {
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
-    let auth_arg = serde_json::from_reader::<_, AuthWithDetails>(std::fs::File::open(&args.file)?)?;
-    let client = reqwest::Client::new();
-
-    let params = [
-        ("grant_type", "refresh_token"),
-        ("refresh_token", &auth_arg.auth.refresh_token.unwrap()),
-        ("client_id", &auth_arg.client_id),
-        ("client_secret", &auth_arg.client_secret),
-    ];
-    let resp = client
-        .post(format!("https://{}/oauth2/token", auth_arg.host))
-        .form(&params)
-        .send()
+    let store = FileTokenStore::new(&args.file);
+    // Hold the lock until the new tokens are saved, so another process refreshing with the same
+    // file doesn't use the refresh token this one invalidates.
+    let _lock = store.lock().await?;
+    let auth_arg = store
+        .load()
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("{} does not exist", args.file.display()))?;
+    let refresh_token = auth_arg
+        .auth
+        .refresh_token
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("The auth details have no refresh token"))?;
+    let auth = OAuthClient::new(&auth_arg.host, &auth_arg.client_id, &auth_arg.client_secret)
+        .refresh(refresh_token)
         .await?;
 
-    if resp.status().is_success() {
-        let auth = resp.json::<Auth>().await?;
-
-        if args.token_only {
-            print!("{}", auth.access_token);
-        } else {
-            println!("Access token: {}", auth.access_token);
-            if let Some(refresh_token) = &auth.refresh_token {
-                println!("Refresh token: {}", refresh_token);
-            }
-            println!("Expires in: {}s", auth.expires_in);
-            println!("Created at: {}", auth.created_at);
+    if args.token_only {
+        print!("{}", auth.access_token);
+    } else {
+        println!("Access token: {}", auth.access_token);
+        if let Some(refresh_token) = &auth.refresh_token {
+            println!("Refresh token: {}", refresh_token);
         }
+        println!("Expires in: {}s", auth.expires_in);
+        println!("Created at: {}", auth.created_at);
+    }
 
-        let file = std::fs::File::create(&args.file)?;
-        let auth = AuthWithDetails { auth, ..auth_arg };
-
-        serde_json::to_writer_pretty(file, &auth)?;
-    } else {
-        eprintln!("{:?}", resp);
-        eprintln!("{}", resp.text().await?);
+    let auth = AuthWithDetails { auth, ..auth_arg };
 
-        std::process::exit(-1);
-    }
+    store.save(&auth).await?;
 
     Ok(())
 }
@@ -0,0 +1,67 @@
+use clap::Parser;
+use sharesight_examples::init_logger;
+use sharesight_reqwest::{ClientPool, FileTokenStore};
+use sharesight_types::{
+    PortfolioList, PortfolioListSuccess, Valuation, ValuationParameters, ValuationSuccess,
+};
+
+/// Report the valuation of every portfolio of every account using the Sharesight API
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    /// The number of accounts to report on at a time.
+    #[clap(long, default_value_t = 4)]
+    concurrency: usize,
+    /// Files with the auth details of each account, refreshed when needed.
+    files: Vec<std::path::PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    init_logger();
+
+    let args = Args::parse();
+    let mut pool = ClientPool::new();
+    for file in &args.files {
+        pool.add(FileTokenStore::new(file)).await?;
+    }
+
+    let report = pool
+        .for_each(args.concurrency, |_, client| async move {
+            let PortfolioListSuccess { portfolios, .. } = client
+                .execute::<PortfolioList, PortfolioListSuccess>(&())
+                .await?;
+
+            let mut valuations = Vec::new();
+            for portfolio in portfolios {
+                let parameters = ValuationParameters {
+                    portfolio_id: portfolio.id,
+                    consolidated: None,
+                    include_sales: None,
+                    grouping: None,
+                    custom_group_id: None,
+                    balance_date: None,
+                };
+                let valuation = client
+                    .execute::<Valuation, ValuationSuccess>(&parameters)
+                    .await?;
+
+                valuations.push((portfolio.name, valuation.value));
+            }
+
+            Ok(valuations)
+        })
+        .await;
+
+    for (account, valuations) in &report.successes {
+        println!("{}", account);
+        for (name, value) in valuations {
+            println!("  {}: {}", name, value);
+        }
+    }
+    if !report.is_success() {
+        anyhow::bail!("{}", report);
+    }
+
+    Ok(())
+}
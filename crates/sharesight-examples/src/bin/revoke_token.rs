@@ -0,0 +1,25 @@
+use clap::Parser;
+use sharesight_reqwest::OAuthClient;
+use sharesight_types::AuthWithDetails;
+
+/// Revoke the API access of a user, invalidating their access and refresh tokens
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    /// A file to read the auth details to revoke from.
+    file: std::path::PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let auth = serde_json::from_reader::<_, AuthWithDetails>(std::fs::File::open(&args.file)?)?;
+
+    OAuthClient::new(&auth.host, &auth.client_id, &auth.client_secret)
+        .revoke(&auth.auth.access_token)
+        .await?;
+
+    eprintln!("Revoked API access");
+
+    Ok(())
+}
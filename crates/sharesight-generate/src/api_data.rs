@@ -21,7 +21,6 @@ pub struct ApiEndpoint {
     #[allow(dead_code)]
     pub title: String,
     pub name: String,
-    #[allow(dead_code)]
     pub group: String,
     pub version: String,
     #[allow(dead_code)]
@@ -52,11 +51,27 @@ impl ApiEndpoint {
     }
 
     pub fn fix(&mut self) {
+        self.fix_url();
         self.fix_url_params();
         self.fix_container_params();
         self.fix_dates();
     }
 
+    /// Use `:id` for URL parameters written as `{id}`.
+    fn fix_url(&mut self) {
+        self.url = self
+            .url
+            .split('/')
+            .map(
+                |s| match s.strip_prefix('{').and_then(|s| s.split_once('}')) {
+                    Some((param, rest)) => format!(":{}{}", param, rest),
+                    None => s.to_string(),
+                },
+            )
+            .collect::<Vec<_>>()
+            .join("/");
+    }
+
     fn fix_url_params(&mut self) {
         for url_param in self.url_params() {
             let parameter_fields = &mut self.parameter.fields.parameter;
@@ -200,6 +215,16 @@ impl ApiSuccess {
         self.api_fields().is_empty()
     }
 
+    /// Whether the endpoint returns a file rather than JSON.
+    pub fn is_file(&self) -> bool {
+        let fields = self.api_fields();
+
+        !fields.is_empty()
+            && fields
+                .iter()
+                .all(|field| matches!(field.field_type, FieldType::Scalar(FieldTypeBase::File)))
+    }
+
     pub fn api_fields(&self) -> &[Field] {
         self.fields
             .success
@@ -279,6 +304,111 @@ pub struct Field {
     pub description: String,
 }
 
+const ENUM_INTRODUCERS: &[&str] = &["one of:", "one of", "can be any of", "allowed values:"];
+const ENUM_EXCLUSIONS: &[&str] = &["etc", "for example", "like ", "may be any"];
+
+impl Field {
+    /// The values listed by the description when it enumerates what the field may contain, for
+    /// example `One of: market(s), portfolio or ungrouped.` or `("simple" or "compound")`.
+    pub fn enum_values(&self) -> Option<Vec<EnumValue>> {
+        let text = self.plain_description();
+        let lower = text.to_ascii_lowercase();
+
+        if ENUM_EXCLUSIONS.iter().any(|s| lower.contains(s)) {
+            return None;
+        }
+
+        let (list, quoted) = ENUM_INTRODUCERS
+            .iter()
+            .find_map(|intro| lower.find(intro).map(|i| (&text[i + intro.len()..], false)))
+            .or_else(|| text.find(['(', ':']).map(|i| (&text[i + 1..], true)))?;
+
+        let mut depth = 0;
+        let end = list
+            .char_indices()
+            .find(|&(_, c)| match c {
+                '(' => {
+                    depth += 1;
+                    false
+                }
+                ')' if depth > 0 => {
+                    depth -= 1;
+                    false
+                }
+                ')' => true,
+                '.' => depth == 0,
+                _ => false,
+            })
+            .map(|(i, _)| i)
+            .unwrap_or(list.len());
+
+        let values = list[..end]
+            .replace(" or ", ", ")
+            .split(',')
+            .map(|s| s.trim().trim_start_matches("or ").trim())
+            .filter(|s| !s.is_empty())
+            .map(|item| {
+                let item = match (item.strip_prefix('"'), quoted) {
+                    (Some(item), _) => item.strip_suffix('"')?,
+                    (None, true) => return None,
+                    (None, false) => item,
+                };
+                let names = if let Some(base) = item.strip_suffix("(s)") {
+                    vec![base.to_string(), format!("{}s", base)]
+                } else {
+                    item.split('/').map(String::from).collect()
+                };
+
+                if names.iter().all(|name| {
+                    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                }) {
+                    let (name, aliases) = names.split_first()?;
+
+                    Some(EnumValue {
+                        name: name.clone(),
+                        aliases: aliases.to_vec(),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        if values.len() > 1 {
+            Some(values)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the description notes that the field can be `null` despite not being optional.
+    pub fn is_nullable(&self) -> bool {
+        self.plain_description()
+            .to_ascii_lowercase()
+            .contains("can be null")
+    }
+
+    fn plain_description(&self) -> String {
+        plain_text(&self.description)
+    }
+}
+
+/// A field description without its markup.
+pub fn plain_text(description: &str) -> String {
+    description
+        .replace("<p>", "")
+        .replace("</p>", "")
+        .replace("<code>", "")
+        .replace("</code>", "")
+        .replace("&quot;", "\"")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumValue {
+    pub name: String,
+    pub aliases: Vec<String>,
+}
+
 #[derive(Debug)]
 pub enum FieldType {
     Scalar(FieldTypeBase),
@@ -307,6 +437,13 @@ impl FieldType {
             FieldType::Scalar(FieldTypeBase::String) | FieldType::Array(FieldTypeBase::String)
         )
     }
+
+    pub fn is_number(&self) -> bool {
+        matches!(
+            self,
+            FieldType::Scalar(FieldTypeBase::Number) | FieldType::Scalar(FieldTypeBase::Integer)
+        )
+    }
 }
 
 impl<'de> Deserialize<'de> for FieldType {
@@ -421,3 +558,64 @@ impl Separator for PeriodSeparator {
         "."
     }
 }
+
+#[cfg(test)]
+mod enum_values_tests {
+    use super::{EnumValue, Field, FieldType, FieldTypeBase};
+
+    fn field(description: &str) -> Field {
+        Field {
+            group: String::new(),
+            field_type: FieldType::Scalar(FieldTypeBase::String),
+            optional: false,
+            field: vec!["field".to_string()],
+            description: description.to_string(),
+        }
+    }
+
+    fn value(name: &str, aliases: &[&str]) -> EnumValue {
+        EnumValue {
+            name: name.to_string(),
+            aliases: aliases.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn one_of() {
+        assert_eq!(
+            Some(vec![
+                value("market", &["markets"]),
+                value("countries", &["country"]),
+                value("ungrouped", &[]),
+            ]),
+            field("<p>One of: market(s), countries/country, or ungrouped.</p>").enum_values()
+        );
+    }
+
+    #[test]
+    fn quoted() {
+        assert_eq!(
+            Some(vec![value("simple", &[]), value("compound", &[])]),
+            field("<p>Interest method: <code>&quot;simple&quot;</code> or <code>&quot;compound&quot;</code>.</p>")
+                .enum_values()
+        );
+    }
+
+    #[test]
+    fn not_exhaustive() {
+        assert_eq!(
+            None,
+            field("<p>The trade type (<code>&quot;BUY&quot;</code>, <code>&quot;SELL&quot;</code>, etc).</p>")
+                .enum_values()
+        );
+        assert_eq!(
+            None,
+            field("<p>The new state of the trade: <code>&quot;confirmed&quot;</code>.</p>")
+                .enum_values()
+        );
+        assert_eq!(
+            None,
+            field("<p>Grouping id or name (see below)</p>").enum_values()
+        );
+    }
+}
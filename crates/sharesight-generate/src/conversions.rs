@@ -0,0 +1,384 @@
+use std::{collections::HashMap, fmt};
+
+use heck::ToUpperCamelCase;
+use indexmap::IndexMap;
+use log::warn;
+
+use crate::{
+    api_data::{group_fields_by_prefix, ApiEndpoint},
+    display::{ApiStruct, StructDef, StructFieldDef},
+    shared::SharedTypes,
+};
+
+/// The read models that can be converted into the parameters of an update. The structs shared as
+/// each read model convert through it.
+const CONVERSIONS: &[(&str, &str)] = &[
+    ("TradesShowSuccess", "TradesUpdateParameters"),
+    ("TradesTradesSuccess", "TradesUpdateParameters"),
+    ("Trade", "TradesUpdateParameters"),
+    ("PayoutShowSuccess", "PayoutUpdateParameters"),
+    (
+        "ListPortfolioPayoutsPayoutsSuccess",
+        "PayoutUpdateParameters",
+    ),
+    ("ListHoldingPayoutsPayoutsSuccess", "PayoutUpdateParameters"),
+    ("Payout", "PayoutUpdateParameters"),
+    ("CashAccountShowSuccess", "CashAccountUpdateParameters"),
+    ("CashAccount", "CashAccountUpdateParameters"),
+];
+
+/// Fields that are named differently in the read model and the parameters, as (read model field,
+/// parameter field).
+const FIELD_RENAMES: &[(&str, &str)] = &[("ex_date", "goes_ex_on"), ("currency", "currency_code")];
+
+/// Parameter fields that the read model has in another field for some types of trade only, as
+/// (parameter field, read model field, trade types).
+const TRADE_TYPE_FIELDS: &[(&str, &str, &[&str])] = &[
+    ("adjust_cost_base_value", "value", &["AdjustCostBase"]),
+    (
+        "capital_return_value",
+        "value",
+        &["CapitalReturn", "CapitalCall"],
+    ),
+];
+
+/// `From` and `TryFrom` implementations converting read models into parameters.
+pub struct Conversions {
+    conversions: IndexMap<(String, String), Conversion>,
+}
+
+struct Conversion {
+    fallible: bool,
+    body: String,
+}
+
+struct Converter<'a> {
+    defs: &'a HashMap<String, StructDef>,
+    shared_types: &'a SharedTypes,
+    conversions: IndexMap<(String, String), Conversion>,
+}
+
+impl Conversions {
+    pub fn new(api_endpoints: &[ApiEndpoint], shared_types: &SharedTypes) -> Self {
+        let mut defs = shared_types.defs();
+
+        for api_endpoint in api_endpoints {
+            let endpoint_name = api_endpoint.name.to_upper_camel_case();
+            let parameter_fields = group_fields_by_prefix(&api_endpoint.parameter.fields.parameter);
+
+            for def in
+                ApiStruct::parameters(&endpoint_name, &api_endpoint.group, &parameter_fields).defs()
+            {
+                defs.insert(def.name.clone(), def);
+            }
+        }
+
+        let mut converter = Converter {
+            defs: &defs,
+            shared_types,
+            conversions: IndexMap::new(),
+        };
+
+        for (source, target) in CONVERSIONS {
+            if !converter.convert(source, target) {
+                warn!("Unable to convert {} into {}", source, target);
+            }
+        }
+
+        Conversions {
+            conversions: converter.conversions,
+        }
+    }
+}
+
+impl<'a> Converter<'a> {
+    /// Add the conversion between two structs, returning whether the conversion is possible.
+    fn convert(&mut self, source: &str, target: &str) -> bool {
+        let source = self.shared_types.shared_name(source).unwrap_or(source);
+        let key = (source.to_string(), target.to_string());
+
+        if self.conversions.contains_key(&key) {
+            return true;
+        }
+
+        match self.struct_expr(source, target, "value") {
+            Some((body, fallible)) => {
+                self.conversions.insert(key, Conversion { fallible, body });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// An expression building the target struct from the source struct in `value`, and whether
+    /// building it can fail.
+    fn struct_expr(&mut self, source: &str, target: &str, value: &str) -> Option<(String, bool)> {
+        let source_def = self.defs.get(source)?;
+        let target_def = self.defs.get(target)?;
+        let mut fallible = false;
+        let mut body = format!("{} {{\n", target);
+
+        for target_field in &target_def.fields {
+            let source_name = FIELD_RENAMES
+                .iter()
+                .find(|(_, t)| *t == target_field.name)
+                .map(|(s, _)| *s)
+                .unwrap_or(&target_field.name);
+            let source_field = source_def
+                .fields
+                .iter()
+                .find(|f| f.name == source_name)
+                .or_else(|| {
+                    source_def
+                        .fields
+                        .iter()
+                        .find(|f| f.name == target_field.name)
+                });
+
+            let (expr, field_fallible) = match source_field {
+                Some(source_field) => self.field_expr(source_field, target_field, value)?,
+                None => match trade_type_expr(source_def, target_field, value) {
+                    Some(expr) => (expr, false),
+                    None if is_option(&target_field.type_name) => ("None".to_string(), false),
+                    None => match target_field.nested {
+                        Some(ref nested) => self.struct_expr(source, nested, value)?,
+                        None => return None,
+                    },
+                },
+            };
+
+            fallible |= field_fallible;
+            body += &format!("    {}: {},\n", target_field.name, expr);
+        }
+        body += "}";
+
+        Some((body, fallible))
+    }
+
+    /// An expression converting the source field into the target field, and whether the
+    /// conversion can fail.
+    fn field_expr(
+        &mut self,
+        source: &StructFieldDef,
+        target: &StructFieldDef,
+        value: &str,
+    ) -> Option<(String, bool)> {
+        let source_type = strip_option(&source.type_name);
+        let target_type = strip_option(&target.type_name);
+        let expr = format!("{}.{}", value, source.name);
+
+        let (convert, fallible) = match (&source.nested, &target.nested) {
+            _ if source_type == target_type => (None, false),
+            (Some(source_nested), Some(target_nested)) => {
+                let source_nested = self
+                    .shared_types
+                    .shared_name(source_nested)
+                    .unwrap_or(source_nested)
+                    .to_string();
+
+                if !self.convert(&source_nested, target_nested) {
+                    return None;
+                }
+
+                if self.conversions[&(source_nested, target_nested.clone())].fallible {
+                    (Some("TryFrom::try_from(VALUE)?"), true)
+                } else {
+                    (Some("VALUE.into()"), false)
+                }
+            }
+            (None, None) => match (source_type, target_type) {
+                ("NaiveDate" | "i64" | "Number", "String") => (Some("VALUE.to_string()"), false),
+                ("String", "i64") => (
+                    Some("VALUE.parse().map_err(|e| IntoParametersError::Invalid(NAME, e))?"),
+                    true,
+                ),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let convert = |value: &str| match convert {
+            Some(convert) => convert
+                .replace("NAME", &format!("{:?}", target.name))
+                .replace("VALUE", value),
+            None => value.to_string(),
+        };
+
+        let expr = match (is_option(&source.type_name), is_option(&target.type_name)) {
+            (false, false) => convert(&expr),
+            (false, true) => format!("Some({})", convert(&expr)),
+            (true, true) if convert("v") == "v" => expr,
+            (true, true) if fallible => format!(
+                "{}.map(|v| -> Result<_, IntoParametersError> {{ Ok({}) }}).transpose()?",
+                expr,
+                convert("v")
+            ),
+            (true, true) => format!("{}.map(|v| {})", expr, convert("v")),
+            (true, false) => {
+                return Some((
+                    convert(&format!(
+                        "{}.ok_or(IntoParametersError::Missing({:?}))?",
+                        expr, target.name
+                    )),
+                    true,
+                ));
+            }
+        };
+
+        Some((expr, fallible))
+    }
+}
+
+impl fmt::Display for Conversions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((source, target), conversion) in &self.conversions {
+            if conversion.fallible {
+                writeln!(f, "impl TryFrom<{}> for {} {{", source, target)?;
+                writeln!(f, "    type Error = IntoParametersError;")?;
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "    fn try_from(value: {}) -> Result<Self, Self::Error> {{",
+                    source
+                )?;
+                writeln!(f, "        Ok({})", conversion.body)?;
+            } else {
+                writeln!(f, "impl From<{}> for {} {{", source, target)?;
+                writeln!(f, "    fn from(value: {}) -> Self {{", source)?;
+                writeln!(f, "        {}", conversion.body)?;
+            }
+            writeln!(f, "    }}")?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An expression taking an optional target field from another field of the source struct when
+/// the trade in `value` is of a type the field applies to.
+fn trade_type_expr(source: &StructDef, target: &StructFieldDef, value: &str) -> Option<String> {
+    let (_, source_name, types) = TRADE_TYPE_FIELDS
+        .iter()
+        .find(|(name, _, _)| *name == target.name)?;
+    let has_field = |name: &str, type_name: &str| {
+        source
+            .fields
+            .iter()
+            .any(|f| f.name == name && f.type_name == type_name)
+    };
+
+    if !is_option(&target.type_name)
+        || !has_field("transaction_type", "TradeDescription")
+        || !has_field(source_name, strip_option(&target.type_name))
+    {
+        return None;
+    }
+
+    let types = types
+        .iter()
+        .map(|t| format!("TradeDescription::{}", t))
+        .collect::<Vec<_>>();
+
+    Some(format!(
+        "value_for_types({}.transaction_type, &[{}], &{}.{})",
+        value,
+        types.join(", "),
+        value,
+        source_name
+    ))
+}
+
+fn is_option(type_name: &str) -> bool {
+    type_name.starts_with("Option<")
+}
+
+fn strip_option(type_name: &str) -> &str {
+    type_name
+        .strip_prefix("Option<")
+        .and_then(|t| t.strip_suffix('>'))
+        .unwrap_or(type_name)
+}
+
+#[cfg(test)]
+mod conversions_tests {
+    use std::collections::HashMap;
+
+    use indexmap::IndexMap;
+
+    use super::{Conversions, Converter};
+    use crate::{
+        display::{StructDef, StructFieldDef},
+        shared::SharedTypes,
+    };
+
+    fn def(name: &str, fields: &[(&str, &str)]) -> (String, StructDef) {
+        let fields = fields
+            .iter()
+            .map(|(name, type_name)| StructFieldDef {
+                description: String::new(),
+                name: name.to_string(),
+                attributes: Vec::new(),
+                type_name: type_name.to_string(),
+                nested: None,
+            })
+            .collect();
+
+        (
+            name.to_string(),
+            StructDef {
+                name: name.to_string(),
+                derives: &[],
+                fields,
+            },
+        )
+    }
+
+    #[test]
+    fn read_model_into_update() {
+        let defs = vec![
+            def(
+                "Read",
+                &[
+                    ("id", "String"),
+                    ("ex_date", "Option<NaiveDate>"),
+                    ("quantity", "Number"),
+                ],
+            ),
+            def(
+                "Update",
+                &[
+                    ("id", "i64"),
+                    ("goes_ex_on", "Option<NaiveDate>"),
+                    ("quantity", "Option<Number>"),
+                    ("send_to_xero", "Option<bool>"),
+                ],
+            ),
+            def("Create", &[("id", "i64"), ("portfolio_id", "i64")]),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let shared_types = SharedTypes::new(&[]).unwrap();
+        let mut converter = Converter {
+            defs: &defs,
+            shared_types: &shared_types,
+            conversions: IndexMap::new(),
+        };
+
+        assert!(converter.convert("Read", "Update"));
+        assert!(!converter.convert("Read", "Create"));
+
+        let conversions = Conversions {
+            conversions: converter.conversions,
+        }
+        .to_string();
+
+        assert!(conversions.contains("impl TryFrom<Read> for Update {"));
+        assert!(conversions.contains(
+            "id: value.id.parse().map_err(|e| IntoParametersError::Invalid(\"id\", e))?,"
+        ));
+        assert!(conversions.contains("goes_ex_on: value.ex_date,"));
+        assert!(conversions.contains("quantity: Some(value.quantity),"));
+        assert!(conversions.contains("send_to_xero: None,"));
+    }
+}
@@ -4,13 +4,23 @@ use heck::ToUpperCamelCase;
 use indexmap::IndexMap;
 use log::{error, warn};
 
-use crate::api_data::{group_fields_by_prefix, ApiEndpoint, Field, FieldType, FieldTypeBase};
-
-pub struct ApiEndpointStruct<'a>(pub &'a ApiEndpoint);
+use crate::{
+    api_data::{
+        group_fields_by_prefix, ApiEndpoint, EnumValue, Field, FieldType, FieldTypeBase, Method,
+    },
+    shared::SharedTypes,
+    validation::Validations,
+};
+
+pub struct ApiEndpointStruct<'a>(
+    pub &'a ApiEndpoint,
+    pub &'a SharedTypes,
+    pub &'a Validations,
+);
 
 impl<'a> fmt::Display for ApiEndpointStruct<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self(data) = self;
+        let Self(data, shared_types, validations) = self;
 
         let parameter_fields = &data.parameter.fields.parameter;
         if parameter_fields
@@ -68,7 +78,7 @@ impl<'a> fmt::Display for ApiEndpointStruct<'a> {
             write!(f, "{}Parameters", endpoint_name)?;
         }
         writeln!(f, ";")?;
-        if data.success.is_empty() {
+        if data.success.is_empty() || data.success.is_file() {
             writeln!(f, "    type Success = ();")?;
         } else {
             writeln!(f, "    type Success = {}Success;", endpoint_name)?;
@@ -85,9 +95,44 @@ impl<'a> fmt::Display for ApiEndpointStruct<'a> {
             write!(f, "        {}UrlDisplay(parameters)", endpoint_name)?;
         }
         writeln!(f, "    }}")?;
+        if validations.contains(&format!("{}Parameters", endpoint_name)) {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "    fn validate(parameters: &Self::Parameters) -> Result<(), ValidationError> {{"
+            )?;
+            writeln!(f, "        parameters.validate()")?;
+            writeln!(f, "    }}")?;
+        }
+        if validations.contains_quantity(&format!("{}Parameters", endpoint_name)) {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "    fn validate_quantity(parameters: &Self::Parameters, decimal_markets: &[Market]) -> Result<(), ValidationError> {{"
+            )?;
+            writeln!(f, "        parameters.validate_quantity(decimal_markets)")?;
+            writeln!(f, "    }}")?;
+        }
+        if validations.contains_currency(&format!("{}Parameters", endpoint_name)) {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "    fn validate_brokerage_currency(parameters: &Self::Parameters, currencies: &[Currency]) -> Result<(), ValidationError> {{"
+            )?;
+            writeln!(
+                f,
+                "        parameters.validate_brokerage_currency(currencies)"
+            )?;
+            writeln!(f, "    }}")?;
+        }
         writeln!(f, "}}")?;
         writeln!(f)?;
 
+        if data.success.is_file() {
+            writeln!(f, "impl<'a> ApiFileEndpoint<'a> for {} {{}}", endpoint_name)?;
+            writeln!(f)?;
+        }
+
         if !params.is_empty() {
             writeln!(
                 f,
@@ -120,14 +165,27 @@ impl<'a> fmt::Display for ApiEndpointStruct<'a> {
             writeln!(
                 f,
                 "{}",
-                ApiStruct::parameters(&endpoint_name, &parameter_fields)
+                ApiStruct::parameters(&endpoint_name, &data.group, &parameter_fields)
+                    .skip_none(matches!(data.method, Method::Post))
             )?;
         }
         writeln!(f)?;
 
+        if data.success.is_file() {
+            return Ok(());
+        }
+
         let success_fields = group_fields_by_prefix(data.success.api_fields());
 
-        writeln!(f, "{}", ApiStruct::success(&endpoint_name, &success_fields))?;
+        for def in ApiStruct::success(&endpoint_name, &data.group, &success_fields).defs() {
+            if let Some(shared_name) = shared_types.shared_name(&def.name) {
+                writeln!(f, "pub type {} = {};", def.name, shared_name)?;
+                writeln!(f)?;
+            } else {
+                write!(f, "{}", def)?;
+            }
+        }
+        writeln!(f)?;
         writeln!(f)?;
 
         Ok(())
@@ -138,156 +196,266 @@ pub struct ApiStruct<'a> {
     tag: &'a str,
     label: &'a str,
     endpoint_name: &'a str,
+    group: &'a str,
     fields: &'a IndexMap<&'a [std::string::String], Vec<&'a Field>>,
-    derives: &'a [&'static str],
+    derives: &'static [&'static str],
+    skip_none: bool,
 }
 
 impl<'a> ApiStruct<'a> {
-    fn parameters(
+    pub fn parameters(
         endpoint_name: &'a str,
+        group: &'a str,
         fields: &'a IndexMap<&'a [std::string::String], Vec<&'a Field>>,
     ) -> Self {
         ApiStruct {
             tag: "Parameters",
             label: "parameter",
             endpoint_name,
+            group,
             fields,
             derives: &["Serialize"],
+            skip_none: false,
         }
     }
 
-    fn success(
+    pub fn success(
         endpoint_name: &'a str,
+        group: &'a str,
         fields: &'a IndexMap<&'a [std::string::String], Vec<&'a Field>>,
     ) -> Self {
         ApiStruct {
             tag: "Success",
             label: "success",
             endpoint_name,
+            group,
             fields,
             derives: &["Deserialize"],
+            skip_none: false,
         }
     }
+
+    /// Leave fields that are `None` out when serializing, rather than sending `null`, so that
+    /// what is created gets the API's defaults for the fields that aren't set. Updates still send
+    /// `null`, which is how a field is cleared.
+    pub fn skip_none(self, skip_none: bool) -> Self {
+        ApiStruct { skip_none, ..self }
+    }
 }
 
-impl<'a> fmt::Display for ApiStruct<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<'a> ApiStruct<'a> {
+    /// The structs for each group of fields sharing a prefix.
+    pub fn defs(&self) -> Vec<StructDef> {
         let ApiStruct {
             tag,
             label,
             endpoint_name,
+            group,
             fields,
             derives,
+            skip_none,
         } = *self;
 
-        for (prefix, fields) in fields.iter() {
-            write!(f, "#[serde_as]")?;
-            write!(f, "#[derive(Debug, Clone")?;
-            for derive in derives {
-                write!(f, ", {}", derive)?;
-            }
-            writeln!(f, ")]")?;
-            write!(f, "pub struct {}", endpoint_name)?;
-            for prefix_segment in prefix.iter() {
-                write!(f, "{}", prefix_segment.to_upper_camel_case())?;
-            }
-            writeln!(f, "{} {{", tag)?;
-            for parameter in fields {
-                if let [ref prefix_segments @ .., ref field_name] = parameter.field[..] {
-                    write!(f, "{}", DocComment(&parameter.description))?;
-
-                    if field_name == "self" {
-                        writeln!(f, "    #[serde(rename = \"self\")]")?;
-                    }
-
-                    if matches!(
-                        parameter.field_type,
-                        FieldType::Scalar(FieldTypeBase::Integer)
-                    ) {
-                        if parameter.optional {
-                            writeln!(
-                                f,
-                                "    #[serde_as(as = \"Option<PickFirst<(_, DisplayFromStr)>>\")]"
-                            )?;
-                        } else {
-                            writeln!(
-                                f,
-                                "    #[serde_as(as = \"PickFirst<(_, DisplayFromStr)>\")]"
-                            )?;
-                        }
-                    }
-
-                    if matches!(parameter.field_type, FieldType::Scalar(FieldTypeBase::Date)) {
-                        if parameter.optional {
-                            writeln!(f, "    #[serde_as(as = \"Option<DeserializeDate>\")]")?;
-                        } else {
-                            writeln!(f, "    #[serde_as(as = \"DeserializeDate\")]")?;
-                        }
-                    }
-
-                    if parameter.optional {
-                        writeln!(f, "    #[serde(default)]")?;
-                    } else if parameter.field_type.is_string()
-                        && string_enum_type(field_name, endpoint_name).is_none()
-                    {
-                        writeln!(f, "    #[serde(default)]")?;
-                        writeln!(f, "    #[serde_as(deserialize_as = \"DefaultOnNull\")]")?;
-                    }
-
-                    write!(
-                        f,
-                        "    pub {}: ",
-                        if field_name == "self" {
-                            "itself"
-                        } else {
-                            field_name
-                        }
-                    )?;
-                    if parameter.optional {
-                        write!(f, "Option<")?;
-                    }
-                    if parameter.field_type.is_hash() {
-                        if parameter.field_type.is_array() {
-                            write!(f, "Vec<")?;
-                        }
-                        if field_name == "cash_account_transaction_type" {
-                            write!(f, "CashAccountTransactionType")?;
-                        } else {
-                            write!(f, "{}", endpoint_name)?;
-                            for prefix_segment in prefix_segments.iter() {
-                                write!(f, "{}", prefix_segment.to_upper_camel_case())?;
+        fields
+            .iter()
+            .map(|(prefix, fields)| {
+                let name = prefix.iter().fold(endpoint_name.to_string(), |name, s| {
+                    name + &s.to_upper_camel_case()
+                }) + tag;
+                let fields = fields
+                    .iter()
+                    .filter_map(|parameter| {
+                        let mut field = StructFieldDef::new(parameter, endpoint_name, group, tag);
+
+                        match field {
+                            Some(ref mut field) if skip_none && field.is_option() => {
+                                field.attributes.push(
+                                    "#[serde(skip_serializing_if = \"Option::is_none\")]"
+                                        .to_string(),
+                                );
                             }
-                            write!(f, "{}{}", field_name.to_upper_camel_case(), tag)?;
-                        }
-                        if parameter.field_type.is_array() {
-                            write!(f, ">")?;
+                            Some(_) => {}
+                            None => error!(
+                                "Endpoint {} has {} field with no field name: {:?} ",
+                                endpoint_name, label, parameter
+                            ),
                         }
-                    } else if parameter.field_type.is_string()
-                        && string_enum_type(field_name, endpoint_name).is_some()
-                    {
-                        write!(
-                            f,
-                            "{}",
-                            string_enum_type(field_name, endpoint_name).unwrap()
-                        )?;
-                    } else {
-                        write!(f, "{}", FieldTypeRustTypeNameDisplay(&parameter.field_type))?;
-                    }
-                    if parameter.optional {
-                        write!(f, ">")?;
-                    }
-                    writeln!(f, ",")?;
-                } else {
-                    error!(
-                        "Endpoint {} has {} field with no field name: {:?} ",
-                        endpoint_name, label, parameter
-                    );
+
+                        field
+                    })
+                    .collect();
+
+                StructDef {
+                    name,
+                    derives,
+                    fields,
                 }
+            })
+            .collect()
+    }
+}
+
+impl<'a> fmt::Display for ApiStruct<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for def in self.defs() {
+            write!(f, "{}", def)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A generated struct, before it is written out.
+#[derive(Clone)]
+pub struct StructDef {
+    pub name: String,
+    pub derives: &'static [&'static str],
+    pub fields: Vec<StructFieldDef>,
+}
+
+#[derive(Clone)]
+pub struct StructFieldDef {
+    pub description: String,
+    pub name: String,
+    pub attributes: Vec<String>,
+    pub type_name: String,
+    /// The name of the generated struct this field contains, if any.
+    pub nested: Option<String>,
+}
+
+impl StructFieldDef {
+    pub fn is_option(&self) -> bool {
+        self.type_name.starts_with("Option<")
+    }
+
+    fn new(parameter: &Field, endpoint_name: &str, group: &str, tag: &str) -> Option<Self> {
+        let (prefix_segments, field_name) = match parameter.field[..] {
+            [ref prefix_segments @ .., ref field_name] => (prefix_segments, field_name),
+            [] => return None,
+        };
+        let mut attributes = Vec::new();
+
+        let enum_type = if parameter.field_type.is_string() {
+            string_enum_type_for_field(parameter, endpoint_name, group)
+        } else {
+            None
+        };
+        let optional = parameter.optional
+            || matches!(enum_type, Some(StringEnumType::Inferred(..))) && parameter.is_nullable();
+
+        if field_name == "self" {
+            attributes.push("#[serde(rename = \"self\")]".to_string());
+        }
+
+        if matches!(
+            parameter.field_type,
+            FieldType::Scalar(FieldTypeBase::Integer)
+        ) {
+            if parameter.optional {
+                attributes.push(
+                    "#[serde_as(as = \"Option<PickFirst<(_, DisplayFromStr)>>\")]".to_string(),
+                );
+            } else {
+                attributes.push("#[serde_as(as = \"PickFirst<(_, DisplayFromStr)>\")]".to_string());
             }
-            writeln!(f, "}}")?;
-            writeln!(f)?;
         }
 
+        if matches!(parameter.field_type, FieldType::Scalar(FieldTypeBase::Date)) {
+            if parameter.optional {
+                attributes.push("#[serde_as(as = \"Option<DeserializeDate>\")]".to_string());
+            } else {
+                attributes.push("#[serde_as(as = \"DeserializeDate\")]".to_string());
+            }
+        }
+
+        if optional {
+            attributes.push("#[serde(default)]".to_string());
+        } else if parameter.field_type.is_string() && enum_type.is_none() {
+            attributes.push("#[serde(default)]".to_string());
+            attributes.push("#[serde_as(deserialize_as = \"DefaultOnNull\")]".to_string());
+        }
+
+        let mut nested = None;
+        let mut type_name = if parameter.field_type.is_hash() {
+            let type_name = if field_name == "cash_account_transaction_type" {
+                "CashAccountTransactionType".to_string()
+            } else {
+                let type_name = prefix_segments
+                    .iter()
+                    .fold(endpoint_name.to_string(), |name, s| {
+                        name + &s.to_upper_camel_case()
+                    })
+                    + &field_name.to_upper_camel_case()
+                    + tag;
+                nested = Some(type_name.clone());
+                type_name
+            };
+
+            if parameter.field_type.is_array() {
+                format!("Vec<{}>", type_name)
+            } else {
+                type_name
+            }
+        } else if let Some(ref enum_type) = enum_type {
+            enum_type.name().to_string()
+        } else {
+            FieldTypeRustTypeNameDisplay(&parameter.field_type).to_string()
+        };
+        if optional {
+            type_name = format!("Option<{}>", type_name);
+        }
+
+        Some(StructFieldDef {
+            description: parameter.description.clone(),
+            name: if field_name == "self" {
+                "itself".to_string()
+            } else {
+                field_name.clone()
+            },
+            attributes,
+            type_name,
+            nested,
+        })
+    }
+}
+
+impl fmt::Display for StructDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let StructDef {
+            name,
+            derives,
+            fields,
+        } = self;
+
+        write!(f, "#[serde_as]")?;
+        write!(f, "#[derive(Debug, Clone")?;
+        for derive in derives.iter() {
+            write!(f, ", {}", derive)?;
+        }
+        writeln!(f, ")]")?;
+        writeln!(f, "pub struct {} {{", name)?;
+        for field in fields {
+            write!(f, "{}", DocComment(&field.description))?;
+            for attribute in &field.attributes {
+                writeln!(f, "    {}", attribute)?;
+            }
+            writeln!(f, "    pub {}: {},", field.name, field.type_name)?;
+        }
+        if derives.contains(&"Deserialize") {
+            writeln!(
+                f,
+                "    /// Fields returned by the API that aren't in the API documentation."
+            )?;
+            writeln!(f, "    #[cfg(feature = \"extra-fields\")]")?;
+            writeln!(f, "    #[serde(flatten)]")?;
+            writeln!(
+                f,
+                "    pub extra: serde_json::Map<String, serde_json::Value>,"
+            )?;
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+
         Ok(())
     }
 }
@@ -306,6 +474,92 @@ fn string_enum_type(s: &str, endpoint_name: &str) -> Option<&'static str> {
     }
 }
 
+/// The enum used in place of `String` for a string field.
+pub enum StringEnumType {
+    /// An enum hand written in `codes.rs` or `types_prelude.rs`.
+    Existing(&'static str),
+    /// An enum generated from the values listed in the field description.
+    Inferred(String, Vec<EnumValue>),
+}
+
+impl StringEnumType {
+    pub fn name(&self) -> &str {
+        match self {
+            StringEnumType::Existing(name) => name,
+            StringEnumType::Inferred(name, _) => name,
+        }
+    }
+}
+
+pub fn string_enum_type_for_field(
+    field: &Field,
+    endpoint_name: &str,
+    group: &str,
+) -> Option<StringEnumType> {
+    let (prefix_segments, field_name) = match field.field[..] {
+        [ref prefix_segments @ .., ref field_name] => (prefix_segments, field_name),
+        [] => return None,
+    };
+
+    if let Some(name) = string_enum_type(field_name, endpoint_name) {
+        Some(StringEnumType::Existing(name))
+    } else if field.description.contains("codes#sale_allocation_method") {
+        Some(StringEnumType::Existing("SaleAllocationMethod"))
+    } else {
+        let values = field.enum_values()?;
+        let name = match &field_name[..] {
+            "state" => {
+                let entity = prefix_segments
+                    .last()
+                    .map(String::as_str)
+                    .unwrap_or_else(|| group.trim_start_matches("User_API_"))
+                    .to_upper_camel_case();
+                let entity = entity.strip_suffix('s').unwrap_or(&entity);
+
+                format!("{}State", entity)
+            }
+            _ => field_name.to_upper_camel_case(),
+        };
+
+        Some(StringEnumType::Inferred(name, values))
+    }
+}
+
+pub struct ApiEnum<'a> {
+    pub name: &'a str,
+    pub description: &'a str,
+    pub values: &'a [EnumValue],
+}
+
+impl<'a> fmt::Display for ApiEnum<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ApiEnum {
+            name,
+            description,
+            values,
+        } = *self;
+
+        write!(f, "{}", DocComment(description))?;
+        writeln!(
+            f,
+            "#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]"
+        )?;
+        writeln!(f, "pub enum {} {{", name)?;
+        for value in values {
+            write!(f, "    #[serde(rename = \"{}\"", value.name)?;
+            for alias in &value.aliases {
+                write!(f, ", alias = \"{}\"", alias)?;
+            }
+            writeln!(f, ")]")?;
+            writeln!(f, "    {},", value.name.to_upper_camel_case())?;
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+
+        Ok(())
+    }
+}
+
 struct FieldTypeRustTypeNameDisplay<'a>(&'a FieldType);
 
 impl<'a> fmt::Display for FieldTypeRustTypeNameDisplay<'a> {
@@ -365,7 +619,7 @@ impl<'a> fmt::Display for FieldTypeBaseRustTypeNameDisplay<'a> {
     }
 }
 
-struct DocComment<'a>(&'a str);
+pub struct DocComment<'a>(pub &'a str);
 
 impl<'a> fmt::Display for DocComment<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
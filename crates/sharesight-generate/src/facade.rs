@@ -0,0 +1,394 @@
+use std::fmt;
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use indexmap::IndexMap;
+use log::warn;
+
+use crate::{
+    api_data::{group_fields_by_prefix, ApiEndpoint, Method},
+    display::{ApiStruct, DocComment, StructFieldDef},
+};
+
+/// The group of endpoints that report on a portfolio rather than list its records, so each
+/// becomes a method of the portfolio, for example `client.portfolio(id).valuation(..)`.
+const REPORTS_GROUP: &str = "User_API_Reports";
+
+/// A segment of a resource path, which is either a collection such as `trades` or a single record
+/// of a collection such as `trades/:id`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Segment {
+    collection: String,
+    member: bool,
+}
+
+impl Segment {
+    fn singular(&self) -> &str {
+        self.collection
+            .strip_suffix('s')
+            .unwrap_or(&self.collection)
+    }
+}
+
+/// The methods of a resource and the resources reached from it.
+#[derive(Default)]
+struct Resource<'a> {
+    methods: Vec<ResourceMethod<'a>>,
+}
+
+struct ResourceMethod<'a> {
+    name: String,
+    api_endpoint: &'a ApiEndpoint,
+    /// The URL parameters of the endpoint, in the order of the members in the resource path.
+    url_params: Vec<String>,
+}
+
+/// A client facade with a struct for each resource in the API, reached through methods named after
+/// the segments of the endpoint URLs: `client.portfolios().list()`,
+/// `client.portfolio(id).trades().list(..)` or `client.cash_account(id).transactions().create(..)`.
+pub struct Facade<'a> {
+    resources: IndexMap<Vec<Segment>, Resource<'a>>,
+}
+
+impl<'a> Facade<'a> {
+    pub fn new(api_endpoints: &'a [ApiEndpoint]) -> Self {
+        let mut resources = IndexMap::<Vec<Segment>, Resource<'a>>::new();
+        resources.insert(Vec::new(), Resource::default());
+
+        for api_endpoint in api_endpoints {
+            let (mut path, url_params) = match resource_path(&api_endpoint.url) {
+                Some(path) => path,
+                None => {
+                    warn!(
+                        "Endpoint {} has no resource path: {}",
+                        api_endpoint.name, api_endpoint.url
+                    );
+                    continue;
+                }
+            };
+            let last = path.last().cloned();
+
+            let name = match (last, &api_endpoint.method) {
+                (Some(last), Method::Get | Method::Show)
+                    if !last.member
+                        && (!last.collection.ends_with('s')
+                            || api_endpoint.group == REPORTS_GROUP) =>
+                {
+                    path.pop();
+                    accessor_name(&path, &last)
+                }
+                (Some(last), method) => match (last.member, method) {
+                    (false, Method::Get | Method::Show) => "list".to_string(),
+                    (false, Method::Post) => "create".to_string(),
+                    (true, Method::Get | Method::Show) => "show".to_string(),
+                    (true, Method::Put | Method::Patch) => "update".to_string(),
+                    (true, Method::Delete) => "delete".to_string(),
+                    _ => {
+                        warn!("Endpoint {} has no resource method", api_endpoint.name);
+                        continue;
+                    }
+                },
+                (None, _) => continue,
+            };
+
+            for len in 0..path.len() {
+                resources.entry(path[..len].to_vec()).or_default();
+            }
+            resources
+                .entry(path)
+                .or_default()
+                .methods
+                .push(ResourceMethod {
+                    name,
+                    api_endpoint,
+                    url_params,
+                });
+        }
+
+        for (path, resource) in resources.iter_mut() {
+            let words = path
+                .last()
+                .map(|segment| vec![segment.collection.clone(), segment.singular().to_string()])
+                .unwrap_or_default();
+
+            // Endpoints that share a verb, like confirming and rejecting a payout, are named after
+            // the endpoint instead.
+            let names = resource
+                .methods
+                .iter()
+                .map(|method| method.name.clone())
+                .collect::<Vec<_>>();
+            for method in resource.methods.iter_mut() {
+                if names.iter().filter(|name| **name == method.name).count() > 1 {
+                    method.name = method
+                        .api_endpoint
+                        .name
+                        .to_snake_case()
+                        .split('_')
+                        .filter(|word| !words.iter().any(|w| w == word))
+                        .collect::<Vec<_>>()
+                        .join("_");
+                }
+            }
+        }
+
+        Facade { resources }
+    }
+}
+
+/// The resource path of a URL and the names of the URL parameters identifying its members, for
+/// example `/portfolios/:portfolio_id/trades.json` is the trades of a portfolio.
+fn resource_path(url: &str) -> Option<(Vec<Segment>, Vec<String>)> {
+    let mut path = Vec::<Segment>::new();
+    let mut url_params = Vec::new();
+
+    for segment in url.strip_prefix('/')?.split('/') {
+        let segment = segment.split('.').next().unwrap_or(segment);
+
+        if let Some(param) = segment.strip_prefix(':') {
+            let last = path.last_mut().filter(|last| !last.member)?;
+            last.member = true;
+            url_params.push(param.to_string());
+        } else {
+            path.push(Segment {
+                collection: segment.to_string(),
+                member: false,
+            });
+        }
+    }
+
+    Some((path, url_params))
+}
+
+/// The name of the method reaching the segment from the resource at the path, without the name
+/// of the parent it repeats, so the transactions of a cash account are `transactions()` rather
+/// than `cash_account_transactions()`.
+fn accessor_name(path: &[Segment], segment: &Segment) -> String {
+    let name = if segment.member {
+        segment.singular()
+    } else {
+        &segment.collection
+    };
+
+    path.last()
+        .and_then(|parent| {
+            name.strip_prefix(parent.singular())
+                .and_then(|s| s.strip_prefix('_'))
+        })
+        .unwrap_or(name)
+        .to_string()
+}
+
+fn struct_name(path: &[Segment]) -> String {
+    path.iter()
+        .enumerate()
+        .map(|(i, segment)| accessor_name(&path[..i], segment).to_upper_camel_case())
+        .collect::<String>()
+        + "Resource"
+}
+
+/// The fields of the resource struct holding the ids of the members in its path.
+fn id_fields(path: &[Segment]) -> Vec<String> {
+    path.iter()
+        .filter(|segment| segment.member)
+        .map(|segment| format!("{}_id", segment.singular()))
+        .collect()
+}
+
+impl<'a> fmt::Display for Facade<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "use chrono::{{DateTime, FixedOffset, NaiveDate}};")?;
+        writeln!(f, "use sharesight_types::*;")?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "use crate::{{Client, Download, ReqwestTransport, SharesightReqwestError}};"
+        )?;
+        writeln!(f)?;
+
+        for (path, resource) in &self.resources {
+            let ids = id_fields(path);
+
+            if path.is_empty() {
+                writeln!(f, "impl<H: Transport> Client<H> {{")?;
+            } else {
+                let name = struct_name(path);
+
+                writeln!(
+                    f,
+                    "pub struct {}<'a, H: Transport = ReqwestTransport> {{",
+                    name
+                )?;
+                writeln!(f, "    client: &'a Client<H>,")?;
+                for id in &ids {
+                    writeln!(f, "    {}: i64,", id)?;
+                }
+                writeln!(f, "}}")?;
+                writeln!(f)?;
+                writeln!(f, "impl<'a, H: Transport> {}<'a, H> {{", name)?;
+            }
+
+            let client = if path.is_empty() {
+                "self"
+            } else {
+                "self.client"
+            };
+
+            for child in self.resources.keys() {
+                if child.len() != path.len() + 1 || !child.starts_with(path) {
+                    continue;
+                }
+
+                let segment = &child[path.len()];
+                let child_ids = id_fields(child);
+
+                write!(
+                    f,
+                    "pub fn {}(&self{}) -> {}<'_, H> {{",
+                    accessor_name(path, segment),
+                    if segment.member { ", id: i64" } else { "" },
+                    struct_name(child)
+                )?;
+                write!(f, "{} {{ client: {},", struct_name(child), client)?;
+                for (i, id) in child_ids.iter().enumerate() {
+                    if segment.member && i == child_ids.len() - 1 {
+                        write!(f, "{}: id,", id)?;
+                    } else {
+                        write!(f, "{0}: self.{0},", id)?;
+                    }
+                }
+                writeln!(f, "}} }}")?;
+                writeln!(f)?;
+            }
+
+            for method in &resource.methods {
+                write!(f, "{}", MethodDisplay(method, &ids, client))?;
+            }
+
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+struct MethodDisplay<'a>(&'a ResourceMethod<'a>, &'a [String], &'a str);
+
+impl<'a> fmt::Display for MethodDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let MethodDisplay(method, ids, client) = *self;
+        let api_endpoint = method.api_endpoint;
+        let endpoint_name = api_endpoint.name.to_upper_camel_case();
+        let parameters_name = format!("{}Parameters", endpoint_name);
+        let parameter_fields = group_fields_by_prefix(&api_endpoint.parameter.fields.parameter);
+        let fields = ApiStruct::parameters(&endpoint_name, &api_endpoint.group, &parameter_fields)
+            .defs()
+            .into_iter()
+            .find(|def| def.name == parameters_name)
+            .map(|def| def.fields)
+            .unwrap_or_default();
+        let args = fields
+            .iter()
+            .filter(|field| !method.url_params.contains(&field.name))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", DocComment(&api_endpoint.description))?;
+        if args.len() >= 7 {
+            writeln!(f, "#[allow(clippy::too_many_arguments)]")?;
+        }
+        write!(f, "pub async fn {}(&self", method.name)?;
+        for arg in &args {
+            write!(f, ", {}: {}", arg.name, arg.type_name)?;
+        }
+        write!(f, ") -> Result<")?;
+        if api_endpoint.success.is_file() {
+            write!(f, "Download")?;
+        } else if api_endpoint.success.is_empty() {
+            write!(f, "()")?;
+        } else {
+            write!(f, "{}Success", endpoint_name)?;
+        }
+        writeln!(f, ", SharesightReqwestError> {{")?;
+
+        if fields.is_empty() {
+            writeln!(f, "let parameters = ();")?;
+        } else {
+            writeln!(f, "let parameters = {} {{", parameters_name)?;
+            for field in &fields {
+                match method.url_params.iter().position(|p| *p == field.name) {
+                    Some(i) => writeln!(f, "{}: {},", field.name, IdExpr(field, &ids[i]))?,
+                    None => writeln!(f, "{},", field.name)?,
+                }
+            }
+            writeln!(f, "}};")?;
+        }
+
+        if api_endpoint.success.is_file() {
+            writeln!(
+                f,
+                "{}.download::<{}>(&parameters).await",
+                client, endpoint_name
+            )?;
+        } else {
+            writeln!(
+                f,
+                "{}.execute::<{}, _>(&parameters).await",
+                client, endpoint_name
+            )?;
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+
+        Ok(())
+    }
+}
+
+/// The id held by a resource, converted to the type of the URL parameter.
+struct IdExpr<'a>(&'a StructFieldDef, &'a str);
+
+impl<'a> fmt::Display for IdExpr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let IdExpr(field, id) = *self;
+
+        if field.type_name == "String" {
+            write!(f, "self.{}.to_string()", id)
+        } else {
+            write!(f, "self.{}", id)
+        }
+    }
+}
+
+#[cfg(test)]
+mod resource_path_tests {
+    use super::{accessor_name, resource_path, struct_name, Segment};
+
+    fn segment(collection: &str, member: bool) -> Segment {
+        Segment {
+            collection: collection.to_string(),
+            member,
+        }
+    }
+
+    #[test]
+    fn nested() {
+        let (path, url_params) =
+            resource_path("/cash_accounts/:cash_account_id/cash_account_transactions.json")
+                .unwrap();
+
+        assert_eq!(
+            vec![
+                segment("cash_accounts", true),
+                segment("cash_account_transactions", false)
+            ],
+            path
+        );
+        assert_eq!(vec!["cash_account_id".to_string()], url_params);
+        assert_eq!("transactions", accessor_name(&path[..1], &path[1]));
+        assert_eq!("CashAccountTransactionsResource", struct_name(&path));
+    }
+
+    #[test]
+    fn not_a_path() {
+        assert_eq!(None, resource_path(".1-mobile/identity/by_token.json"));
+    }
+}
@@ -1,13 +1,24 @@
 use std::{collections::BTreeMap, env, fs::File, io::Write, path::PathBuf};
 
 mod api_data;
+mod conversions;
 mod display;
+mod facade;
+mod manifest;
+mod shared;
+mod validation;
 
-use api_data::{ApiData, ApiEndpoint};
+use api_data::{ApiData, ApiEndpoint, EnumValue};
 use clap::Parser;
-use display::ApiEndpointStruct;
+use conversions::Conversions;
+use display::{string_enum_type_for_field, ApiEndpointStruct, ApiEnum, StringEnumType};
+use facade::Facade;
+use heck::ToUpperCamelCase;
 use indexmap::IndexMap;
 use log::info;
+use manifest::Manifest;
+use shared::SharedTypes;
+use validation::Validations;
 
 /// Generate sharesight types from the swagger manifest
 #[derive(Debug, Parser)]
@@ -18,6 +29,10 @@ struct Opt {
     /// The swagger manifest json files
     input: Vec<PathBuf>,
 
+    /// The rust file to generate the resource-oriented client facade into
+    #[clap(long)]
+    facade: Option<PathBuf>,
+
     /// Only generate the endpoints with the names listed
     #[clap(long, short)]
     only: Option<Vec<String>>,
@@ -28,14 +43,14 @@ fn main() -> anyhow::Result<()> {
 
     let opt = Opt::parse();
 
-    let mut f = File::create(opt.output)?;
+    let mut f = File::create(&opt.output)?;
     let mut api_endpoints = Vec::<ApiEndpoint>::new();
 
     for input in &opt.input {
         info!("Reading {:?}", input);
 
         let ApiData { api: endpoints } = serde_json::from_reader::<_, ApiData>(File::open(input)?)?;
-        api_endpoints.extend(endpoints.into_iter());
+        api_endpoints.extend(endpoints);
     }
 
     writeln!(f, "use crate::types_prelude::*;")?;
@@ -52,22 +67,94 @@ fn main() -> anyhow::Result<()> {
         by_version.insert(api_endpoint.version.clone(), api_endpoint);
     }
 
-    for api_endpoint in by_name_and_version
+    let endpoint_names = by_name_and_version.keys().cloned().collect::<Vec<_>>();
+    let api_endpoints = by_name_and_version
         .values_mut()
         .filter_map(|v| v.remove("2.0.0"))
-    {
-        if let Some(ref only) = opt.only {
-            if !only.iter().any(|name| name == &api_endpoint.name) {
-                continue;
+        .filter(|api_endpoint| match opt.only {
+            Some(ref only) => only.iter().any(|name| name == &api_endpoint.name),
+            None => true,
+        })
+        .collect::<Vec<_>>();
+
+    for (name, (description, values)) in inferred_enums(&api_endpoints) {
+        write!(
+            f,
+            "{}",
+            ApiEnum {
+                name: &name,
+                description,
+                values: &values,
             }
-        }
+        )?;
+    }
+
+    let shared_types = SharedTypes::new(&api_endpoints)?;
+    write!(f, "{}", shared_types)?;
+
+    let validations = Validations::new(&api_endpoints, &endpoint_names)?;
+
+    for api_endpoint in &api_endpoints {
+        write!(
+            f,
+            "{}",
+            ApiEndpointStruct(api_endpoint, &shared_types, &validations)
+        )?;
+    }
 
-        write!(f, "{}", ApiEndpointStruct(&api_endpoint))?;
+    write!(f, "{}", Conversions::new(&api_endpoints, &shared_types))?;
+    write!(f, "{}", validations)?;
+    write!(f, "{}", Manifest(&api_endpoints))?;
+
+    if let Some(ref facade) = opt.facade {
+        write!(File::create(facade)?, "{}", Facade::new(&api_endpoints))?;
     }
 
     Ok(())
 }
 
+/// Collect the enums inferred from field descriptions, merging the values of enums with the same
+/// name across endpoints.
+fn inferred_enums(api_endpoints: &[ApiEndpoint]) -> IndexMap<String, (&str, Vec<EnumValue>)> {
+    let mut enums = IndexMap::<String, (&str, Vec<EnumValue>)>::new();
+
+    for api_endpoint in api_endpoints {
+        let endpoint_name = api_endpoint.name.to_upper_camel_case();
+        let fields = api_endpoint
+            .parameter
+            .fields
+            .parameter
+            .iter()
+            .chain(api_endpoint.success.api_fields())
+            .filter(|field| field.field_type.is_string());
+
+        for field in fields {
+            if let Some(StringEnumType::Inferred(name, values)) =
+                string_enum_type_for_field(field, &endpoint_name, &api_endpoint.group)
+            {
+                let (_, known_values) = enums
+                    .entry(name)
+                    .or_insert_with(|| (&field.description, Vec::new()));
+
+                for value in values {
+                    match known_values.iter_mut().find(|v| v.name == value.name) {
+                        Some(known) => {
+                            for alias in value.aliases {
+                                if !known.aliases.contains(&alias) {
+                                    known.aliases.push(alias);
+                                }
+                            }
+                        }
+                        None => known_values.push(value),
+                    }
+                }
+            }
+        }
+    }
+
+    enums
+}
+
 fn init_logger() {
     if Err(env::VarError::NotPresent) == env::var("RUST_LOG") {
         env::set_var("RUST_LOG", "warn");
@@ -0,0 +1,66 @@
+use std::fmt;
+
+use heck::ToUpperCamelCase;
+
+use crate::api_data::{ApiEndpoint, FieldType, FieldTypeBase};
+
+/// The `API_ENDPOINTS` table describing each endpoint and its parameter fields, so endpoints
+/// chosen at run time can be called.
+pub struct Manifest<'a>(pub &'a [ApiEndpoint]);
+
+impl<'a> fmt::Display for Manifest<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(api_endpoints) = self;
+
+        writeln!(f, "pub const API_ENDPOINTS: &[EndpointManifest] = &[")?;
+
+        for api_endpoint in api_endpoints.iter() {
+            writeln!(f, "    EndpointManifest {{")?;
+            writeln!(
+                f,
+                "        name: {:?},",
+                api_endpoint.name.to_upper_camel_case()
+            )?;
+            writeln!(
+                f,
+                "        http_method: ApiHttpMethod::{},",
+                api_endpoint.method.api_http_method()
+            )?;
+            writeln!(f, "        url_path: {:?},", api_endpoint.url)?;
+            writeln!(f, "        parameters: &[")?;
+
+            for field in &api_endpoint.parameter.fields.parameter {
+                writeln!(f, "            ParameterManifest {{")?;
+                writeln!(f, "                field: {:?},", field.field.join("."))?;
+                writeln!(
+                    f,
+                    "                kind: ParameterKind::{},",
+                    parameter_kind(&field.field_type)
+                )?;
+                writeln!(f, "                array: {},", field.field_type.is_array())?;
+                writeln!(f, "                optional: {},", field.optional)?;
+                writeln!(f, "            }},")?;
+            }
+
+            writeln!(f, "        ],")?;
+            writeln!(f, "        file: {},", api_endpoint.success.is_file())?;
+            writeln!(f, "    }},")?;
+        }
+
+        writeln!(f, "];")?;
+        writeln!(f)
+    }
+}
+
+fn parameter_kind(field_type: &FieldType) -> &'static str {
+    let (FieldType::Scalar(base) | FieldType::Array(base)) = field_type;
+
+    match base {
+        FieldTypeBase::Integer => "Integer",
+        FieldTypeBase::Number => "Number",
+        FieldTypeBase::Boolean => "Boolean",
+        FieldTypeBase::Date => "Date",
+        base if base.is_hash() => "Object",
+        _ => "String",
+    }
+}
@@ -0,0 +1,493 @@
+use std::{collections::HashMap, fmt};
+
+use anyhow::bail;
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use indexmap::IndexMap;
+use log::{debug, info, warn};
+
+use crate::{
+    api_data::{group_fields_by_prefix, ApiEndpoint},
+    display::{ApiStruct, StructDef, StructFieldDef},
+};
+
+/// The domain types shared between endpoints. Identical success structs describing the same
+/// entity are merged into the domain type their entity name ends with, such as `Parcel` for the
+/// `short_term_parcels` of a capital gains report.
+const SHARED_TYPES: &[&str] = &["Trade", "Payout", "Portfolio", "CashAccount", "Parcel"];
+
+/// Structs with fewer fields than this can't be told apart by their fields, so are never merged.
+const MIN_FIELDS: usize = 5;
+
+/// The success structs of all endpoints, with the identical structs describing the same entity
+/// merged into a single domain type.
+pub struct SharedTypes {
+    defs: HashMap<String, StructDef>,
+    /// The singular name of the entity each struct describes, such as `short_term_parcel`.
+    entities: HashMap<String, String>,
+    shared_names: HashMap<String, String>,
+    /// The structs merged into each shared type.
+    shared_members: IndexMap<String, Vec<String>>,
+    shared_defs: IndexMap<String, StructDef>,
+}
+
+impl SharedTypes {
+    pub fn new(api_endpoints: &[ApiEndpoint]) -> anyhow::Result<Self> {
+        let mut defs = HashMap::new();
+        let mut entities = HashMap::new();
+
+        for api_endpoint in api_endpoints {
+            let endpoint_name = api_endpoint.name.to_upper_camel_case();
+            let success_fields = group_fields_by_prefix(api_endpoint.success.api_fields());
+            let struct_defs =
+                ApiStruct::success(&endpoint_name, &api_endpoint.group, &success_fields).defs();
+
+            for (def, (prefix, _)) in struct_defs.into_iter().zip(&success_fields) {
+                let entity = match prefix.last() {
+                    Some(entity) => entity.to_snake_case(),
+                    None => api_endpoint
+                        .group
+                        .trim_start_matches("User_API_")
+                        .to_snake_case(),
+                };
+
+                entities.insert(def.name.clone(), singular(&entity).to_string());
+                defs.insert(def.name.clone(), def);
+            }
+        }
+
+        let mut shared_types = SharedTypes {
+            defs,
+            entities,
+            shared_names: HashMap::new(),
+            shared_members: IndexMap::new(),
+            shared_defs: IndexMap::new(),
+        };
+
+        for members in shared_types.identical() {
+            let shared_name = SHARED_TYPES
+                .iter()
+                .map(|shared_name| {
+                    let count = members
+                        .iter()
+                        .filter(|member| {
+                            shared_types.entities[*member]
+                                .to_upper_camel_case()
+                                .ends_with(shared_name)
+                        })
+                        .count();
+
+                    (count, shared_name)
+                })
+                .filter(|(count, _)| count * 2 > members.len())
+                .max();
+
+            match shared_name {
+                Some((_, shared_name))
+                    if shared_types.shared_members.contains_key(*shared_name) =>
+                {
+                    info!(
+                        "Not sharing {} as {}, it is already shared",
+                        members.join(", "),
+                        shared_name
+                    )
+                }
+                Some((_, shared_name)) => shared_types.share_members(shared_name, members),
+                None => {}
+            }
+        }
+
+        for shared_name in SHARED_TYPES {
+            if !shared_types.shared_members.contains_key(*shared_name) {
+                warn!("Shared type {} has no structs", shared_name);
+            }
+        }
+
+        shared_types.shared_defs = shared_types
+            .shared_members
+            .keys()
+            .map(|shared_name| Ok((shared_name.clone(), shared_types.shared_def(shared_name)?)))
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(shared_types)
+    }
+
+    /// The name of the shared type that replaces the struct, if any.
+    pub fn shared_name(&self, struct_name: &str) -> Option<&str> {
+        self.shared_names.get(struct_name).map(String::as_str)
+    }
+
+    /// The success structs of all endpoints and the shared types, by name.
+    pub fn defs(&self) -> HashMap<String, StructDef> {
+        let mut defs = self.defs.clone();
+
+        for (shared_name, def) in &self.shared_defs {
+            defs.insert(shared_name.clone(), def.clone());
+        }
+
+        defs
+    }
+
+    /// The groups of identical structs describing the same entity, largest first. A struct is
+    /// only added to a group when it is identical to every struct already in it, so a struct that
+    /// conflicts with a group is kept apart from it. Structs too small to compare, or identical to
+    /// no other struct, are left out.
+    fn identical(&self) -> Vec<Vec<String>> {
+        let mut names = self
+            .defs
+            .iter()
+            .filter(|(_, def)| def.fields.len() >= MIN_FIELDS)
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        names.sort_by(|a, b| {
+            self.defs[b]
+                .fields
+                .len()
+                .cmp(&self.defs[a].fields.len())
+                .then_with(|| a.cmp(b))
+        });
+
+        let mut groups = Vec::<Vec<String>>::new();
+        for name in names {
+            let entity = self.entities[&name].rsplit('_').next();
+            let group = groups.iter_mut().find(|members| {
+                members.iter().all(|member| {
+                    self.entities[member].rsplit('_').next() == entity
+                        && self.conflict(member, &name).is_none()
+                })
+            });
+
+            match group {
+                Some(members) => members.push(name),
+                None => {
+                    let same_entity = groups
+                        .iter()
+                        .filter(|members| self.entities[&members[0]].rsplit('_').next() == entity);
+                    for members in same_entity {
+                        if let Some(conflict) = self.conflict(&members[0], &name) {
+                            debug!("Not merging {} with {}: {}", name, members[0], conflict);
+                        }
+                    }
+                    groups.push(vec![name]);
+                }
+            }
+        }
+
+        groups.retain(|members| members.len() > 1);
+        for members in groups.iter_mut() {
+            members.sort();
+        }
+        groups.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+        groups
+    }
+
+    /// How the two structs differ, if they can't be merged. They can be when every field they
+    /// have in common has the same type, including whether it is optional, and every field only
+    /// one of them has is optional, so merging them makes no field optional that wasn't already.
+    /// The structs nested within them are compared in the same way.
+    fn conflict(&self, a: &str, b: &str) -> Option<String> {
+        let (a_def, b_def) = (&self.defs[a], &self.defs[b]);
+
+        for field in &a_def.fields {
+            let other = match b_def.fields.iter().find(|f| f.name == field.name) {
+                Some(other) => other,
+                None if field.is_option() => continue,
+                None => return Some(format!("{} requires {}", a, field.name)),
+            };
+
+            if field_type(field) != field_type(other) || field.attributes != other.attributes {
+                return Some(format!(
+                    "{}.{} is {} and {}.{} is {}",
+                    a, field.name, field.type_name, b, other.name, other.type_name
+                ));
+            }
+
+            match (&field.nested, &other.nested) {
+                (Some(nested), Some(other_nested))
+                    if self.defs.contains_key(nested) && self.defs.contains_key(other_nested) =>
+                {
+                    if let Some(conflict) = self.conflict(nested, other_nested) {
+                        return Some(conflict);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        b_def
+            .fields
+            .iter()
+            .find(|field| !field.is_option() && a_def.fields.iter().all(|f| f.name != field.name))
+            .map(|field| format!("{} requires {}", b, field.name))
+    }
+
+    /// Share the given structs, and the structs nested within them, as a single type.
+    fn share_members(&mut self, shared_name: &str, members: Vec<String>) {
+        if self.shared_members.contains_key(shared_name) {
+            warn!("Shared type {} is defined more than once", shared_name);
+            return;
+        }
+
+        let mut nested_members = IndexMap::<String, Vec<String>>::new();
+        for member in &members {
+            self.shared_names
+                .entry(member.clone())
+                .or_insert_with(|| shared_name.to_string());
+
+            for field in &self.defs[member].fields {
+                if let Some(ref nested) = field.nested {
+                    if self.defs.contains_key(nested) {
+                        nested_members
+                            .entry(field.name.clone())
+                            .or_default()
+                            .push(nested.clone());
+                    }
+                }
+            }
+        }
+        self.shared_members.insert(shared_name.to_string(), members);
+
+        for (field_name, nested_members) in nested_members {
+            self.share_members(
+                &format!("{}{}", shared_name, field_name.to_upper_camel_case()),
+                nested_members,
+            );
+        }
+    }
+
+    /// The merged definition of a shared type, with every field of the structs it replaces.
+    /// Generation fails if any of the structs conflict, rather than changing the type of a field
+    /// one of them is documented with.
+    fn shared_def(&self, shared_name: &str) -> anyhow::Result<StructDef> {
+        let mut members = self.shared_members[shared_name].clone();
+        members.sort_by_key(|member| std::cmp::Reverse(self.defs[member].fields.len()));
+
+        for (i, member) in members.iter().enumerate() {
+            for other in &members[i + 1..] {
+                if let Some(conflict) = self.conflict(member, other) {
+                    bail!("Can't share {} as {}: {}", other, shared_name, conflict);
+                }
+            }
+        }
+
+        let mut fields = IndexMap::<&str, &StructFieldDef>::new();
+        for member in &members {
+            for field in &self.defs[member].fields {
+                fields.entry(&field.name).or_insert(field);
+            }
+        }
+
+        let fields = fields
+            .into_values()
+            .map(|field| {
+                let mut field = field.clone();
+                if let Some(nested) = field.nested.take() {
+                    let shared_nested = self.shared_name(&nested).unwrap_or(&nested);
+
+                    field.type_name = field.type_name.replacen(&nested, shared_nested, 1);
+                    field.nested = Some(shared_nested.to_string());
+                }
+
+                field
+            })
+            .collect();
+
+        Ok(StructDef {
+            name: shared_name.to_string(),
+            derives: &["Deserialize"],
+            fields,
+        })
+    }
+}
+
+impl fmt::Display for SharedTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for def in self.shared_defs.values() {
+            write!(f, "{}", def)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The type of a field for comparison, with the name of any nested struct left out.
+fn field_type(field: &StructFieldDef) -> String {
+    match field.nested {
+        Some(ref nested) => field.type_name.replacen(nested, "_", 1),
+        None => field.type_name.clone(),
+    }
+}
+
+fn singular(plural: &str) -> &str {
+    if plural.ends_with("sses") {
+        &plural[..plural.len() - 2]
+    } else if plural.ends_with('s') && !plural.ends_with("ss") {
+        &plural[..plural.len() - 1]
+    } else {
+        plural
+    }
+}
+
+#[cfg(test)]
+mod shared_tests {
+    use serde_json::json;
+
+    use super::SharedTypes;
+    use crate::api_data::ApiEndpoint;
+
+    fn endpoint(name: &str, group: &str, fields: &[(&str, &str, bool)]) -> ApiEndpoint {
+        let fields = fields
+            .iter()
+            .map(|(field, field_type, optional)| {
+                json!({
+                    "group": "200 Success",
+                    "type": field_type,
+                    "optional": optional,
+                    "field": field,
+                    "description": "",
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::from_value(json!({
+            "type": "get",
+            "url": "/test.json",
+            "title": "",
+            "name": name,
+            "group": group,
+            "version": "2.0.0",
+            "description": "",
+            "header": {},
+            "success": { "fields": { "200 Success": fields } },
+            "filename": "",
+            "groupTitle": "",
+        }))
+        .unwrap()
+    }
+
+    fn field_types(shared_types: &SharedTypes, name: &str) -> Vec<(String, String)> {
+        shared_types.defs()[name]
+            .fields
+            .iter()
+            .map(|field| (field.name.clone(), field.type_name.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn merges_identical() {
+        let trade = |value_type, comments_optional| {
+            vec![
+                ("id", "Integer", false),
+                ("quantity", "Number", false),
+                ("price", "Number", false),
+                ("symbol", "String", false),
+                ("comments", "String", comments_optional),
+                ("value", value_type, false),
+                ("confirmed", "Boolean", true),
+            ]
+        };
+
+        let shared_types = SharedTypes::new(&[
+            endpoint(
+                "TradesList",
+                "User_API_Trades",
+                &[
+                    ("trades", "Object[]", false),
+                    ("trades.id", "Integer", false),
+                    ("trades.quantity", "Number", false),
+                    ("trades.price", "Number", false),
+                    ("trades.symbol", "String", false),
+                    ("trades.comments", "String", true),
+                    ("trades.value", "Number", false),
+                ],
+            ),
+            endpoint("TradeShow", "User_API_Trades", &trade("Number", true)),
+            endpoint("TradeConfirm", "User_API_Trades", &trade("String", true)),
+            endpoint("TradeReject", "User_API_Trades", &trade("Number", false)),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            Some("Trade"),
+            shared_types.shared_name("TradesListTradesSuccess")
+        );
+        assert_eq!(Some("Trade"), shared_types.shared_name("TradeShowSuccess"));
+        assert_eq!(None, shared_types.shared_name("TradesListSuccess"));
+        assert_eq!(None, shared_types.shared_name("TradeConfirmSuccess"));
+        assert_eq!(None, shared_types.shared_name("TradeRejectSuccess"));
+
+        let types = field_types(&shared_types, "Trade");
+        let type_of = |name: &str| {
+            types
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, type_name)| type_name.as_str())
+        };
+        assert_eq!(Some("i64"), type_of("id"));
+        assert_eq!(Some("Number"), type_of("quantity"));
+        assert_eq!(Some("Option<String>"), type_of("comments"));
+        assert_eq!(Some("Number"), type_of("value"));
+        assert_eq!(Some("Option<bool>"), type_of("confirmed"));
+    }
+
+    #[test]
+    fn keeps_entities_apart() {
+        let shared_types = SharedTypes::new(&[
+            endpoint(
+                "TradeShow",
+                "User_API_Trades",
+                &[
+                    ("id", "Integer", false),
+                    ("quantity", "Number", false),
+                    ("price", "Number", false),
+                    ("symbol", "String", false),
+                    ("brokerage", "Number", false),
+                    ("paid_on", "Date", false),
+                ],
+            ),
+            endpoint(
+                "PayoutShow",
+                "User_API_Payouts",
+                &[
+                    ("id", "Integer", false),
+                    ("amount", "Number", false),
+                    ("symbol", "String", false),
+                    ("paid_on", "Date", false),
+                    ("brokerage", "Number", false),
+                    ("gross_amount", "Number", false),
+                ],
+            ),
+            endpoint(
+                "MembershipList",
+                "User_API_Memberships",
+                &[
+                    ("memberships", "Object[]", false),
+                    ("memberships.id", "Integer", false),
+                    ("memberships.access_code", "String", false),
+                    ("memberships.portfolio_id", "Integer", false),
+                    ("memberships.user_id", "Integer", false),
+                    ("memberships.email", "String", false),
+                ],
+            ),
+            endpoint(
+                "MembershipShow",
+                "User_API_Memberships",
+                &[
+                    ("id", "Integer", false),
+                    ("access_code", "String", false),
+                    ("portfolio_id", "Integer", false),
+                    ("user_id", "Integer", false),
+                    ("email", "String", false),
+                ],
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(None, shared_types.shared_name("TradeShowSuccess"));
+        assert_eq!(None, shared_types.shared_name("PayoutShowSuccess"));
+        assert_eq!(
+            None,
+            shared_types.shared_name("MembershipListMembershipsSuccess")
+        );
+    }
+}
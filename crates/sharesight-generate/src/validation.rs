@@ -0,0 +1,546 @@
+use std::fmt;
+
+use anyhow::bail;
+use heck::ToUpperCamelCase;
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{
+    api_data::{group_fields_by_prefix, plain_text, ApiEndpoint, Method},
+    display::{ApiStruct, StructDef},
+};
+
+/// Fields that must not be negative, which the API documentation doesn't state, as (endpoint
+/// name, field). Generation fails if an endpoint doesn't have the field, so the list can't go
+/// stale when the documentation changes.
+const NON_NEGATIVE_FIELDS: &[(&str, &str)] = &[
+    ("PayoutCreate", "payout.amount"),
+    ("PayoutUpdate", "payout.amount"),
+    ("TradesCreate", "trade.price"),
+    ("TradesCreate", "trade.brokerage"),
+    ("TradesUpdate", "trade.price"),
+    ("TradesUpdate", "trade.brokerage"),
+];
+
+/// A rule stated by the description of a parameter field.
+#[derive(Debug, PartialEq, Eq)]
+enum FieldRule {
+    /// The field must be set when the other field is set, for example `This parameter is required
+    /// if attachment is set.`
+    RequiredWith(String),
+    /// The other field must be set when the field is set, for example `must be accompanied by
+    /// market`.
+    AccompaniedBy(String),
+    /// The field, or another field with the same rule, must be set unless the other field is set,
+    /// for example `Required unless you specify a holding_id.`
+    RequiredUnless(String),
+    /// The field has a maximum length, for example `a string of up to 255 characters`.
+    MaxLength(usize),
+    /// The field must be a whole number unless the market allows decimal quantities, for example
+    /// `Must be a whole number unless the market allows fractional quantities to be traded`.
+    WholeUnlessMarketAllows,
+    /// The field must be the currency of the portfolio or of the instrument, for example `must be
+    /// either Portfolio or Instrument currency`.
+    PortfolioOrInstrumentCurrency,
+}
+
+/// `Validate` implementations for the parameters of the endpoints that create or update records.
+pub struct Validations {
+    checks: IndexMap<String, Vec<String>>,
+    quantity_checks: IndexMap<String, Vec<String>>,
+    currency_checks: IndexMap<String, Vec<String>>,
+}
+
+impl Validations {
+    /// The validations of the endpoints, which are among the endpoints with the given names.
+    pub fn new(api_endpoints: &[ApiEndpoint], endpoint_names: &[String]) -> anyhow::Result<Self> {
+        let non_negative_fields = non_negative_fields(api_endpoints, endpoint_names)?;
+        let mut checks = IndexMap::new();
+        let mut quantity_checks = IndexMap::new();
+        let mut currency_checks = IndexMap::new();
+
+        for api_endpoint in api_endpoints {
+            if !matches!(api_endpoint.method, Method::Post | Method::Put) {
+                continue;
+            }
+
+            let endpoint_name = api_endpoint.name.to_upper_camel_case();
+            let parameter_fields = group_fields_by_prefix(&api_endpoint.parameter.fields.parameter);
+            let defs =
+                ApiStruct::parameters(&endpoint_name, &api_endpoint.group, &parameter_fields)
+                    .defs();
+
+            // Nested structs follow the struct containing them.
+            for def in defs.iter().rev() {
+                let def_checks = struct_checks(def, &checks, &non_negative_fields);
+                if !def_checks.is_empty() {
+                    checks.insert(def.name.clone(), def_checks);
+                }
+
+                let def_quantity_checks = quantity_checks_of(def, &quantity_checks);
+                if !def_quantity_checks.is_empty() {
+                    quantity_checks.insert(def.name.clone(), def_quantity_checks);
+                }
+
+                let def_currency_checks = currency_checks_of(def, &currency_checks);
+                if !def_currency_checks.is_empty() {
+                    currency_checks.insert(def.name.clone(), def_currency_checks);
+                }
+            }
+        }
+
+        Ok(Validations {
+            checks,
+            quantity_checks,
+            currency_checks,
+        })
+    }
+
+    /// Whether the parameters struct implements `Validate`.
+    pub fn contains(&self, struct_name: &str) -> bool {
+        self.checks.contains_key(struct_name)
+            || self.contains_quantity(struct_name)
+            || self.contains_currency(struct_name)
+    }
+
+    /// Whether the parameters struct checks its quantities against the markets allowing decimal
+    /// quantities.
+    pub fn contains_quantity(&self, struct_name: &str) -> bool {
+        self.quantity_checks.contains_key(struct_name)
+    }
+
+    /// Whether the parameters struct checks its brokerage currencies against the currencies of
+    /// the portfolio and instrument.
+    pub fn contains_currency(&self, struct_name: &str) -> bool {
+        self.currency_checks.contains_key(struct_name)
+    }
+}
+
+/// The fields of `NON_NEGATIVE_FIELDS` as (struct name, field name), failing if one of them isn't
+/// a number parameter of its endpoint. Endpoints left out of generation are skipped, but not
+/// endpoints missing from the documentation.
+fn non_negative_fields(
+    api_endpoints: &[ApiEndpoint],
+    endpoint_names: &[String],
+) -> anyhow::Result<IndexSet<(String, String)>> {
+    let mut fields = IndexSet::new();
+
+    for (endpoint, field) in NON_NEGATIVE_FIELDS {
+        let api_endpoint = match api_endpoints.iter().find(|e| e.name == *endpoint) {
+            Some(api_endpoint) => api_endpoint,
+            None if endpoint_names.iter().any(|name| name == endpoint) => continue,
+            None => bail!("Non-negative field {}.{} has no endpoint", endpoint, field),
+        };
+
+        let parameter = api_endpoint
+            .parameter
+            .fields
+            .parameter
+            .iter()
+            .find(|parameter| parameter.field.join(".") == *field);
+        match parameter {
+            Some(parameter) if parameter.field_type.is_number() => {}
+            Some(_) => bail!("Non-negative field {}.{} isn't a number", endpoint, field),
+            None => bail!(
+                "Non-negative field {}.{} isn't a parameter",
+                endpoint,
+                field
+            ),
+        }
+
+        let mut segments = field.split('.').collect::<Vec<_>>();
+        let name = segments.pop().unwrap_or_default();
+        let struct_name = segments
+            .iter()
+            .fold(endpoint.to_upper_camel_case(), |name, s| {
+                name + &s.to_upper_camel_case()
+            })
+            + "Parameters";
+
+        fields.insert((struct_name, name.to_string()));
+    }
+
+    Ok(fields)
+}
+
+/// The statements checking each field of the struct.
+fn struct_checks(
+    def: &StructDef,
+    checks: &IndexMap<String, Vec<String>>,
+    non_negative_fields: &IndexSet<(String, String)>,
+) -> Vec<String> {
+    let is_option = |name: &str| {
+        def.fields
+            .iter()
+            .any(|field| field.name == name && field.type_name.starts_with("Option<"))
+    };
+    let mut statements = Vec::new();
+    let mut required_unless = IndexMap::<String, Vec<String>>::new();
+
+    for field in &def.fields {
+        let name = &field.name;
+        let optional = is_option(name);
+
+        if non_negative_fields.contains(&(def.name.clone(), name.clone())) {
+            statements.push(if optional {
+                format!(
+                    "if matches!(self.{0}, Some(ref v) if *v < Number::default()) {{ return Err(ValidationError::Negative({0:?})); }}",
+                    name
+                )
+            } else {
+                format!(
+                    "if self.{0} < Number::default() {{ return Err(ValidationError::Negative({0:?})); }}",
+                    name
+                )
+            });
+        }
+
+        for rule in field_rules(&field.description) {
+            match rule {
+                FieldRule::RequiredWith(other) if optional && is_option(&other) => {
+                    statements.push(format!(
+                        "if self.{}.is_some() && self.{1}.is_none() {{ return Err(ValidationError::Missing({1:?})); }}",
+                        other, name
+                    ));
+                }
+                FieldRule::AccompaniedBy(other) if optional && is_option(&other) => {
+                    statements.push(format!(
+                        "if self.{}.is_some() && self.{1}.is_none() {{ return Err(ValidationError::Missing({1:?})); }}",
+                        name, other
+                    ));
+                }
+                FieldRule::RequiredUnless(other) if optional && is_option(&other) => {
+                    required_unless.entry(other).or_default().push(name.clone());
+                }
+                FieldRule::MaxLength(max) if field.type_name.contains("String") => {
+                    statements.push(if optional {
+                        format!(
+                            "if matches!(self.{0}, Some(ref v) if v.chars().count() > {1}) {{ return Err(ValidationError::TooLong({0:?}, {1})); }}",
+                            name, max
+                        )
+                    } else {
+                        format!(
+                            "if self.{0}.chars().count() > {1} {{ return Err(ValidationError::TooLong({0:?}, {1})); }}",
+                            name, max
+                        )
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(ref nested) = field.nested {
+            if checks.contains_key(nested) {
+                statements.push(if field.type_name.starts_with("Vec<") {
+                    format!("for v in &self.{} {{ v.validate()?; }}", name)
+                } else if optional {
+                    format!("if let Some(ref v) = self.{} {{ v.validate()?; }}", name)
+                } else {
+                    format!("self.{}.validate()?;", name)
+                });
+            }
+        }
+    }
+
+    for (other, names) in required_unless {
+        let fields = std::iter::once(&other).chain(&names).collect::<Vec<_>>();
+
+        statements.push(format!(
+            "if {} {{ return Err(ValidationError::MissingOneOf(&{:?})); }}",
+            fields
+                .iter()
+                .map(|name| format!("self.{}.is_none()", name))
+                .collect::<Vec<_>>()
+                .join(" && "),
+            fields
+        ));
+    }
+
+    statements
+}
+
+/// The statements checking that the quantities are whole numbers unless their market is one of
+/// `decimal_markets`. Which markets allow decimal quantities is only known from the API at run
+/// time, so these are separate from `struct_checks`. A quantity is only checked alongside a
+/// `market`, as the market of a `holding_id` is only known by looking up the holding.
+fn quantity_checks_of(
+    def: &StructDef,
+    quantity_checks: &IndexMap<String, Vec<String>>,
+) -> Vec<String> {
+    let field_type = |name: &str| {
+        def.fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(|field| field.type_name.as_str())
+    };
+    let value = |name: &str| match field_type(name) {
+        Some(type_name) if type_name.starts_with("Option<") => format!("self.{}.as_ref()", name),
+        _ => format!("Some(&self.{})", name),
+    };
+    let mut statements = Vec::new();
+
+    for field in &def.fields {
+        let name = &field.name;
+
+        let whole_unless_market_allows =
+            field_rules(&field.description).contains(&FieldRule::WholeUnlessMarketAllows);
+        let has_market = matches!(
+            field_type("market"),
+            Some("Market") | Some("Option<Market>")
+        );
+        if whole_unless_market_allows && has_market {
+            statements.push(format!(
+                "if let (Some(market), Some(v)) = ({}, {}) {{ if !is_whole(v) && !decimal_markets.contains(market) {{ return Err(ValidationError::NotWhole({:?})); }} }}",
+                value("market"),
+                value(name),
+                name
+            ));
+        }
+
+        if let Some(ref nested) = field.nested {
+            if quantity_checks.contains_key(nested) {
+                statements.push(if field.type_name.starts_with("Vec<") {
+                    format!(
+                        "for v in &self.{} {{ v.validate_quantity(decimal_markets)?; }}",
+                        name
+                    )
+                } else if field.type_name.starts_with("Option<") {
+                    format!(
+                        "if let Some(ref v) = self.{} {{ v.validate_quantity(decimal_markets)?; }}",
+                        name
+                    )
+                } else {
+                    format!("self.{}.validate_quantity(decimal_markets)?;", name)
+                });
+            }
+        }
+    }
+
+    statements
+}
+
+/// The statements checking that the brokerage currencies are among `currencies`, the currencies
+/// of the portfolio and the instrument. These are only known from the API at run time, so these
+/// are separate from `struct_checks`.
+fn currency_checks_of(
+    def: &StructDef,
+    currency_checks: &IndexMap<String, Vec<String>>,
+) -> Vec<String> {
+    let mut statements = Vec::new();
+
+    for field in &def.fields {
+        let name = &field.name;
+
+        if field_rules(&field.description).contains(&FieldRule::PortfolioOrInstrumentCurrency) {
+            match field.type_name.as_str() {
+                "Currency" => statements.push(format!(
+                    "if !currencies.contains(&self.{0}) {{ return Err(ValidationError::Currency({0:?})); }}",
+                    name
+                )),
+                "Option<Currency>" => statements.push(format!(
+                    "if matches!(self.{0}, Some(ref v) if !currencies.contains(v)) {{ return Err(ValidationError::Currency({0:?})); }}",
+                    name
+                )),
+                _ => {}
+            }
+        }
+
+        if let Some(ref nested) = field.nested {
+            if currency_checks.contains_key(nested) {
+                statements.push(if field.type_name.starts_with("Vec<") {
+                    format!(
+                        "for v in &self.{} {{ v.validate_brokerage_currency(currencies)?; }}",
+                        name
+                    )
+                } else if field.type_name.starts_with("Option<") {
+                    format!(
+                        "if let Some(ref v) = self.{} {{ v.validate_brokerage_currency(currencies)?; }}",
+                        name
+                    )
+                } else {
+                    format!("self.{}.validate_brokerage_currency(currencies)?;", name)
+                });
+            }
+        }
+    }
+
+    statements
+}
+
+/// The rules stated by a field description.
+fn field_rules(description: &str) -> Vec<FieldRule> {
+    let text = plain_text(description).to_ascii_lowercase();
+    let mut rules = Vec::new();
+
+    if let Some((other, rest)) = word_after(&text, "required if ") {
+        if rest.starts_with(" is set") {
+            rules.push(FieldRule::RequiredWith(other));
+        }
+    }
+
+    if let Some((other, _)) = word_after(&text, "must be accompanied by ") {
+        rules.push(FieldRule::AccompaniedBy(other));
+    }
+
+    if let Some((other, rest)) = word_after(&text, "required unless you specify a ") {
+        if rest.is_empty() || rest.starts_with('.') || rest.starts_with(" or ") {
+            rules.push(FieldRule::RequiredUnless(other));
+        }
+    }
+
+    if let Some((max, rest)) = word_after(&text, "up to ") {
+        if let (Ok(max), true) = (max.parse(), rest.starts_with(" characters")) {
+            rules.push(FieldRule::MaxLength(max));
+        }
+    }
+
+    if text.contains("whole number unless the market allows fractional quantities") {
+        rules.push(FieldRule::WholeUnlessMarketAllows);
+    }
+
+    if text.contains("must be either portfolio or instrument currency") {
+        rules.push(FieldRule::PortfolioOrInstrumentCurrency);
+    }
+
+    rules
+}
+
+/// The word following the pattern in the text, and the text after the word.
+fn word_after<'a>(text: &'a str, pattern: &str) -> Option<(String, &'a str)> {
+    let start = text.find(pattern)? + pattern.len();
+    let rest = &text[start..];
+    let end = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+
+    if end == 0 {
+        None
+    } else {
+        Some((rest[..end].to_string(), &rest[end..]))
+    }
+}
+
+impl fmt::Display for Validations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let struct_names = self
+            .checks
+            .keys()
+            .chain(self.quantity_checks.keys())
+            .chain(self.currency_checks.keys())
+            .collect::<IndexSet<_>>();
+
+        for struct_name in struct_names {
+            writeln!(f, "impl Validate for {} {{", struct_name)?;
+            writeln!(
+                f,
+                "    fn validate(&self) -> Result<(), ValidationError> {{"
+            )?;
+            for statement in self.checks.get(struct_name).into_iter().flatten() {
+                writeln!(f, "        {}", statement)?;
+            }
+            writeln!(f, "        Ok(())")?;
+            writeln!(f, "    }}")?;
+            if let Some(statements) = self.quantity_checks.get(struct_name) {
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "    fn validate_quantity(&self, decimal_markets: &[Market]) -> Result<(), ValidationError> {{"
+                )?;
+                for statement in statements {
+                    writeln!(f, "        {}", statement)?;
+                }
+                writeln!(f, "        Ok(())")?;
+                writeln!(f, "    }}")?;
+            }
+            if let Some(statements) = self.currency_checks.get(struct_name) {
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "    fn validate_brokerage_currency(&self, currencies: &[Currency]) -> Result<(), ValidationError> {{"
+                )?;
+                for statement in statements {
+                    writeln!(f, "        {}", statement)?;
+                }
+                writeln!(f, "        Ok(())")?;
+                writeln!(f, "    }}")?;
+            }
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod field_rules_tests {
+    use super::{field_rules, non_negative_fields, FieldRule};
+
+    #[test]
+    fn instrument_lookup() {
+        assert_eq!(
+            vec![
+                FieldRule::AccompaniedBy("market".to_string()),
+                FieldRule::RequiredUnless("holding_id".to_string())
+            ],
+            field_rules(
+                "<p>Code to look up the Instrument; must be accompanied by market. Required unless you specify a holding_id.</p>"
+            )
+        );
+        assert_eq!(
+            vec![FieldRule::AccompaniedBy("symbol".to_string())],
+            field_rules(
+                "<p>Market Country Code to look up the Instrument; must be accompanied by symbol.  Required unless you specify a symbol with market, or a holding_id.</p>"
+            )
+        );
+    }
+
+    #[test]
+    fn attachment() {
+        assert_eq!(
+            vec![FieldRule::RequiredWith("attachment".to_string())],
+            field_rules(
+                "<p>File name for the attachment. This parameter is required if attachment is set.</p>"
+            )
+        );
+        assert_eq!(
+            vec![FieldRule::MaxLength(255)],
+            field_rules("<p>This is a string of up to 255 characters used to identify duplicate trades.</p>")
+        );
+    }
+
+    #[test]
+    fn non_negative_fields_need_endpoints() {
+        assert!(non_negative_fields(&[], &[]).is_err());
+
+        let names = [
+            "PayoutCreate",
+            "PayoutUpdate",
+            "TradesCreate",
+            "TradesUpdate",
+        ]
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+        assert!(non_negative_fields(&[], &names).unwrap().is_empty());
+    }
+
+    #[test]
+    fn brokerage_currency() {
+        assert_eq!(
+            vec![FieldRule::PortfolioOrInstrumentCurrency],
+            field_rules(
+                "<p>The ISO code of the brokerage currency, must be either Portfolio or Instrument currency. If the instrument is a cryptocurrency, any valid brokerage currency is supported.</p>"
+            )
+        );
+    }
+
+    #[test]
+    fn quantity() {
+        assert_eq!(
+            vec![FieldRule::WholeUnlessMarketAllows],
+            field_rules(
+                "<p>Number of units in the transaction. Must be a whole number unless the market allows fractional quantities to be traded</p>"
+            )
+        );
+    }
+}
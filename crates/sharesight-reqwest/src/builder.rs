@@ -0,0 +1,184 @@
+use std::{path::PathBuf, time::Duration};
+
+use sharesight_types::{api_base_url, Market, Transport, DEFAULT_API_HOST};
+
+use crate::{Client, Recorder, Replayer, ReqwestTransport, RetryPolicy, SharesightReqwestError};
+
+/// Configures a client, for example
+/// `Client::builder(token).base_url("http://127.0.0.1:3000/api/v2").timeout(..).build()?`.
+pub struct ClientBuilder {
+    access_token: String,
+    base_url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<reqwest::Proxy>,
+    client: Option<reqwest::Client>,
+    validate: bool,
+    decimal_markets: Option<Vec<Market>>,
+    retry: RetryPolicy,
+}
+
+impl ClientBuilder {
+    pub fn new(access_token: String) -> Self {
+        ClientBuilder {
+            access_token,
+            base_url: api_base_url(DEFAULT_API_HOST),
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxy: None,
+            client: None,
+            validate: true,
+            decimal_markets: None,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Use the API served by the host, such as `api.sharesight.com`.
+    pub fn api_host(mut self, api_host: &str) -> Self {
+        self.base_url = api_base_url(api_host);
+        self
+    }
+
+    /// Use the API at the base URL, including the scheme and path prefix, such as
+    /// `http://127.0.0.1:3000/api/v2` for a stub or the URL of a gateway.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// The time allowed for each request, from connecting until the response has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The time allowed for connecting to the API.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Send requests with the `reqwest` client, which already has its own timeouts, user agent
+    /// and proxy, so they can't also be set by the builder.
+    pub fn reqwest_client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Whether parameters are checked locally before they are sent, which is on by default.
+    pub fn validation(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// The markets allowing decimal quantities, so validation checks the quantities of trades on
+    /// the other markets are whole numbers.
+    pub fn decimal_markets(mut self, decimal_markets: Vec<Market>) -> Self {
+        self.decimal_markets = Some(decimal_markets);
+        self
+    }
+
+    /// When requests are sent again after failing. Requests aren't retried by default.
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn build(self) -> Result<Client, SharesightReqwestError> {
+        let transport = self.reqwest_transport()?;
+
+        self.build_with_transport(transport)
+    }
+
+    /// Build a client that sends requests to the API and records them to the cassette file.
+    pub fn build_recording<P: Into<PathBuf>>(
+        self,
+        path: P,
+    ) -> Result<Client<Recorder<ReqwestTransport>>, SharesightReqwestError> {
+        let transport = Recorder::new(self.reqwest_transport()?, path);
+
+        self.build_with_transport(transport)
+    }
+
+    /// Build a client that answers requests from the cassette file instead of the API.
+    pub fn build_replaying<P: Into<PathBuf>>(
+        self,
+        path: P,
+        strict: bool,
+    ) -> Result<Client<Replayer>, SharesightReqwestError> {
+        let transport = Replayer::load(path.into(), strict)?;
+
+        self.build_with_transport(transport)
+    }
+
+    fn reqwest_transport(&self) -> Result<ReqwestTransport, SharesightReqwestError> {
+        let client = match self.client {
+            Some(ref client) => {
+                if self.timeout.is_some()
+                    || self.connect_timeout.is_some()
+                    || self.user_agent.is_some()
+                    || self.proxy.is_some()
+                {
+                    return Err(SharesightReqwestError::Config(
+                        "timeouts, user agent and proxy can't be set with a reqwest client"
+                            .to_string(),
+                    ));
+                }
+
+                client.clone()
+            }
+            None => {
+                let mut builder = reqwest::Client::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                if let Some(ref user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                if let Some(ref proxy) = self.proxy {
+                    builder = builder.proxy(proxy.clone());
+                }
+
+                builder.build()?
+            }
+        };
+
+        Ok(ReqwestTransport::new(client))
+    }
+
+    /// Build a client sending its requests with the transport. Only the base URL, access token,
+    /// retry policy and validation, including the decimal markets, apply to it.
+    pub fn build_with_transport<H: Transport>(
+        self,
+        transport: H,
+    ) -> Result<Client<H>, SharesightReqwestError> {
+        reqwest::Url::parse(&self.base_url).map_err(|e| {
+            SharesightReqwestError::Config(format!("invalid base URL {}: {}", self.base_url, e))
+        })?;
+
+        let client =
+            Client::new_with_transport_and_base_url(transport, self.access_token, self.base_url)
+                .with_validation(self.validate)
+                .with_retry(self.retry);
+
+        Ok(match self.decimal_markets {
+            Some(decimal_markets) => client.with_decimal_markets(decimal_markets),
+            None => client,
+        })
+    }
+}
@@ -0,0 +1,504 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    error::Error,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sharesight_types::{ApiHttpMethod, HttpRequest, HttpResponse, Transport, TransportFuture};
+
+use crate::Client;
+
+/// How long a response is used without asking the API again, when it has no `Cache-Control`
+/// header saying otherwise.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Where cached responses are kept.
+pub enum CacheStorage {
+    Memory(Mutex<HashMap<String, CacheEntry>>),
+    /// A directory with a JSON file for each response. The files hold the portfolio data of the
+    /// responses in plain text, so the directory should only be readable by its user.
+    Disk(PathBuf),
+}
+
+impl CacheStorage {
+    pub fn memory() -> Self {
+        CacheStorage::Memory(Mutex::default())
+    }
+
+    pub fn disk<P: Into<PathBuf>>(dir: P) -> Self {
+        CacheStorage::Disk(dir.into())
+    }
+
+    fn entry_path(dir: &std::path::Path, key: &str) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+
+        dir.join(format!("{:016x}.json", hasher.finish()))
+    }
+
+    async fn get(&self, key: &str) -> Option<CacheEntry> {
+        match self {
+            CacheStorage::Memory(entries) => entries.lock().unwrap().get(key).cloned(),
+            CacheStorage::Disk(dir) => {
+                let bytes = tokio::fs::read(Self::entry_path(dir, key)).await.ok()?;
+                serde_json::from_slice::<CacheEntry>(&bytes)
+                    .ok()
+                    .filter(|entry| entry.key == key)
+            }
+        }
+    }
+
+    async fn insert(&self, entry: CacheEntry) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match self {
+            CacheStorage::Memory(entries) => {
+                entries.lock().unwrap().insert(entry.key.clone(), entry);
+            }
+            CacheStorage::Disk(dir) => {
+                tokio::fs::create_dir_all(dir).await?;
+                tokio::fs::write(
+                    Self::entry_path(dir, &entry.key),
+                    serde_json::to_vec(&entry)?,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove the entries the predicate matches.
+    async fn remove_where<F: Fn(&CacheEntry) -> bool>(
+        &self,
+        matches: F,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match self {
+            CacheStorage::Memory(entries) => {
+                entries.lock().unwrap().retain(|_, entry| !matches(entry));
+            }
+            CacheStorage::Disk(dir) => {
+                let mut read_dir = match tokio::fs::read_dir(dir).await {
+                    Ok(read_dir) => read_dir,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+                    Err(e) => return Err(e.into()),
+                };
+
+                while let Some(file) = read_dir.next_entry().await? {
+                    let entry = tokio::fs::read(file.path())
+                        .await
+                        .ok()
+                        .and_then(|bytes| serde_json::from_slice::<CacheEntry>(&bytes).ok());
+
+                    if entry.map_or(true, |entry| matches(&entry)) {
+                        tokio::fs::remove_file(file.path()).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A cached response to a `GET` request.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CacheEntry {
+    key: String,
+    account: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    /// Seconds since the Unix epoch after which the response is revalidated.
+    expires_at: u64,
+}
+
+impl CacheEntry {
+    fn response(&self) -> HttpResponse {
+        HttpResponse {
+            status: self.status,
+            headers: self.headers.clone(),
+            body: self.body.clone(),
+        }
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Caches the responses to `GET` requests sent with another transport, such as slow reports.
+///
+/// Responses are cached for each access token, so clients of different accounts can share the
+/// storage. A cached response is used until it expires, after the `max-age` of its
+/// `Cache-Control` header or the TTL of the cache. It is then revalidated with its `ETag` or
+/// `Last-Modified` header when it has one. A successful change by an access token removes all of
+/// its cached responses, as a change such as a trade affects holdings, cash accounts and reports
+/// throughout the account.
+pub struct Cache<H> {
+    transport: H,
+    storage: CacheStorage,
+    ttl: Duration,
+}
+
+impl<H> Cache<H> {
+    pub fn new(transport: H, storage: CacheStorage) -> Self {
+        Cache {
+            transport,
+            storage,
+            ttl: DEFAULT_CACHE_TTL,
+        }
+    }
+
+    /// How long responses without a `Cache-Control` `max-age` are used.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+}
+
+impl<H: Transport + Sync> Cache<H> {
+    async fn get(
+        &self,
+        mut request: HttpRequest,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        let account = account(&request);
+        let key = format!(
+            "{} {} {}",
+            account,
+            request.url,
+            String::from_utf8_lossy(&request.body)
+        );
+        let cached = self.storage.get(&key).await;
+
+        if let Some(ref entry) = cached {
+            if now() < entry.expires_at {
+                return Ok(entry.response());
+            }
+
+            if let Some(etag) = entry.header("etag") {
+                request
+                    .headers
+                    .push(("if-none-match".to_string(), etag.to_string()));
+            }
+            if let Some(last_modified) = entry.header("last-modified") {
+                request
+                    .headers
+                    .push(("if-modified-since".to_string(), last_modified.to_string()));
+            }
+        }
+
+        let response = self.transport.send(request).await.map_err(Into::into)?;
+
+        if let (304, Some(mut entry)) = (response.status, cached) {
+            entry.expires_at = now() + self.fresh_for(&response).unwrap_or(self.ttl).as_secs();
+            self.storage.insert(entry.clone()).await?;
+
+            return Ok(entry.response());
+        }
+
+        let fresh_for = self.fresh_for(&response).unwrap_or(self.ttl);
+
+        if response.status == 200
+            && response
+                .header("cache-control")
+                .map_or(true, |c| !c.contains("no-store"))
+        {
+            self.storage
+                .insert(CacheEntry {
+                    key,
+                    account,
+                    status: response.status,
+                    headers: response.headers.clone(),
+                    body: response.body.clone(),
+                    expires_at: now() + fresh_for.as_secs(),
+                })
+                .await?;
+        }
+
+        Ok(response)
+    }
+
+    /// How long the response can be used for, from its `Cache-Control` header.
+    fn fresh_for(&self, response: &HttpResponse) -> Option<Duration> {
+        let cache_control = response.header("cache-control")?;
+
+        cache_control.split(',').find_map(|directive| {
+            let directive = directive.trim();
+
+            if directive == "no-cache" {
+                Some(Duration::ZERO)
+            } else {
+                directive
+                    .strip_prefix("max-age=")
+                    .and_then(|secs| secs.parse().ok())
+                    .map(Duration::from_secs)
+            }
+        })
+    }
+
+    async fn mutate(
+        &self,
+        request: HttpRequest,
+    ) -> Result<HttpResponse, Box<dyn Error + Send + Sync>> {
+        let account = account(&request);
+        let response = self.transport.send(request).await.map_err(Into::into)?;
+
+        if response.is_success() {
+            self.storage
+                .remove_where(|entry| entry.account == account)
+                .await?;
+        }
+
+        Ok(response)
+    }
+}
+
+impl<H: Transport> Client<H> {
+    /// Cache the responses to `GET` requests, such as slow reports requested repeatedly.
+    pub fn cached(self, storage: CacheStorage) -> Client<Cache<H>> {
+        self.map_transport(|transport| Cache::new(transport, storage))
+    }
+}
+
+impl<H: Transport + Sync> Transport for Cache<H> {
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_, Self::Error> {
+        Box::pin(async move {
+            if request.method == ApiHttpMethod::Get {
+                self.get(request).await
+            } else {
+                self.mutate(request).await
+            }
+        })
+    }
+}
+
+/// The account the request is sent for, as a hash of its `Authorization` header, so the access
+/// token itself isn't stored.
+fn account(request: &HttpRequest) -> String {
+    let authorization = request
+        .headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("authorization"))
+        .map_or("", |(_, value)| value.as_str());
+
+    Sha256::digest(authorization.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod cache_tests {
+    use std::{collections::VecDeque, convert::Infallible, sync::Mutex, time::Duration};
+
+    use sharesight_types::{ApiHttpMethod, HttpRequest, HttpResponse, Transport, TransportFuture};
+
+    use super::{Cache, CacheStorage};
+
+    /// Answers the requests with the responses in order, keeping the requests sent.
+    #[derive(Default)]
+    struct ScriptTransport {
+        responses: Mutex<VecDeque<HttpResponse>>,
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl ScriptTransport {
+        fn new(responses: Vec<HttpResponse>) -> Self {
+            ScriptTransport {
+                responses: Mutex::new(responses.into()),
+                requests: Mutex::default(),
+            }
+        }
+
+        fn sent(&self) -> usize {
+            self.requests.lock().unwrap().len()
+        }
+
+        fn header(&self, i: usize, name: &str) -> Option<String> {
+            self.requests.lock().unwrap()[i]
+                .headers
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, value)| value.clone())
+        }
+    }
+
+    impl Transport for ScriptTransport {
+        type Error = Infallible;
+
+        fn send(&self, request: HttpRequest) -> TransportFuture<'_, Self::Error> {
+            self.requests.lock().unwrap().push(request);
+            let response = self.responses.lock().unwrap().pop_front().unwrap();
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    fn response(status: u16, headers: &[(&str, &str)], body: &str) -> HttpResponse {
+        HttpResponse {
+            status,
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    fn request(method: ApiHttpMethod, token: &str, url: &str) -> HttpRequest {
+        HttpRequest {
+            method,
+            url: url.to_string(),
+            headers: vec![("authorization".to_string(), format!("Bearer {}", token))],
+            body: b"null".to_vec(),
+        }
+    }
+
+    const HOLDINGS: &str = "https://api.sharesight.com/api/v2/portfolios/1/holdings.json";
+
+    #[tokio::test]
+    async fn hit() {
+        let cache = Cache::new(
+            ScriptTransport::new(vec![
+                response(200, &[], "first"),
+                response(200, &[], "other account"),
+            ]),
+            CacheStorage::memory(),
+        );
+
+        for _ in 0..2 {
+            let resp = cache
+                .send(request(ApiHttpMethod::Get, "a", HOLDINGS))
+                .await
+                .unwrap();
+            assert_eq!(b"first".to_vec(), resp.body);
+        }
+        assert_eq!(1, cache.transport.sent());
+
+        let resp = cache
+            .send(request(ApiHttpMethod::Get, "b", HOLDINGS))
+            .await
+            .unwrap();
+        assert_eq!(b"other account".to_vec(), resp.body);
+        assert_eq!(2, cache.transport.sent());
+    }
+
+    #[tokio::test]
+    async fn expiry_and_revalidation() {
+        let cache = Cache::new(
+            ScriptTransport::new(vec![
+                response(200, &[("ETag", "\"v1\"")], "first"),
+                response(304, &[], ""),
+                response(200, &[("ETag", "\"v2\"")], "second"),
+            ]),
+            CacheStorage::memory(),
+        )
+        .with_ttl(Duration::ZERO);
+
+        for body in &["first", "first", "second"] {
+            let resp = cache
+                .send(request(ApiHttpMethod::Get, "a", HOLDINGS))
+                .await
+                .unwrap();
+            assert_eq!(body.as_bytes().to_vec(), resp.body);
+        }
+
+        assert_eq!(3, cache.transport.sent());
+        assert_eq!(None, cache.transport.header(0, "if-none-match"));
+        assert_eq!(
+            Some("\"v1\"".to_string()),
+            cache.transport.header(1, "if-none-match")
+        );
+        assert_eq!(
+            Some("\"v1\"".to_string()),
+            cache.transport.header(2, "if-none-match")
+        );
+    }
+
+    #[tokio::test]
+    async fn no_store() {
+        let cache = Cache::new(
+            ScriptTransport::new(vec![
+                response(200, &[("Cache-Control", "private, no-store")], "first"),
+                response(200, &[], "second"),
+            ]),
+            CacheStorage::memory(),
+        );
+
+        for body in &["first", "second"] {
+            let resp = cache
+                .send(request(ApiHttpMethod::Get, "a", HOLDINGS))
+                .await
+                .unwrap();
+            assert_eq!(body.as_bytes().to_vec(), resp.body);
+        }
+        assert_eq!(2, cache.transport.sent());
+    }
+
+    #[tokio::test]
+    async fn invalidation() {
+        let trades = "https://api.sharesight.com/api/v2/holdings/2/trades.json";
+        let cache = Cache::new(
+            ScriptTransport::new(vec![
+                response(200, &[], "holdings"),
+                response(200, &[], "trades"),
+                response(200, &[], "other account"),
+                response(200, &[], "changed"),
+                response(200, &[], "new holdings"),
+                response(200, &[], "new trades"),
+            ]),
+            CacheStorage::memory(),
+        );
+
+        cache
+            .send(request(ApiHttpMethod::Get, "a", HOLDINGS))
+            .await
+            .unwrap();
+        cache
+            .send(request(ApiHttpMethod::Get, "a", trades))
+            .await
+            .unwrap();
+        cache
+            .send(request(ApiHttpMethod::Get, "b", trades))
+            .await
+            .unwrap();
+        cache
+            .send(request(
+                ApiHttpMethod::Post,
+                "a",
+                "https://api.sharesight.com/api/v2/trades.json",
+            ))
+            .await
+            .unwrap();
+
+        for (url, body) in &[(HOLDINGS, "new holdings"), (trades, "new trades")] {
+            let resp = cache
+                .send(request(ApiHttpMethod::Get, "a", url))
+                .await
+                .unwrap();
+            assert_eq!(body.as_bytes().to_vec(), resp.body);
+        }
+
+        let resp = cache
+            .send(request(ApiHttpMethod::Get, "b", trades))
+            .await
+            .unwrap();
+        assert_eq!(b"other account".to_vec(), resp.body);
+        assert_eq!(6, cache.transport.sent());
+    }
+}
@@ -0,0 +1,301 @@
+use chrono::NaiveDate;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde_json::{Map, Value};
+use sharesight_types::{EndpointManifest, HttpResponse, ParameterKind, Transport};
+
+use crate::{success, trace::RequestSpan, Client, SharesightReqwestError};
+
+/// The characters encoded in a parameter of the URL path: all but the unreserved characters,
+/// except `.` so a value can't be a `..` segment.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'_').remove(b'~');
+
+/// Parameters that don't match the fields the API documentation gives an endpoint.
+#[derive(Debug, thiserror::Error)]
+pub enum ParameterError {
+    #[error("{0} has no parameter {1}, its parameters are: {2}")]
+    Unknown(&'static str, String, String),
+    #[error("{0} requires the parameters: {1}")]
+    Missing(&'static str, String),
+    #[error("Parameter {0} must be {1}, not {2:?}")]
+    Invalid(&'static str, &'static str, String),
+}
+
+/// The parameters of the endpoint from `field=value` pairs such as `trade.quantity=10`, checked
+/// against the fields of the endpoint and nested by the periods in the field names. Objects and
+/// arrays are given as JSON, or arrays as comma separated values.
+pub fn manifest_parameters(
+    endpoint: &EndpointManifest,
+    pairs: &[(String, String)],
+) -> Result<Value, ParameterError> {
+    let mut parameters = Map::new();
+
+    for (field, text) in pairs {
+        let parameter = endpoint.parameter(field).ok_or_else(|| {
+            ParameterError::Unknown(endpoint.name, field.clone(), field_list(endpoint, |_| true))
+        })?;
+
+        let value = if parameter.array {
+            match serde_json::from_str::<Value>(text) {
+                Ok(value @ Value::Array(_)) => value,
+                _ => Value::Array(
+                    text.split(',')
+                        .map(|item| parameter_value(parameter.field, parameter.kind, item))
+                        .collect::<Result<_, _>>()?,
+                ),
+            }
+        } else {
+            parameter_value(parameter.field, parameter.kind, text)?
+        };
+
+        let mut path = field.split('.').peekable();
+        let mut object = &mut parameters;
+        while let Some(segment) = path.next() {
+            if path.peek().is_none() {
+                object.insert(segment.to_string(), value);
+                break;
+            }
+
+            let entry = object
+                .entry(segment)
+                .or_insert_with(|| Value::Object(Map::new()));
+            if !entry.is_object() {
+                *entry = Value::Object(Map::new());
+            }
+            object = entry.as_object_mut().unwrap();
+        }
+    }
+
+    let parameters = Value::Object(parameters);
+    let is_set = |field: &str| {
+        field
+            .split('.')
+            .try_fold(&parameters, |value, segment| value.get(segment))
+            .is_some()
+    };
+    // A field inside an optional object is only required when the object is set.
+    let is_required = |field: &str| {
+        let mut prefix = String::new();
+        for segment in field.split('.') {
+            if !prefix.is_empty() && !is_set(&prefix) {
+                return false;
+            }
+            if !prefix.is_empty() {
+                prefix.push('.');
+            }
+            prefix.push_str(segment);
+        }
+
+        !endpoint.parameter(field).map_or(true, |p| p.optional)
+    };
+    let missing = field_list(endpoint, |field| is_required(field) && !is_set(field));
+
+    if missing.is_empty() {
+        Ok(parameters)
+    } else {
+        Err(ParameterError::Missing(endpoint.name, missing))
+    }
+}
+
+fn field_list<F: Fn(&str) -> bool>(endpoint: &EndpointManifest, include: F) -> String {
+    endpoint
+        .parameters
+        .iter()
+        .map(|p| p.field)
+        .filter(|field| include(field))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn parameter_value(
+    field: &'static str,
+    kind: ParameterKind,
+    text: &str,
+) -> Result<Value, ParameterError> {
+    let invalid = |expected| ParameterError::Invalid(field, expected, text.to_string());
+
+    match kind {
+        ParameterKind::String => Ok(Value::String(text.to_string())),
+        ParameterKind::Integer => text
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| invalid("an integer")),
+        ParameterKind::Number => serde_json::from_str::<serde_json::Number>(text)
+            .map(Value::Number)
+            .map_err(|_| invalid("a number")),
+        ParameterKind::Boolean => text
+            .parse::<bool>()
+            .map(Value::Bool)
+            .map_err(|_| invalid("true or false")),
+        ParameterKind::Date => NaiveDate::parse_from_str(text, "%Y-%m-%d")
+            .map(|_| Value::String(text.to_string()))
+            .map_err(|_| invalid("a date such as 2024-06-30")),
+        ParameterKind::Object => match serde_json::from_str::<Value>(text) {
+            Ok(value @ Value::Object(_)) => Ok(value),
+            _ => Err(invalid("a JSON object")),
+        },
+    }
+}
+
+impl<H: Transport> Client<H> {
+    /// Execute an endpoint chosen at run time with parameters from [`manifest_parameters`],
+    /// returning the response when it is successful. The parameters in the URL path are taken
+    /// from the top level of the parameters.
+    pub async fn execute_manifest(
+        &self,
+        endpoint: &EndpointManifest,
+        parameters: &Value,
+    ) -> Result<HttpResponse, SharesightReqwestError> {
+        let mut url_path = endpoint.url_path.to_string();
+        for name in endpoint.url_parameters() {
+            let value = match parameters.get(name) {
+                Some(Value::String(value)) => value.clone(),
+                Some(Value::Number(value)) => value.to_string(),
+                _ => return Err(ParameterError::Missing(endpoint.name, name.to_string()).into()),
+            };
+            if let Some(parameter) = endpoint.parameter(name) {
+                if parameter.kind == ParameterKind::Integer && value.parse::<i64>().is_err() {
+                    return Err(
+                        ParameterError::Invalid(parameter.field, "an integer", value).into(),
+                    );
+                }
+            }
+
+            // Encoded so a value such as `1/../other` stays within its segment of the path.
+            let value = utf8_percent_encode(&value, PATH_SEGMENT).to_string();
+            url_path = url_path.replacen(&format!(":{}", name), &value, 1);
+        }
+        let url = format!("{}{}", self.base_url.trim_end_matches('/'), url_path);
+
+        let request = self.http_request(
+            endpoint.http_method,
+            url.clone(),
+            serde_json::to_vec(parameters)?,
+        );
+        let span = RequestSpan::named(endpoint.name, endpoint.http_method, endpoint.url_path);
+        let resp = self.send_request(request, span).await?;
+
+        success(&url, resp)
+    }
+}
+
+#[cfg(test)]
+mod call_tests {
+    use serde_json::json;
+    use sharesight_types::{ApiHttpMethod, EndpointManifest, ParameterKind, ParameterManifest};
+
+    use super::{manifest_parameters, ParameterError};
+    use crate::{client_tests::FakeTransport, Client, SharesightReqwestError};
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parameters() {
+        let endpoint = EndpointManifest::find("HoldingMergesUpdate").unwrap();
+
+        assert_eq!(
+            json!({"portfolio_id": 1, "id": 2, "quantity": 10.5, "merge_date": "2024-06-30"}),
+            manifest_parameters(
+                endpoint,
+                &pairs(&[
+                    ("portfolio_id", "1"),
+                    ("id", "2"),
+                    ("quantity", "10.5"),
+                    ("merge_date", "2024-06-30")
+                ])
+            )
+            .unwrap()
+        );
+        assert!(matches!(
+            manifest_parameters(endpoint, &pairs(&[("portfolio_id", "1")])),
+            Err(ParameterError::Missing(_, missing)) if missing == "id"
+        ));
+        assert!(matches!(
+            manifest_parameters(endpoint, &pairs(&[("portfolio_id", "x")])),
+            Err(ParameterError::Invalid("portfolio_id", _, _))
+        ));
+        assert!(matches!(
+            manifest_parameters(endpoint, &pairs(&[("colour", "red")])),
+            Err(ParameterError::Unknown(_, _, _))
+        ));
+
+        let endpoint = EndpointManifest::find("TradesCreate").unwrap();
+        assert_eq!(
+            json!({"trade": {"portfolio_id": 1, "quantity": 3}}),
+            manifest_parameters(
+                endpoint,
+                &pairs(&[("trade.portfolio_id", "1"), ("trade.quantity", "3")])
+            )
+            .unwrap()
+        );
+        assert!(matches!(
+            manifest_parameters(endpoint, &[]),
+            Err(ParameterError::Missing(_, missing)) if missing == "trade"
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_manifest() {
+        let client = Client::new_with_transport(
+            FakeTransport::new(200, "{}"),
+            "token".to_string(),
+            "api.example.com".to_string(),
+        );
+        let endpoint = EndpointManifest::find("HoldingMergesUpdate").unwrap();
+        let parameters = json!({"portfolio_id": 1, "id": "2", "quantity": 10.5});
+
+        let resp = client
+            .execute_manifest(endpoint, &parameters)
+            .await
+            .unwrap();
+        assert_eq!(b"{}", resp.body.as_slice());
+
+        let bad_id = json!({"portfolio_id": 1, "id": "1/../other"});
+        assert!(matches!(
+            client.execute_manifest(endpoint, &bad_id).await,
+            Err(SharesightReqwestError::Parameter(ParameterError::Invalid(
+                "id",
+                _,
+                _
+            )))
+        ));
+
+        // A parameter of the path that isn't an integer is encoded within its segment.
+        let endpoint = EndpointManifest {
+            name: "InstrumentShow",
+            http_method: ApiHttpMethod::Get,
+            url_path: "/instruments/:code.json",
+            parameters: &[ParameterManifest {
+                field: "code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            }],
+            file: false,
+        };
+        client
+            .execute_manifest(&endpoint, &json!({"code": "1/../other"}))
+            .await
+            .unwrap();
+
+        let requests = client.transport.requests.lock().unwrap();
+        assert_eq!(2, requests.len());
+        assert_eq!(ApiHttpMethod::Put, requests[0].method);
+        assert_eq!(
+            "https://api.example.com/api/v2/portfolios/1/holding_merges/2.json",
+            requests[0].url
+        );
+        assert_eq!(
+            parameters,
+            serde_json::from_slice::<serde_json::Value>(&requests[0].body).unwrap()
+        );
+        assert_eq!(
+            "https://api.example.com/api/v2/instruments/1%2F%2E%2E%2Fother.json",
+            requests[1].url
+        );
+    }
+}
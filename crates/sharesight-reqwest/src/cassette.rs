@@ -0,0 +1,408 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sharesight_types::{ApiHttpMethod, HttpRequest, HttpResponse, Transport, TransportFuture};
+
+/// Fields of request and response bodies that are replaced with `REDACTED` when recorded.
+pub const DEFAULT_REDACTED_FIELDS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "email",
+    "first_name",
+    "last_name",
+    "name",
+];
+
+/// The response headers that are recorded; the others, like cookies, are dropped.
+const RECORDED_HEADERS: &[&str] = &["content-type", "content-disposition"];
+
+const REDACTED: &str = "REDACTED";
+
+/// Interactions with the API saved as JSON, to replay in tests.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// The key a request is replayed by. The path holds the query string but not the base URL, so a
+/// cassette can be replayed against any host.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    #[serde(default)]
+    pub body: Value,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    #[serde(default)]
+    pub body: RecordedBody,
+}
+
+/// A JSON body, or a base64 encoded file.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedBody {
+    Json(Value),
+    Base64(String),
+}
+
+impl Default for RecordedBody {
+    fn default() -> Self {
+        RecordedBody::Json(Value::Null)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CassetteError {
+    #[error("Cassette IO error occurred\n{0:?}")]
+    Io(#[from] std::io::Error),
+    #[error("Cassette is not valid JSON\n{0:?}")]
+    Json(#[from] serde_json::Error),
+    #[error("Cassette has an invalid base64 body\n{0:?}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("Cassette has no interaction for {0} {1}")]
+    Unmatched(String, String),
+}
+
+impl Cassette {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, CassetteError> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), CassetteError> {
+        Ok(std::fs::write(path, serde_json::to_vec_pretty(self)?)?)
+    }
+}
+
+impl RecordedRequest {
+    fn new(request: &HttpRequest, redacted_fields: &[String]) -> Self {
+        let path = reqwest::Url::parse(&request.url)
+            .map(|url| match url.query() {
+                Some(query) => format!("{}?{}", url.path(), query),
+                None => url.path().to_string(),
+            })
+            .unwrap_or_else(|_| request.url.clone());
+        let mut body = serde_json::from_slice(&request.body).unwrap_or(Value::Null);
+        redact(&mut body, redacted_fields);
+
+        RecordedRequest {
+            method: method_name(request.method).to_string(),
+            path,
+            body,
+        }
+    }
+}
+
+impl RecordedResponse {
+    fn new(response: &HttpResponse, redacted_fields: &[String]) -> Self {
+        let headers = response
+            .headers
+            .iter()
+            .filter(|(name, _)| {
+                RECORDED_HEADERS
+                    .iter()
+                    .any(|h| name.eq_ignore_ascii_case(h))
+            })
+            .cloned()
+            .collect();
+        let body = if response.body.is_empty() {
+            RecordedBody::Json(Value::Null)
+        } else {
+            match serde_json::from_slice(&response.body) {
+                Ok(mut value) => {
+                    redact(&mut value, redacted_fields);
+                    RecordedBody::Json(value)
+                }
+                Err(_) => RecordedBody::Base64(STANDARD.encode(&response.body)),
+            }
+        };
+
+        RecordedResponse {
+            status: response.status,
+            headers,
+            body,
+        }
+    }
+
+    fn to_response(&self) -> Result<HttpResponse, CassetteError> {
+        let body = match self.body {
+            RecordedBody::Json(Value::Null) => Vec::new(),
+            RecordedBody::Json(ref value) => serde_json::to_vec(value)?,
+            RecordedBody::Base64(ref encoded) => STANDARD.decode(encoded)?,
+        };
+
+        Ok(HttpResponse {
+            status: self.status,
+            headers: self.headers.clone(),
+            body,
+        })
+    }
+}
+
+fn method_name(method: ApiHttpMethod) -> &'static str {
+    match method {
+        ApiHttpMethod::Get => "GET",
+        ApiHttpMethod::Post => "POST",
+        ApiHttpMethod::Put => "PUT",
+        ApiHttpMethod::Delete => "DELETE",
+    }
+}
+
+pub(crate) fn redact(value: &mut Value, redacted_fields: &[String]) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if redacted_fields.iter().any(|field| field == key) && !value.is_null() {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact(value, redacted_fields);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                redact(value, redacted_fields);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Sends requests with another transport and records them to a cassette file, which is rewritten
+/// after each response. Headers, including the access token, are never recorded.
+pub struct Recorder<H> {
+    transport: H,
+    path: PathBuf,
+    redacted_fields: Vec<String>,
+    cassette: Arc<Mutex<Cassette>>,
+}
+
+impl<H> Recorder<H> {
+    pub fn new<P: Into<PathBuf>>(transport: H, path: P) -> Self {
+        Recorder {
+            transport,
+            path: path.into(),
+            redacted_fields: DEFAULT_REDACTED_FIELDS
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+            cassette: Arc::default(),
+        }
+    }
+
+    /// Redact these fields, in place of [`DEFAULT_REDACTED_FIELDS`].
+    pub fn with_redacted_fields<I: IntoIterator<Item = S>, S: Into<String>>(
+        mut self,
+        fields: I,
+    ) -> Self {
+        self.redacted_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl<H: Transport + Sync> Transport for Recorder<H> {
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_, Self::Error> {
+        Box::pin(async move {
+            let recorded = RecordedRequest::new(&request, &self.redacted_fields);
+            let response = self.transport.send(request).await.map_err(Into::into)?;
+
+            let cassette = {
+                let mut cassette = self.cassette.lock().unwrap();
+                cassette.interactions.push(Interaction {
+                    request: recorded,
+                    response: RecordedResponse::new(&response, &self.redacted_fields),
+                });
+                serde_json::to_vec_pretty(&*cassette)?
+            };
+            tokio::fs::write(&self.path, cassette).await?;
+
+            Ok(response)
+        })
+    }
+}
+
+/// Answers requests from a cassette. Requests are matched by method, path and body.
+///
+/// A strict replay answers each interaction once and fails on a request it has no interaction
+/// for. Otherwise interactions can be replayed any number of times, the body is ignored when no
+/// interaction matches it, and unmatched requests get a `404 Not Found`.
+pub struct Replayer {
+    interactions: Vec<Interaction>,
+    redacted_fields: Vec<String>,
+    strict: bool,
+    used: Mutex<Vec<bool>>,
+}
+
+impl Replayer {
+    pub fn new(cassette: Cassette, strict: bool) -> Self {
+        let used = Mutex::new(vec![false; cassette.interactions.len()]);
+
+        Replayer {
+            interactions: cassette.interactions,
+            redacted_fields: DEFAULT_REDACTED_FIELDS
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+            strict,
+            used,
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P, strict: bool) -> Result<Self, CassetteError> {
+        Ok(Replayer::new(Cassette::load(path)?, strict))
+    }
+
+    /// The fields redacted when the cassette was recorded, so requests still match.
+    pub fn with_redacted_fields<I: IntoIterator<Item = S>, S: Into<String>>(
+        mut self,
+        fields: I,
+    ) -> Self {
+        self.redacted_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn replay(&self, request: &HttpRequest) -> Result<HttpResponse, CassetteError> {
+        let recorded = RecordedRequest::new(request, &self.redacted_fields);
+        let mut used = self.used.lock().unwrap();
+
+        let found = self
+            .interactions
+            .iter()
+            .enumerate()
+            .find(|(i, interaction)| interaction.request == recorded && !(self.strict && used[*i]))
+            .or_else(|| {
+                if self.strict {
+                    None
+                } else {
+                    self.interactions
+                        .iter()
+                        .enumerate()
+                        .find(|(_, interaction)| {
+                            interaction.request.method == recorded.method
+                                && interaction.request.path == recorded.path
+                        })
+                }
+            });
+
+        match found {
+            Some((i, interaction)) => {
+                used[i] = true;
+                interaction.response.to_response()
+            }
+            None if self.strict => Err(CassetteError::Unmatched(recorded.method, recorded.path)),
+            None => Ok(HttpResponse {
+                status: 404,
+                headers: Vec::new(),
+                body: format!(
+                    "Cassette has no interaction for {} {}",
+                    recorded.method, recorded.path
+                )
+                .into_bytes(),
+            }),
+        }
+    }
+}
+
+impl Transport for Replayer {
+    type Error = CassetteError;
+
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_, Self::Error> {
+        let response = self.replay(&request);
+
+        Box::pin(async move { response })
+    }
+}
+
+#[cfg(test)]
+mod cassette_tests {
+    use serde_json::json;
+    use sharesight_types::{ApiHttpMethod, HttpRequest, HttpResponse};
+
+    use super::{
+        Cassette, CassetteError, Interaction, RecordedBody, RecordedRequest, RecordedResponse,
+        Replayer, DEFAULT_REDACTED_FIELDS,
+    };
+
+    fn request(url: &str) -> HttpRequest {
+        HttpRequest {
+            method: ApiHttpMethod::Get,
+            url: url.to_string(),
+            headers: vec![("authorization".to_string(), "Bearer token".to_string())],
+            body: b"null".to_vec(),
+        }
+    }
+
+    #[test]
+    fn record() {
+        let fields = DEFAULT_REDACTED_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .collect::<Vec<_>>();
+        let recorded = RecordedRequest::new(
+            &request("https://api.sharesight.com/api/v2/portfolios.json?page=2"),
+            &fields,
+        );
+        let response = RecordedResponse::new(
+            &HttpResponse {
+                status: 200,
+                headers: vec![("Set-Cookie".to_string(), "session=1".to_string())],
+                body: br#"{"user":{"id":1,"email":"a@example.com"}}"#.to_vec(),
+            },
+            &fields,
+        );
+
+        assert_eq!("/api/v2/portfolios.json?page=2", recorded.path);
+        assert!(response.headers.is_empty());
+        assert!(matches!(
+            response.body,
+            RecordedBody::Json(ref value) if *value == json!({"user": {"id": 1, "email": "REDACTED"}})
+        ));
+    }
+
+    #[test]
+    fn strict_replay() {
+        let replayer = Replayer::new(
+            Cassette {
+                interactions: vec![Interaction {
+                    request: RecordedRequest {
+                        method: "GET".to_string(),
+                        path: "/api/v2/portfolios.json".to_string(),
+                        body: json!(null),
+                    },
+                    response: RecordedResponse {
+                        status: 200,
+                        headers: Vec::new(),
+                        body: RecordedBody::Json(json!({"portfolios": []})),
+                    },
+                }],
+            },
+            true,
+        );
+        let portfolios = request("http://127.0.0.1/api/v2/portfolios.json");
+
+        assert_eq!(200, replayer.replay(&portfolios).unwrap().status);
+        assert!(matches!(
+            replayer.replay(&portfolios),
+            Err(CassetteError::Unmatched(_, _))
+        ));
+    }
+}
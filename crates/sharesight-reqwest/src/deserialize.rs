@@ -0,0 +1,182 @@
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+use crate::cassette::{redact, DEFAULT_REDACTED_FIELDS};
+
+/// The longest excerpt of a response kept in a `DeserializeError`.
+const MAX_EXCERPT_CHARS: usize = 200;
+
+/// A response body that doesn't match the type of its endpoint's success.
+#[derive(Debug)]
+pub struct DeserializeError {
+    /// The name of the endpoint, such as `PortfolioList`.
+    pub endpoint: &'static str,
+    pub url: String,
+    /// The path of the field that failed, such as `portfolios[2].currency_code`.
+    pub path: String,
+    /// The value that failed, with personal fields redacted and truncated, or the text around
+    /// the error when the body isn't JSON.
+    pub excerpt: String,
+    pub source: serde_json::Error,
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {} at {}\n{}",
+            self.endpoint, self.url, self.source, self.path, self.excerpt
+        )
+    }
+}
+
+impl std::error::Error for DeserializeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Deserialize the body, returning the paths of the fields it has that `U` doesn't know about.
+pub(crate) fn from_slice<U: DeserializeOwned>(
+    endpoint: &'static str,
+    url: String,
+    body: &[u8],
+) -> Result<(U, Vec<String>), DeserializeError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let error = |path: String, excerpt: String, source: serde_json::Error| DeserializeError {
+        endpoint,
+        url: url.clone(),
+        path,
+        excerpt,
+        source,
+    };
+
+    let mut unknown_fields = Vec::new();
+
+    let value: U = serde_path_to_error::deserialize(serde_ignored::Deserializer::new(
+        &mut deserializer,
+        &mut |path: serde_ignored::Path| unknown_fields.push(path.to_string()),
+    ))
+    .map_err(|e| {
+        let excerpt = excerpt(body, e.path(), e.inner());
+        error(e.path().to_string(), excerpt, e.into_inner())
+    })?;
+    deserializer
+        .end()
+        .map_err(|e| error(".".to_string(), excerpt_around(body, &e), e))?;
+
+    Ok((value, unknown_fields))
+}
+
+fn excerpt(body: &[u8], path: &serde_path_to_error::Path, e: &serde_json::Error) -> String {
+    let text = match serde_json::from_slice::<Value>(body) {
+        Ok(mut value) => {
+            redact(
+                &mut value,
+                &DEFAULT_REDACTED_FIELDS
+                    .iter()
+                    .map(|field| field.to_string())
+                    .collect::<Vec<_>>(),
+            );
+
+            let mut value = &value;
+            for segment in path.iter() {
+                let next = match segment {
+                    Segment::Seq { index } => value.get(index),
+                    Segment::Map { key } => value.get(key),
+                    Segment::Enum { .. } | Segment::Unknown => None,
+                };
+                match next {
+                    Some(next) => value = next,
+                    None => break,
+                }
+            }
+
+            value.to_string()
+        }
+        // Not JSON, so only the text around the error can be shown.
+        Err(_) => return excerpt_around(body, e),
+    };
+
+    truncate(text)
+}
+
+/// The text of the body starting a little before the error.
+fn excerpt_around(body: &[u8], e: &serde_json::Error) -> String {
+    let text = String::from_utf8_lossy(body)
+        .lines()
+        .nth(e.line().saturating_sub(1))
+        .map(|line| {
+            line.chars()
+                .skip(e.column().saturating_sub(MAX_EXCERPT_CHARS / 2))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    truncate(text)
+}
+
+fn truncate(text: String) -> String {
+    if text.chars().count() > MAX_EXCERPT_CHARS {
+        text.chars().take(MAX_EXCERPT_CHARS).collect::<String>() + "..."
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod deserialize_tests {
+    use serde::Deserialize;
+
+    use super::from_slice;
+
+    #[derive(Debug, Deserialize)]
+    struct Success {
+        #[allow(dead_code)]
+        users: Vec<User>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct User {
+        #[allow(dead_code)]
+        id: i64,
+    }
+
+    #[test]
+    fn unknown_fields() {
+        let (_, unknown_fields) = from_slice::<Success>(
+            "UserList",
+            "https://api.example.com/users.json".to_string(),
+            br#"{"users":[{"id":1,"email":"a@example.com"}],"links":{}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(vec!["users.0.email", "links"], unknown_fields);
+    }
+
+    #[test]
+    fn path_and_excerpt() {
+        let e = from_slice::<Success>(
+            "UserList",
+            "https://api.example.com/users.json".to_string(),
+            br#"{"users":[{"id":1},{"id":null,"email":"a@example.com"}]}"#,
+        )
+        .unwrap_err();
+
+        assert_eq!("users[1].id", e.path);
+        assert_eq!("null", e.excerpt);
+
+        let e = from_slice::<Success>(
+            "UserList",
+            "https://api.example.com/users.json".to_string(),
+            br#"{"users":[{"email":"a@example.com"}]}"#,
+        )
+        .unwrap_err();
+
+        assert_eq!("users[0]", e.path);
+        assert_eq!(r#"{"email":"REDACTED"}"#, e.excerpt);
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// A local HTTP server answering each connection with the next of its responses, for testing the
+/// code that sends requests with `reqwest` directly rather than through a `Transport`.
+pub(crate) struct FakeServer {
+    pub(crate) url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl FakeServer {
+    /// Serve the responses, each given as its status line and header lines, and its body in the
+    /// chunks to send it in. Each connection is closed after its response.
+    pub(crate) async fn start(responses: Vec<(&'static str, Vec<&'static str>)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = requests.clone();
+
+        tokio::spawn(async move {
+            for (head, chunks) in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let request = read_request(&mut stream).await;
+                received.lock().unwrap().push(request);
+
+                stream.write_all(head.as_bytes()).await.unwrap();
+                stream
+                    .write_all(b"connection: close\r\n\r\n")
+                    .await
+                    .unwrap();
+                for chunk in chunks {
+                    stream.write_all(chunk.as_bytes()).await.unwrap();
+                    stream.flush().await.unwrap();
+                }
+                stream.shutdown().await.unwrap();
+            }
+        });
+
+        FakeServer { url, requests }
+    }
+
+    /// The requests received so far, as their request line and headers followed by the body.
+    pub(crate) fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn read_request(stream: &mut tokio::net::TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+
+    loop {
+        let n = stream.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+
+        let text = String::from_utf8_lossy(&request);
+        if let Some(end) = text.find("\r\n\r\n") {
+            let content_length = text[..end]
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    if name.eq_ignore_ascii_case("content-length") {
+                        value.trim().parse::<usize>().ok()
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(0);
+
+            if n == 0 || request.len() >= end + 4 + content_length {
+                return text.into_owned();
+            }
+        } else if n == 0 {
+            return text.into_owned();
+        }
+    }
+}
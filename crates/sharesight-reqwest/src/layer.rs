@@ -0,0 +1,88 @@
+use std::{
+    error::Error,
+    future::{poll_fn, Future},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use sharesight_types::{HttpRequest, HttpResponse, Transport, TransportFuture};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::Client;
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+/// A transport as a `tower` service, so `tower` layers can be stacked around it.
+#[derive(Clone, Debug)]
+pub struct TransportService<H>(H);
+
+impl<H> TransportService<H> {
+    pub fn new(transport: H) -> Self {
+        TransportService(transport)
+    }
+}
+
+impl<H> Service<HttpRequest> for TransportService<H>
+where
+    H: Transport + Clone + Send + Sync + 'static,
+{
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<HttpResponse, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let transport = self.0.clone();
+
+        Box::pin(async move { transport.send(request).await.map_err(Into::into) })
+    }
+}
+
+/// A `tower` service as a transport, such as a `reqwest` transport wrapped in timeout or
+/// concurrency limit layers. The client retries requests itself, through its `RetryPolicy`.
+#[derive(Clone, Debug)]
+pub struct ServiceTransport<S>(S);
+
+impl<S> ServiceTransport<S> {
+    pub fn new(service: S) -> Self {
+        ServiceTransport(service)
+    }
+}
+
+impl<S> Transport for ServiceTransport<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse> + Clone + Send,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+{
+    type Error = S::Error;
+
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_, Self::Error> {
+        let mut service = self.0.clone();
+
+        Box::pin(async move {
+            poll_fn(|cx| service.poll_ready(cx)).await?;
+            service.call(request).await
+        })
+    }
+}
+
+impl<H> Client<H>
+where
+    H: Transport + Clone + Send + Sync + 'static,
+{
+    /// Send requests through the `tower` layer, for example
+    /// `client.layer(ServiceBuilder::new().timeout(..).concurrency_limit(..).into_inner())`.
+    pub fn layer<L>(self, layer: L) -> Client<ServiceTransport<L::Service>>
+    where
+        L: Layer<TransportService<H>>,
+    {
+        self.map_transport(|transport| {
+            ServiceTransport::new(layer.layer(TransportService::new(transport)))
+        })
+    }
+}
@@ -1,13 +1,53 @@
-use std::sync::Arc;
+use std::{error::Error, path::Path, sync::Arc};
 
-use log::warn;
+use futures_util::{stream, StreamExt};
+use log::{debug, warn};
+use percent_encoding::percent_decode_str;
+use retry::Attempt;
 use serde::de::DeserializeOwned;
-use sharesight_types::ApiEndpoint;
+use sharesight_types::{
+    api_base_url, ApiEndpoint, ApiFileEndpoint, ApiHttpMethod, HttpRequest, HttpResponse, Market,
+    ResolveError, Transport, TransportFuture, ValidationError,
+};
+use tokio::io::AsyncWriteExt;
+use trace::RequestSpan;
 
-pub struct Client {
-    client: reqwest::Client,
-    api_host: Arc<String>,
+mod builder;
+mod cache;
+mod call;
+mod cassette;
+mod deserialize;
+#[cfg(test)]
+mod fake_server;
+mod layer;
+mod oauth;
+mod pool;
+mod resolve;
+mod resources;
+mod retry;
+mod token_store;
+mod trace;
+
+pub use builder::*;
+pub use cache::*;
+pub use call::*;
+pub use cassette::*;
+pub use deserialize::DeserializeError;
+pub use layer::*;
+pub use oauth::*;
+pub use pool::*;
+pub use resources::*;
+pub use retry::*;
+pub use token_store::*;
+
+pub struct Client<H = ReqwestTransport> {
+    transport: H,
+    base_url: Arc<String>,
     credentials: Credentials,
+    validate: bool,
+    decimal_markets: Option<Arc<[Market]>>,
+    strict: bool,
+    retry: RetryPolicy,
 }
 
 enum Credentials {
@@ -24,61 +64,574 @@ impl Credentials {
 
 impl Client {
     pub fn new_with_token_and_host(access_token: String, api_host: String) -> Self {
+        Client::new_with_transport(ReqwestTransport::default(), access_token, api_host)
+    }
+
+    pub fn builder(access_token: String) -> ClientBuilder {
+        ClientBuilder::new(access_token)
+    }
+}
+
+impl<H> Client<H> {
+    fn map_transport<I>(self, f: impl FnOnce(H) -> I) -> Client<I> {
         Client {
-            api_host: Arc::new(api_host),
+            transport: f(self.transport),
+            base_url: self.base_url,
+            credentials: self.credentials,
+            validate: self.validate,
+            decimal_markets: self.decimal_markets,
+            strict: self.strict,
+            retry: self.retry,
+        }
+    }
+}
+
+impl<H: Transport> Client<H> {
+    /// A client sending its requests with the transport, such as a fake in tests.
+    pub fn new_with_transport(transport: H, access_token: String, api_host: String) -> Self {
+        Client::new_with_transport_and_base_url(transport, access_token, api_base_url(&api_host))
+    }
+
+    /// A client using the API at the base URL, such as `https://api.sharesight.com/api/v2`.
+    pub fn new_with_transport_and_base_url(
+        transport: H,
+        access_token: String,
+        base_url: String,
+    ) -> Self {
+        Client {
+            transport,
+            base_url: Arc::new(base_url),
             credentials: Credentials::AccessToken(access_token),
-            client: reqwest::Client::default(),
+            validate: true,
+            decimal_markets: None,
+            strict: false,
+            retry: RetryPolicy::default(),
         }
     }
 
+    /// Whether parameters are checked locally before they are sent, which is on by default.
+    pub fn with_validation(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// The markets allowing decimal quantities, as given by the `allow_decimal_quantities` field
+    /// of the API's market list. With them, validation also checks that the quantity of a trade
+    /// on any other market is a whole number. A trade given by `holding_id` isn't checked, as its
+    /// market would need another request to look up the holding.
+    pub fn with_decimal_markets(mut self, decimal_markets: Vec<Market>) -> Self {
+        self.decimal_markets = Some(decimal_markets.into());
+        self
+    }
+
+    /// Whether fields of a response that its success type doesn't know about are an error rather
+    /// than ignored, which is off by default. This catches changes to the API in tests.
+    ///
+    /// With the `extra-fields` feature of `sharesight-types`, unknown fields are kept in the
+    /// `extra` field of each success struct instead, so they are never an error.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// When requests are sent again after failing, whatever the transport. Requests aren't
+    /// retried by default.
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     pub async fn execute<'a, T: ApiEndpoint<'a>, U: DeserializeOwned>(
         &'a self,
         parameters: &'a T::Parameters,
     ) -> Result<U, SharesightReqwestError> {
-        let method = match T::HTTP_METHOD {
-            sharesight_types::ApiHttpMethod::Get => reqwest::Method::GET,
-            sharesight_types::ApiHttpMethod::Post => reqwest::Method::POST,
-            sharesight_types::ApiHttpMethod::Put => reqwest::Method::PUT,
-            sharesight_types::ApiHttpMethod::Delete => reqwest::Method::DELETE,
+        let full = self.send::<T>(parameters).await?.body;
+
+        self.decode::<T, U>(parameters, &full)
+    }
+
+    /// Execute the endpoint, returning the JSON of the response as well as the typed value, for
+    /// example to archive it or to read a field the success type doesn't have.
+    pub async fn execute_with_json<'a, T: ApiEndpoint<'a>, U: DeserializeOwned>(
+        &'a self,
+        parameters: &'a T::Parameters,
+    ) -> Result<(U, serde_json::Value), SharesightReqwestError> {
+        let full = self.send::<T>(parameters).await?.body;
+        let success = self.decode::<T, U>(parameters, &full)?;
+        let json = self.decode::<T, serde_json::Value>(parameters, &full)?;
+
+        Ok((success, json))
+    }
+
+    /// Execute the endpoint, returning the response as it was received, whatever its status.
+    pub async fn execute_raw<'a, T: ApiEndpoint<'a>>(
+        &'a self,
+        parameters: &'a T::Parameters,
+    ) -> Result<HttpResponse, SharesightReqwestError> {
+        self.send_raw::<T>(parameters).await
+    }
+
+    fn decode<'a, T: ApiEndpoint<'a>, U: DeserializeOwned>(
+        &'a self,
+        parameters: &'a T::Parameters,
+        full: &[u8],
+    ) -> Result<U, SharesightReqwestError> {
+        let slice = if full.is_empty() {
+            b"null".as_slice()
+        } else {
+            full
         };
-        let resp = self
-            .client
-            .request(method, T::url(&self.api_host, parameters).to_string())
-            .bearer_auth(self.credentials.access_token())
-            .json(parameters)
-            .send()
-            .await?;
 
-        if resp.status().is_success() {
-            let full = resp.bytes().await?;
-
-            let slice = if full.is_empty() {
-                b"null".as_slice()
-            } else {
-                &full
-            };
-
-            Ok(serde_json::from_slice(slice).map_err(|e| {
-                if let Ok(s) = std::str::from_utf8(&full) {
-                    warn!("Error deserializing json: {:?}\n{}", e, s);
-                } else {
-                    warn!("Error deserializing json - not valid utf-8: {:?}", e);
+        let endpoint = endpoint_name::<T>();
+        let (success, unknown_fields) = deserialize::from_slice(
+            endpoint,
+            T::url_with_base(&self.base_url, parameters).to_string(),
+            slice,
+        )
+        .map_err(|e| {
+            warn!("Error deserializing json: {}", e);
+            SharesightReqwestError::Deserialize(Box::new(e))
+        })?;
+
+        if !unknown_fields.is_empty() {
+            if self.strict {
+                return Err(SharesightReqwestError::UnknownFields(
+                    endpoint,
+                    unknown_fields,
+                ));
+            }
+            debug!("{} returned unknown fields: {:?}", endpoint, unknown_fields);
+        }
+
+        Ok(success)
+    }
+
+    /// Execute the endpoint with each of the parameters, sending up to `concurrency` requests at a
+    /// time. The results are in the order of the parameters, and a failed request doesn't stop the
+    /// others. Requests go through the transport one by one, so a rate limiting layer applies to
+    /// each of them.
+    pub async fn execute_many<'a, T, U, I>(
+        &'a self,
+        parameters: I,
+        concurrency: usize,
+    ) -> Vec<Result<U, SharesightReqwestError>>
+    where
+        T: ApiEndpoint<'a>,
+        T::Parameters: 'a,
+        U: DeserializeOwned,
+        I: IntoIterator<Item = &'a T::Parameters>,
+    {
+        stream::iter(parameters)
+            .map(|parameters| self.execute::<T, U>(parameters))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Download the file returned by the endpoint, such as a document attached to a trade. The
+    /// transport reads the whole file before it is returned; use `download_streaming` on a
+    /// client using `reqwest` to read a large file as it arrives.
+    pub async fn download<'a, T: ApiFileEndpoint<'a>>(
+        &'a self,
+        parameters: &'a T::Parameters,
+    ) -> Result<Download, SharesightReqwestError> {
+        let response = self.send::<T>(parameters).await?;
+
+        Ok(Download::buffered(response))
+    }
+
+    async fn send<'a, T: ApiEndpoint<'a>>(
+        &'a self,
+        parameters: &'a T::Parameters,
+    ) -> Result<HttpResponse, SharesightReqwestError> {
+        let resp = self.send_raw::<T>(parameters).await?;
+
+        success(
+            &T::url_with_base(&self.base_url, parameters).to_string(),
+            resp,
+        )
+    }
+
+    async fn send_raw<'a, T: ApiEndpoint<'a>>(
+        &'a self,
+        parameters: &'a T::Parameters,
+    ) -> Result<HttpResponse, SharesightReqwestError> {
+        let request = self.request::<T>(parameters)?;
+
+        self.send_request(request, RequestSpan::new::<T>()).await
+    }
+
+    /// Send the request through the transport within its span, sending it again as the retry
+    /// policy allows.
+    pub(crate) async fn send_request(
+        &self,
+        request: HttpRequest,
+        span: RequestSpan,
+    ) -> Result<HttpResponse, SharesightReqwestError> {
+        let mut retries = 0;
+        let resp = span
+            .instrument(async {
+                loop {
+                    let resp = self
+                        .transport
+                        .send(request.clone())
+                        .await
+                        .map_err(|e| SharesightReqwestError::from_transport(e.into()));
+
+                    match self
+                        .retry
+                        .delay(retries, request.method, Attempt::of(&resp))
+                    {
+                        Some(delay) => {
+                            debug!("Retrying {} in {:?}", request.url, delay);
+                            tokio::time::sleep(delay).await;
+                            retries += 1;
+                        }
+                        None => break resp,
+                    }
                 }
-                e
-            })?)
+            })
+            .await;
+        span.finish(&resp, retries);
+
+        resp
+    }
+
+    /// The request for the endpoint, once the parameters have been validated.
+    fn request<'a, T: ApiEndpoint<'a>>(
+        &'a self,
+        parameters: &'a T::Parameters,
+    ) -> Result<HttpRequest, SharesightReqwestError> {
+        if self.validate {
+            T::validate(parameters)?;
+            if let Some(ref decimal_markets) = self.decimal_markets {
+                T::validate_quantity(parameters, decimal_markets)?;
+            }
+        }
+
+        Ok(self.http_request(
+            T::HTTP_METHOD,
+            T::url_with_base(&self.base_url, parameters).to_string(),
+            serde_json::to_vec(parameters)?,
+        ))
+    }
+
+    /// A request to the URL with the access token and a JSON body.
+    pub(crate) fn http_request(
+        &self,
+        method: ApiHttpMethod,
+        url: String,
+        body: Vec<u8>,
+    ) -> HttpRequest {
+        HttpRequest {
+            method,
+            url,
+            headers: vec![
+                (
+                    "authorization".to_string(),
+                    format!("Bearer {}", self.credentials.access_token()),
+                ),
+                ("content-type".to_string(), "application/json".to_string()),
+            ],
+            body,
+        }
+    }
+}
+
+/// The response when it is successful, or else an [`Http`](SharesightReqwestError::Http) error
+/// for the URL.
+pub(crate) fn success(
+    url: &str,
+    resp: HttpResponse,
+) -> Result<HttpResponse, SharesightReqwestError> {
+    if resp.is_success() {
+        Ok(resp)
+    } else {
+        let url =
+            reqwest::Url::parse(url).map_err(|e| SharesightReqwestError::Transport(Box::new(e)))?;
+        let status = reqwest::StatusCode::from_u16(resp.status)
+            .map_err(|e| SharesightReqwestError::Transport(Box::new(e)))?;
+
+        Err(SharesightReqwestError::Http(
+            url,
+            status,
+            String::from_utf8_lossy(&resp.body).into_owned(),
+        ))
+    }
+}
+
+impl Client {
+    /// Download the file returned by the endpoint, returning once its headers have arrived so
+    /// the file is read from the connection in chunks. This sends the request directly with
+    /// `reqwest`, bypassing the buffering of the transport.
+    pub async fn download_streaming<'a, T: ApiFileEndpoint<'a>>(
+        &'a self,
+        parameters: &'a T::Parameters,
+    ) -> Result<Download, SharesightReqwestError> {
+        let request = self.request::<T>(parameters)?;
+        let span = RequestSpan::new::<T>();
+        let mut retries = 0;
+        let resp = span
+            .instrument(async {
+                loop {
+                    let resp = self.transport.request_builder(request.clone()).send().await;
+
+                    match self
+                        .retry
+                        .delay(retries, request.method, Attempt::of_streaming(&resp))
+                    {
+                        Some(delay) => {
+                            debug!("Retrying {} in {:?}", request.url, delay);
+                            tokio::time::sleep(delay).await;
+                            retries += 1;
+                        }
+                        None => break resp,
+                    }
+                }
+            })
+            .await;
+        span.finish_streaming(&resp, retries);
+        let resp = resp?;
+
+        if resp.status().is_success() {
+            Ok(Download::streaming(resp))
         } else {
+            let url = resp.url().clone();
+            let status = resp.status();
+
             Err(SharesightReqwestError::Http(
-                resp.url().clone(),
-                resp.status(),
+                url,
+                status,
                 resp.text().await?,
             ))
         }
     }
 }
 
+/// The name of the endpoint type, such as `PortfolioList`.
+fn endpoint_name<'a, T: ApiEndpoint<'a>>() -> &'static str {
+    std::any::type_name::<T>()
+        .rsplit("::")
+        .next()
+        .unwrap_or_default()
+}
+
+/// Sends requests using `reqwest`.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport(reqwest::Client);
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        ReqwestTransport(client)
+    }
+
+    fn request_builder(&self, request: HttpRequest) -> reqwest::RequestBuilder {
+        let method = match request.method {
+            ApiHttpMethod::Get => reqwest::Method::GET,
+            ApiHttpMethod::Post => reqwest::Method::POST,
+            ApiHttpMethod::Put => reqwest::Method::PUT,
+            ApiHttpMethod::Delete => reqwest::Method::DELETE,
+        };
+        let mut builder = self.0.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        builder.body(request.body)
+    }
+}
+
+impl Transport for ReqwestTransport {
+    type Error = reqwest::Error;
+
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_, Self::Error> {
+        Box::pin(async move {
+            let resp = self.request_builder(request).send().await?;
+            let status = resp.status().as_u16();
+            let headers = resp
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect();
+            let body = resp.bytes().await?.to_vec();
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+/// A downloaded file, read either in chunks or all at once.
+pub struct Download {
+    body: DownloadBody,
+    content_length: Option<u64>,
+}
+
+enum DownloadBody {
+    /// The whole file, already read by the transport, so it is a single chunk.
+    Buffered(HttpResponse, Option<bytes::Bytes>),
+    /// A response from `reqwest` that the file is read from as it arrives.
+    Streaming(reqwest::Response),
+}
+
+impl Download {
+    fn buffered(mut response: HttpResponse) -> Self {
+        let body = bytes::Bytes::from(std::mem::take(&mut response.body));
+
+        Download {
+            content_length: Some(body.len() as u64),
+            body: DownloadBody::Buffered(response, Some(body)),
+        }
+    }
+
+    fn streaming(response: reqwest::Response) -> Self {
+        Download {
+            content_length: response.content_length(),
+            body: DownloadBody::Streaming(response),
+        }
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        match self.body {
+            DownloadBody::Buffered(ref response, _) => response.header(name),
+            DownloadBody::Streaming(ref response) => response.headers().get(name)?.to_str().ok(),
+        }
+    }
+
+    /// The media type of the file, from the `Content-Type` header.
+    pub fn content_type(&self) -> Option<&str> {
+        self.header("content-type")
+    }
+
+    /// The name of the file, from the `Content-Disposition` header. The percent-encoded
+    /// `filename*` form of RFC 6266 is preferred over `filename` when it is UTF-8.
+    pub fn filename(&self) -> Option<String> {
+        let params = disposition_params(self.header("content-disposition")?);
+        let param = |name: &str| {
+            params
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        };
+
+        let extended = param("filename*").and_then(|value| {
+            let (charset, value) = value.split_once('\'')?;
+            let (_language, encoded) = value.split_once('\'')?;
+            if !charset.eq_ignore_ascii_case("utf-8") {
+                return None;
+            }
+
+            percent_decode_str(encoded).decode_utf8().ok()
+        });
+
+        match extended {
+            Some(filename) => Some(filename.into_owned()),
+            None => param("filename").map(str::to_string),
+        }
+    }
+
+    /// The size of the file in bytes, when it is known before the file has been read.
+    pub fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+
+    /// The next chunk of the file, or `None` once it has all been read.
+    pub async fn chunk(&mut self) -> Result<Option<bytes::Bytes>, SharesightReqwestError> {
+        match self.body {
+            DownloadBody::Buffered(_, ref mut body) => Ok(body.take()),
+            DownloadBody::Streaming(ref mut response) => Ok(response.chunk().await?),
+        }
+    }
+
+    /// The rest of the file.
+    pub async fn bytes(self) -> Result<bytes::Bytes, SharesightReqwestError> {
+        match self.body {
+            DownloadBody::Buffered(_, body) => Ok(body.unwrap_or_default()),
+            DownloadBody::Streaming(response) => Ok(response.bytes().await?),
+        }
+    }
+
+    /// Write the rest of the file to the path, returning the number of bytes written.
+    pub async fn write_to<P: AsRef<Path>>(
+        mut self,
+        path: P,
+    ) -> Result<u64, SharesightReqwestError> {
+        let mut file = tokio::fs::File::create(path).await?;
+        let mut written = 0;
+
+        while let Some(chunk) = self.chunk().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await?;
+
+        Ok(written)
+    }
+}
+
+/// The parameters of a `Content-Disposition` header as (name, value), with quoted values
+/// unescaped, so a `;` or `"` within a quoted file name is kept.
+fn disposition_params(disposition: &str) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    let mut chars = disposition.chars().peekable();
+
+    // The disposition type, such as `attachment`, comes before the parameters.
+    for c in chars.by_ref() {
+        if c == ';' {
+            break;
+        }
+    }
+
+    while chars.peek().is_some() {
+        let mut name = String::new();
+        let mut value = String::new();
+
+        for c in chars.by_ref() {
+            match c {
+                '=' | ';' => break,
+                _ => name.push(c),
+            }
+        }
+
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' => break,
+                    '\\' => value.extend(chars.next()),
+                    _ => value.push(c),
+                }
+            }
+        }
+
+        for c in chars.by_ref() {
+            match c {
+                ';' => break,
+                _ => value.push(c),
+            }
+        }
+
+        let name = name.trim();
+        if !name.is_empty() {
+            params.push((name.to_string(), value.trim().to_string()));
+        }
+    }
+
+    params
+}
+
 impl AsRef<reqwest::Client> for Client {
     fn as_ref(&self) -> &reqwest::Client {
-        &self.client
+        &self.transport.0
     }
 }
 
@@ -88,6 +641,441 @@ pub enum SharesightReqwestError {
     Http(reqwest::Url, reqwest::StatusCode, String),
     #[error("Http error occurred\n{0:?}")]
     Reqwest(#[from] reqwest::Error),
-    #[error("Deserialize error occurred\n{0:?}")]
-    Deserialize(#[from] serde_json::Error),
+    #[error("Deserialize error occurred\n{0}")]
+    Deserialize(Box<DeserializeError>),
+    #[error("{0} returned fields that aren't in its success type: {1:?}")]
+    UnknownFields(&'static str, Vec<String>),
+    #[error("Serialize error occurred\n{0:?}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("Invalid parameters\n{0}")]
+    Validation(#[from] ValidationError),
+    #[error("IO error occurred\n{0:?}")]
+    Io(#[from] std::io::Error),
+    #[error("Cassette error occurred\n{0}")]
+    Cassette(#[from] CassetteError),
+    #[error("{0}")]
+    Resolve(#[from] ResolveError),
+    #[error("Invalid parameters\n{0}")]
+    Parameter(#[from] ParameterError),
+    #[error("Invalid client configuration\n{0}")]
+    Config(String),
+    #[error("Transport error occurred\n{0:?}")]
+    Transport(Box<dyn Error + Send + Sync>),
+}
+
+impl SharesightReqwestError {
+    fn from_transport(e: Box<dyn Error + Send + Sync>) -> Self {
+        let e = match e.downcast::<reqwest::Error>() {
+            Ok(e) => return SharesightReqwestError::Reqwest(*e),
+            Err(e) => e,
+        };
+
+        match e.downcast::<CassetteError>() {
+            Ok(e) => SharesightReqwestError::Cassette(*e),
+            Err(e) => SharesightReqwestError::Transport(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod client_tests {
+    use std::{
+        convert::{Infallible, TryFrom},
+        sync::{Arc, Mutex},
+    };
+
+    use chrono::NaiveDate;
+    use sharesight_types::{
+        ApiHttpMethod, CashAccountTransactionDelete, CashAccountTransactionDeleteParameters,
+        DocumentShow, DocumentShowParameters, HttpRequest, HttpResponse, Market, NewTrade,
+        NewTradeDetails, Number, PortfolioList, PortfolioListSuccess, TradeInstrument,
+        TradesCreate, TradesCreateParameters, Transport, TransportFuture, ValidationError,
+    };
+
+    use super::{fake_server::FakeServer, Client, RetryPolicy, SharesightReqwestError};
+
+    /// Answers every request with the response, keeping the requests sent.
+    #[derive(Clone)]
+    pub(crate) struct FakeTransport {
+        response: HttpResponse,
+        pub(crate) requests: Arc<Mutex<Vec<HttpRequest>>>,
+    }
+
+    impl FakeTransport {
+        pub(crate) fn new(status: u16, body: &str) -> Self {
+            FakeTransport {
+                response: HttpResponse {
+                    status,
+                    headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+                    body: body.as_bytes().to_vec(),
+                },
+                requests: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+    }
+
+    impl Transport for FakeTransport {
+        type Error = Infallible;
+
+        fn send(&self, request: HttpRequest) -> TransportFuture<'_, Self::Error> {
+            self.requests.lock().unwrap().push(request);
+            let response = self.response.clone();
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    #[tokio::test]
+    async fn execute() {
+        let client = Client::new_with_transport(
+            FakeTransport::new(200, r#"{"portfolios":[],"links":{"self":"x"}}"#),
+            "token".to_string(),
+            "api.example.com".to_string(),
+        );
+
+        let portfolios = client.portfolios().list().await.unwrap();
+        assert!(portfolios.portfolios.is_empty());
+
+        let requests = client.transport.requests.lock().unwrap();
+        assert_eq!(1, requests.len());
+        assert_eq!(ApiHttpMethod::Get, requests[0].method);
+        assert_eq!(
+            "https://api.example.com/api/v2/portfolios.json",
+            requests[0].url
+        );
+        assert!(requests[0]
+            .headers
+            .contains(&("authorization".to_string(), "Bearer token".to_string())));
+    }
+
+    #[tokio::test]
+    async fn http_error() {
+        let client = Client::new_with_transport(
+            FakeTransport::new(404, "not found"),
+            "token".to_string(),
+            "api.example.com".to_string(),
+        );
+
+        match client.execute::<PortfolioList, ()>(&()).await {
+            Err(SharesightReqwestError::Http(url, status, body)) => {
+                assert_eq!("/api/v2/portfolios.json", url.path());
+                assert_eq!(404, status.as_u16());
+                assert_eq!("not found", body);
+            }
+            other => panic!("expected an HTTP error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[tokio::test]
+    async fn validation() {
+        let trade = NewTrade::new(
+            TradeInstrument::Symbol {
+                portfolio_id: 1,
+                symbol: "AIR".to_string(),
+                market: Market::ASX,
+            },
+            NaiveDate::from_ymd_opt(2023, 7, 1).unwrap(),
+            NewTradeDetails::Buy {
+                quantity: "10.5".parse::<Number>().unwrap(),
+                price: Number::from(2),
+                brokerage: None,
+                brokerage_currency_code: None,
+            },
+        );
+        let parameters = TradesCreateParameters::try_from(trade).unwrap();
+        let transport = FakeTransport::new(200, "null");
+        let requests = transport.requests.clone();
+        let client = Client::new_with_transport(
+            transport,
+            "token".to_string(),
+            "api.example.com".to_string(),
+        )
+        .with_decimal_markets(vec![Market::NZX]);
+
+        assert!(matches!(
+            client.execute::<TradesCreate, ()>(&parameters).await,
+            Err(SharesightReqwestError::Validation(
+                ValidationError::NotWhole("quantity")
+            ))
+        ));
+        assert!(requests.lock().unwrap().is_empty());
+
+        let client = client.with_validation(false);
+        client
+            .execute::<TradesCreate, ()>(&parameters)
+            .await
+            .unwrap();
+        assert_eq!(1, requests.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn download() {
+        let transport = FakeTransport {
+            response: HttpResponse {
+                status: 200,
+                headers: vec![
+                    ("Content-Type".to_string(), "application/pdf".to_string()),
+                    (
+                        "Content-Disposition".to_string(),
+                        "attachment; filename=\"resume.pdf\"; filename*=UTF-8''r%C3%A9sum%C3%A9.pdf"
+                            .to_string(),
+                    ),
+                ],
+                body: b"%PDF-1.4".to_vec(),
+            },
+            requests: Arc::new(Mutex::new(Vec::new())),
+        };
+        let client = Client::new_with_transport(
+            transport,
+            "token".to_string(),
+            "api.example.com".to_string(),
+        );
+
+        let download = client
+            .download::<DocumentShow>(&DocumentShowParameters { id: 1 })
+            .await
+            .unwrap();
+        assert_eq!(Some("résumé.pdf".to_string()), download.filename());
+        assert_eq!(Some("application/pdf"), download.content_type());
+        assert_eq!(Some(8), download.content_length());
+
+        let path = std::env::temp_dir().join(format!("sharesight-download-{}", std::process::id()));
+        assert_eq!(8, download.write_to(&path).await.unwrap());
+        assert_eq!(b"%PDF-1.4".to_vec(), std::fs::read(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        let download = super::Download::buffered(HttpResponse {
+            status: 200,
+            headers: vec![(
+                "content-disposition".to_string(),
+                "attachment; filename=\"contract note.pdf\"".to_string(),
+            )],
+            body: Vec::new(),
+        });
+        assert_eq!(Some("contract note.pdf".to_string()), download.filename());
+
+        let mut download = super::Download::buffered(HttpResponse {
+            status: 200,
+            headers: vec![(
+                "content-disposition".to_string(),
+                "attachment; filename=\"a; \\\"b\\\".pdf\"; size=3".to_string(),
+            )],
+            body: b"abc".to_vec(),
+        });
+        assert_eq!(Some("a; \"b\".pdf".to_string()), download.filename());
+        assert!(download.chunk().await.unwrap().is_some());
+        assert_eq!(Some(3), download.content_length());
+    }
+
+    #[tokio::test]
+    async fn retry() {
+        let server = FakeServer::start(vec![
+            ("HTTP/1.1 503 Service Unavailable\r\n", vec!["busy"]),
+            ("HTTP/1.1 200 OK\r\n", vec!["%PDF"]),
+            (
+                "HTTP/1.1 429 Too Many Requests\r\nretry-after: 0\r\n",
+                vec![],
+            ),
+            ("HTTP/1.1 200 OK\r\n", vec!["-1.4"]),
+            ("HTTP/1.1 503 Service Unavailable\r\n", vec!["busy"]),
+        ])
+        .await;
+        let client = Client::builder("token".to_string())
+            .base_url(format!("{}/api/v2", server.url))
+            .retry(RetryPolicy::new(1).backoff(std::time::Duration::ZERO))
+            .build()
+            .unwrap();
+
+        let download = client
+            .download::<DocumentShow>(&DocumentShowParameters { id: 1 })
+            .await
+            .unwrap();
+        assert_eq!(b"%PDF".to_vec(), download.bytes().await.unwrap());
+
+        let download = client
+            .download_streaming::<DocumentShow>(&DocumentShowParameters { id: 1 })
+            .await
+            .unwrap();
+        assert_eq!(b"-1.4".to_vec(), download.bytes().await.unwrap());
+        assert_eq!(4, server.requests().len());
+
+        // Nothing listens once the last response has been sent, so the retry fails to connect.
+        assert!(matches!(
+            client
+                .download::<DocumentShow>(&DocumentShowParameters { id: 1 })
+                .await,
+            Err(SharesightReqwestError::Reqwest(_))
+        ));
+        assert_eq!(5, server.requests().len());
+    }
+
+    #[tokio::test]
+    async fn download_streaming() {
+        let server = FakeServer::start(vec![
+            (
+                "HTTP/1.1 200 OK\r\ncontent-type: application/pdf\r\n",
+                vec!["%PDF", "-1.4"],
+            ),
+            ("HTTP/1.1 404 Not Found\r\n", vec!["not found"]),
+        ])
+        .await;
+        let client = Client::builder("token".to_string())
+            .base_url(format!("{}/api/v2", server.url))
+            .build()
+            .unwrap();
+
+        let mut download = client
+            .download_streaming::<DocumentShow>(&DocumentShowParameters { id: 1 })
+            .await
+            .unwrap();
+        assert_eq!(Some("application/pdf"), download.content_type());
+        let mut file = Vec::new();
+        while let Some(chunk) = download.chunk().await.unwrap() {
+            file.extend_from_slice(&chunk);
+        }
+        assert_eq!(b"%PDF-1.4".to_vec(), file);
+
+        let request = &server.requests()[0];
+        assert!(request.starts_with("GET /api/v2/documents/1.json "));
+        assert!(request.contains("authorization: Bearer token\r\n"));
+
+        match client
+            .download_streaming::<DocumentShow>(&DocumentShowParameters { id: 2 })
+            .await
+        {
+            Err(SharesightReqwestError::Http(url, status, body)) => {
+                assert_eq!("/api/v2/documents/2.json", url.path());
+                assert_eq!(404, status.as_u16());
+                assert_eq!("not found", body);
+            }
+            other => panic!("expected an HTTP error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[tokio::test]
+    async fn strict() {
+        let client = Client::new_with_transport(
+            FakeTransport::new(
+                200,
+                r#"{"portfolios":[],"links":{"self":"x"},"surprise":1}"#,
+            ),
+            "token".to_string(),
+            "api.example.com".to_string(),
+        );
+
+        client
+            .execute::<PortfolioList, PortfolioListSuccess>(&())
+            .await
+            .unwrap();
+
+        // With `extra-fields`, the unknown field is kept in `extra` rather than being an error.
+        let client = client.with_strict(true);
+        match client
+            .execute::<PortfolioList, PortfolioListSuccess>(&())
+            .await
+        {
+            Ok(_) if sharesight_types::EXTRA_FIELDS => {}
+            Err(SharesightReqwestError::UnknownFields(endpoint, fields))
+                if !sharesight_types::EXTRA_FIELDS =>
+            {
+                assert_eq!("PortfolioList", endpoint);
+                assert_eq!(vec!["surprise".to_string()], fields);
+            }
+            other => panic!("unexpected result {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[tokio::test]
+    async fn layer() {
+        let transport = FakeTransport::new(200, "null");
+        let requests = transport.requests.clone();
+        let client = Client::new_with_transport(
+            transport,
+            "token".to_string(),
+            "api.example.com".to_string(),
+        )
+        .layer(tower::util::MapRequestLayer::new(
+            |mut request: HttpRequest| {
+                request
+                    .headers
+                    .push(("x-request-id".to_string(), "1".to_string()));
+                request
+            },
+        ));
+
+        client.execute::<PortfolioList, ()>(&()).await.unwrap();
+
+        assert!(requests.lock().unwrap()[0]
+            .headers
+            .contains(&("x-request-id".to_string(), "1".to_string())));
+    }
+
+    #[tokio::test]
+    async fn base_url() {
+        let transport = FakeTransport::new(200, "null");
+        let requests = transport.requests.clone();
+        let client = Client::builder("token".to_string())
+            .base_url("http://127.0.0.1:3000/stub/api/v2/")
+            .build_with_transport(transport)
+            .unwrap();
+
+        client.execute::<PortfolioList, ()>(&()).await.unwrap();
+
+        assert_eq!(
+            "http://127.0.0.1:3000/stub/api/v2/portfolios.json",
+            requests.lock().unwrap()[0].url
+        );
+        assert!(matches!(
+            Client::builder("token".to_string())
+                .base_url("127.0.0.1")
+                .build(),
+            Err(SharesightReqwestError::Config(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_many() {
+        let transport = FakeTransport::new(200, "null");
+        let requests = transport.requests.clone();
+        let client = Client::new_with_transport(
+            transport,
+            "token".to_string(),
+            "api.example.com".to_string(),
+        );
+        let parameters = (1..=3)
+            .map(|id| CashAccountTransactionDeleteParameters { id })
+            .collect::<Vec<_>>();
+
+        let results = client
+            .execute_many::<CashAccountTransactionDelete, (), _>(&parameters, 2)
+            .await;
+
+        assert_eq!(3, results.len());
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(3, requests.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn raw_and_json() {
+        let client = Client::new_with_transport(
+            FakeTransport::new(200, r#"{"portfolios":[],"links":{"self":"x"},"new":1}"#),
+            "token".to_string(),
+            "api.example.com".to_string(),
+        );
+
+        let (success, json) = client
+            .execute_with_json::<PortfolioList, PortfolioListSuccess>(&())
+            .await
+            .unwrap();
+        assert!(success.portfolios.is_empty());
+        assert_eq!(1, json["new"]);
+
+        let client = Client::new_with_transport(
+            FakeTransport::new(404, "not found"),
+            "token".to_string(),
+            "api.example.com".to_string(),
+        );
+        let response = client.execute_raw::<PortfolioList>(&()).await.unwrap();
+        assert_eq!(404, response.status);
+        assert_eq!(b"not found".to_vec(), response.body);
+    }
 }
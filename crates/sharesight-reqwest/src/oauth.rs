@@ -0,0 +1,464 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use sha2::{Digest, Sha256};
+use sharesight_types::{Auth, AuthWithDetails};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// The path of the loopback redirect URI.
+const CALLBACK_PATH: &str = "/callback";
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("Http error occurred\n{0:?}")]
+    Reqwest(#[from] reqwest::Error),
+    #[error("IO error occurred\n{0:?}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not generate random values\n{0}")]
+    Random(getrandom::Error),
+    #[error("Invalid API host: {0}")]
+    InvalidHost(String),
+    #[error("The authorization was denied: {0}")]
+    Denied(String),
+    #[error("The redirect has a different state, so it isn't for this authorization")]
+    StateMismatch,
+    #[error("The redirect has no authorization code")]
+    MissingCode,
+    #[error("OAuth request returned non-success status code\n{0}\n{1}")]
+    Token(reqwest::StatusCode, String),
+}
+
+/// A PKCE code verifier and its S256 challenge.
+pub struct Pkce {
+    pub verifier: String,
+    pub challenge: String,
+}
+
+impl Pkce {
+    pub fn new() -> Result<Self, AuthError> {
+        Ok(Pkce::from_verifier(random_string()?))
+    }
+
+    pub fn from_verifier(verifier: String) -> Self {
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+
+        Pkce {
+            verifier,
+            challenge,
+        }
+    }
+}
+
+/// 32 random bytes, base64 encoded for use in URLs.
+fn random_string() -> Result<String, AuthError> {
+    let mut bytes = [0; 32];
+    getrandom::getrandom(&mut bytes).map_err(AuthError::Random)?;
+
+    Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// Authorizes a user with the OAuth2 authorization code flow, catching the redirect with a
+/// temporary HTTP listener on `127.0.0.1`, for example:
+///
+/// ```no_run
+/// # async fn run() -> Result<(), sharesight_reqwest::AuthError> {
+/// use sharesight_reqwest::AuthorizationCodeFlow;
+///
+/// let auth = AuthorizationCodeFlow::new("api.sharesight.com", "client id", "client secret")
+///     .with_redirect_port(8912)
+///     .run(|url| println!("Open {} to authorize", url))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The redirect URI, `http://127.0.0.1:<port>/callback`, must be one of the redirect URIs of the
+/// API application.
+pub struct AuthorizationCodeFlow {
+    api_host: String,
+    client_id: String,
+    client_secret: String,
+    redirect_port: u16,
+    client: reqwest::Client,
+}
+
+impl AuthorizationCodeFlow {
+    pub fn new(api_host: &str, client_id: &str, client_secret: &str) -> Self {
+        AuthorizationCodeFlow {
+            api_host: api_host.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_port: 0,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// The port to listen for the redirect on. By default any free port is used, which only
+    /// suits API applications allowing any loopback port.
+    pub fn with_redirect_port(mut self, redirect_port: u16) -> Self {
+        self.redirect_port = redirect_port;
+        self
+    }
+
+    pub fn with_reqwest_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// The URL the user visits to authorize the API application.
+    pub fn authorize_url(
+        &self,
+        redirect_uri: &str,
+        state: &str,
+        pkce: &Pkce,
+    ) -> Result<reqwest::Url, AuthError> {
+        let mut url = reqwest::Url::parse(&format!("https://{}/oauth2/authorize", self.api_host))
+            .map_err(|_| AuthError::InvalidHost(self.api_host.clone()))?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", redirect_uri)
+            .append_pair("state", state)
+            .append_pair("code_challenge", &pkce.challenge)
+            .append_pair("code_challenge_method", "S256");
+
+        Ok(url)
+    }
+
+    /// Listen for the redirect, hand the authorize URL to `open` to show to the user or open in a
+    /// browser, then exchange the code from the redirect for an access token.
+    pub async fn run<F: FnOnce(&reqwest::Url)>(
+        self,
+        open: F,
+    ) -> Result<AuthWithDetails, AuthError> {
+        let listener = TcpListener::bind(("127.0.0.1", self.redirect_port)).await?;
+        let redirect_uri = format!(
+            "http://127.0.0.1:{}{}",
+            listener.local_addr()?.port(),
+            CALLBACK_PATH
+        );
+        let state = random_string()?;
+        let pkce = Pkce::new()?;
+
+        open(&self.authorize_url(&redirect_uri, &state, &pkce)?);
+
+        let code = receive_code(&listener, &state).await?;
+        let auth = self
+            .exchange_code(&code, &redirect_uri, Some(&pkce.verifier))
+            .await?;
+
+        Ok(AuthWithDetails {
+            auth,
+            host: self.api_host,
+            client_id: self.client_id,
+            client_secret: self.client_secret,
+        })
+    }
+
+    /// Exchange an authorization code for an access token.
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        redirect_uri: &str,
+        code_verifier: Option<&str>,
+    ) -> Result<Auth, AuthError> {
+        let mut params = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("client_id", &self.client_id),
+            ("client_secret", &self.client_secret),
+        ];
+        if let Some(code_verifier) = code_verifier {
+            params.push(("code_verifier", code_verifier));
+        }
+
+        request_token(&self.client, &format!("https://{}", self.api_host), &params).await
+    }
+}
+
+/// Requests, refreshes and revokes the access tokens of an API application.
+pub struct OAuthClient {
+    api_host: String,
+    base_url: String,
+    client_id: String,
+    client_secret: String,
+    client: reqwest::Client,
+}
+
+impl OAuthClient {
+    pub fn new(api_host: &str, client_id: &str, client_secret: &str) -> Self {
+        OAuthClient {
+            api_host: api_host.to_string(),
+            base_url: format!("https://{}", api_host),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn with_reqwest_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Send the OAuth requests to the base URL rather than to `https://` and the API host, such
+    /// as `http://127.0.0.1:3000` for a stub.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Request an access token for the API application itself, with the client credentials
+    /// grant, for server to server integrations.
+    pub async fn client_credentials(&self) -> Result<Auth, AuthError> {
+        let params = [
+            ("grant_type", "client_credentials"),
+            ("client_id", &self.client_id),
+            ("client_secret", &self.client_secret),
+        ];
+
+        request_token(&self.client, &self.base_url, &params).await
+    }
+
+    /// Exchange a refresh token for a new access token.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<Auth, AuthError> {
+        let params = [
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+            ("client_id", &self.client_id),
+            ("client_secret", &self.client_secret),
+        ];
+
+        request_token(&self.client, &self.base_url, &params).await
+    }
+
+    /// Remove the API access the access token gives, invalidating it and its refresh token, for
+    /// example when a user disconnects Sharesight.
+    pub async fn revoke(&self, access_token: &str) -> Result<(), AuthError> {
+        let resp = self
+            .client
+            .post(format!("{}/api/v3/oauth/revoke", self.base_url))
+            .bearer_auth(access_token)
+            .json(&serde_json::json!({ "client_id": self.client_id }))
+            .send()
+            .await?;
+
+        if resp.status().is_success() {
+            Ok(())
+        } else {
+            Err(AuthError::Token(resp.status(), resp.text().await?))
+        }
+    }
+
+    /// The auth together with the details needed to refresh it.
+    pub fn with_details(&self, auth: Auth) -> AuthWithDetails {
+        AuthWithDetails {
+            auth,
+            host: self.api_host.clone(),
+            client_id: self.client_id.clone(),
+            client_secret: self.client_secret.clone(),
+        }
+    }
+}
+
+async fn request_token(
+    client: &reqwest::Client,
+    base_url: &str,
+    params: &[(&str, &str)],
+) -> Result<Auth, AuthError> {
+    let resp = client
+        .post(format!("{}/oauth2/token", base_url))
+        .form(params)
+        .send()
+        .await?;
+
+    if resp.status().is_success() {
+        Ok(resp.json::<Auth>().await?)
+    } else {
+        Err(AuthError::Token(resp.status(), resp.text().await?))
+    }
+}
+
+/// Wait for the redirect to the callback path and return its authorization code, ignoring other
+/// requests a browser makes such as for `/favicon.ico`.
+async fn receive_code(listener: &TcpListener, state: &str) -> Result<String, AuthError> {
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+
+        let mut buf = vec![0; 8192];
+        let len = stream.read(&mut buf).await?;
+        let request = String::from_utf8_lossy(&buf[..len]);
+        let target = request
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .unwrap_or_default();
+        let url = match reqwest::Url::parse(&format!("http://127.0.0.1{}", target)) {
+            Ok(url) if url.path() == CALLBACK_PATH => url,
+            _ => {
+                stream
+                    .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")
+                    .await?;
+                continue;
+            }
+        };
+
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+        let result = if let Some(error) = param("error") {
+            Err(AuthError::Denied(
+                param("error_description").unwrap_or(error),
+            ))
+        } else if param("state").as_deref() != Some(state) {
+            Err(AuthError::StateMismatch)
+        } else {
+            param("code").ok_or(AuthError::MissingCode)
+        };
+
+        let body = if result.is_ok() {
+            "Sharesight access authorized, you can close this window."
+        } else {
+            "Sharesight access was not authorized."
+        };
+        stream
+            .write_all(
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .as_bytes(),
+            )
+            .await?;
+
+        return result;
+    }
+}
+
+#[cfg(test)]
+mod oauth_tests {
+    use tokio::{io::AsyncWriteExt, net::TcpListener};
+
+    use super::{receive_code, AuthError, AuthorizationCodeFlow, OAuthClient, Pkce};
+    use crate::fake_server::FakeServer;
+
+    const TOKEN: &str = r#"{"access_token":"new","expires_in":1800,"refresh_token":"next","created_at":1700000000}"#;
+
+    /// The body of the request, after its headers.
+    fn body(request: &str) -> &str {
+        request.split("\r\n\r\n").nth(1).unwrap_or_default()
+    }
+
+    #[test]
+    fn pkce() {
+        // The example from RFC 7636 appendix B.
+        let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string());
+        assert_eq!(
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM",
+            pkce.challenge
+        );
+
+        let url = AuthorizationCodeFlow::new("api.example.com", "id", "secret")
+            .authorize_url("http://127.0.0.1:8912/callback", "xyz", &pkce)
+            .unwrap();
+        assert!(url.as_str().starts_with(
+            "https://api.example.com/oauth2/authorize?response_type=code&client_id=id"
+        ));
+        assert!(url.as_str().ends_with("&code_challenge_method=S256"));
+    }
+
+    #[tokio::test]
+    async fn redirect() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::spawn(async move {
+            for target in ["/favicon.ico", "/callback?code=abc&state=xyz"] {
+                let mut stream = tokio::net::TcpStream::connect(("127.0.0.1", port))
+                    .await
+                    .unwrap();
+                stream
+                    .write_all(format!("GET {} HTTP/1.1\r\n\r\n", target).as_bytes())
+                    .await
+                    .unwrap();
+            }
+        });
+
+        assert_eq!("abc", receive_code(&listener, "xyz").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn client_credentials_and_refresh() {
+        let server = FakeServer::start(vec![
+            (
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n",
+                vec![TOKEN],
+            ),
+            (
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n",
+                vec![TOKEN],
+            ),
+            (
+                "HTTP/1.1 401 Unauthorized\r\ncontent-type: application/json\r\n",
+                vec![r#"{"error":"invalid_grant"}"#],
+            ),
+        ])
+        .await;
+        let client = OAuthClient::new("api.example.com", "id", "secret").with_base_url(&server.url);
+
+        let auth = client.client_credentials().await.unwrap();
+        assert_eq!("new", auth.access_token);
+        assert_eq!(Some("next".to_string()), auth.refresh_token);
+
+        let auth = client.refresh("old").await.unwrap();
+        assert_eq!("new", auth.access_token);
+
+        match client.refresh("expired").await {
+            Err(AuthError::Token(status, body)) => {
+                assert_eq!(401, status.as_u16());
+                assert_eq!(r#"{"error":"invalid_grant"}"#, body);
+            }
+            other => panic!("expected a token error, got {:?}", other),
+        }
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /oauth2/token "));
+        assert_eq!(
+            "grant_type=client_credentials&client_id=id&client_secret=secret",
+            body(&requests[0])
+        );
+        assert_eq!(
+            "grant_type=refresh_token&refresh_token=old&client_id=id&client_secret=secret",
+            body(&requests[1])
+        );
+    }
+
+    #[tokio::test]
+    async fn revoke() {
+        let server = FakeServer::start(vec![
+            ("HTTP/1.1 200 OK\r\n", vec![]),
+            ("HTTP/1.1 403 Forbidden\r\n", vec!["forbidden"]),
+        ])
+        .await;
+        let client = OAuthClient::new("api.example.com", "id", "secret").with_base_url(&server.url);
+
+        client.revoke("token").await.unwrap();
+        match client.revoke("token").await {
+            Err(AuthError::Token(status, body)) => {
+                assert_eq!(403, status.as_u16());
+                assert_eq!("forbidden", body);
+            }
+            other => panic!("expected a token error, got {:?}", other),
+        }
+
+        let request = &server.requests()[0];
+        assert!(request.starts_with("POST /api/v3/oauth/revoke "));
+        assert!(request.contains("authorization: Bearer token\r\n"));
+        assert_eq!(r#"{"client_id":"id"}"#, body(request));
+    }
+}
@@ -0,0 +1,267 @@
+use std::{fmt, future::Future, time::Duration};
+
+use futures_util::{stream, StreamExt};
+
+use crate::{
+    fresh_auth, Client, ReqwestTransport, SharesightReqwestError, TokenStore, TokenStoreError,
+};
+
+/// How long the access token of an account must still be valid for, or it is refreshed before
+/// use.
+pub const DEFAULT_MIN_VALIDITY: Duration = Duration::from_secs(60);
+
+/// The credentials of many Sharesight users, such as the clients of an adviser, each in its own
+/// token store and refreshed independently.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), sharesight_reqwest::PoolError> {
+/// use sharesight_reqwest::{ClientPool, FileTokenStore};
+///
+/// let mut pool = ClientPool::new();
+/// pool.add(FileTokenStore::new("alice.json")).await?;
+/// pool.add(FileTokenStore::new("bob.json")).await?;
+///
+/// let report = pool
+///     .for_each(4, |_, client| async move { client.portfolios().list().await })
+///     .await;
+/// println!("{}", report);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ClientPool {
+    accounts: Vec<PoolAccount>,
+    min_validity: Duration,
+    client: reqwest::Client,
+}
+
+struct PoolAccount {
+    key: String,
+    store: Box<dyn TokenStore>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PoolError {
+    #[error("Could not get the credentials of the account\n{0}")]
+    TokenStore(#[from] TokenStoreError),
+    #[error("{0}")]
+    Request(#[from] SharesightReqwestError),
+    #[error("Unknown account: {0}")]
+    UnknownAccount(String),
+}
+
+impl Default for ClientPool {
+    fn default() -> Self {
+        ClientPool::new()
+    }
+}
+
+impl ClientPool {
+    pub fn new() -> Self {
+        ClientPool {
+            accounts: Vec::new(),
+            min_validity: DEFAULT_MIN_VALIDITY,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// How long access tokens must still be valid for when an operation starts. Tokens expiring
+    /// sooner are refreshed first.
+    pub fn with_min_validity(mut self, min_validity: Duration) -> Self {
+        self.min_validity = min_validity;
+        self
+    }
+
+    /// The client the API requests of every account are sent with.
+    pub fn with_reqwest_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Add the account in the store under the key, replacing any account with the same key.
+    pub fn insert<S: TokenStore + 'static>(&mut self, key: &str, store: S) {
+        self.accounts.retain(|account| account.key != key);
+        self.accounts.push(PoolAccount {
+            key: key.to_string(),
+            store: Box::new(store),
+        });
+    }
+
+    /// Add the account in the store under the email address of its user, which is returned.
+    pub async fn add<S: TokenStore + 'static>(&mut self, store: S) -> Result<String, PoolError> {
+        let client = self.client_for(&store).await?;
+        let email = client.my_user().await?.user.email;
+
+        self.insert(&email, store);
+
+        Ok(email)
+    }
+
+    pub fn remove(&mut self, key: &str) -> bool {
+        let len = self.accounts.len();
+        self.accounts.retain(|account| account.key != key);
+
+        self.accounts.len() != len
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.accounts.iter().map(|account| account.key.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// A client for the account, with its access token refreshed if needed.
+    pub async fn client(&self, key: &str) -> Result<Client, PoolError> {
+        let account = self
+            .accounts
+            .iter()
+            .find(|account| account.key == key)
+            .ok_or_else(|| PoolError::UnknownAccount(key.to_string()))?;
+
+        self.client_for(account.store.as_ref()).await
+    }
+
+    async fn client_for<S: TokenStore + ?Sized>(&self, store: &S) -> Result<Client, PoolError> {
+        let auth = fresh_auth(store, self.min_validity).await?;
+
+        Ok(Client::new_with_transport(
+            ReqwestTransport::new(self.client.clone()),
+            auth.auth.access_token,
+            auth.host,
+        ))
+    }
+
+    /// Run the operation for every account, up to `concurrency` accounts at a time. An account
+    /// failing, including failing to refresh its token, doesn't stop the others.
+    pub async fn for_each<'a, F, Fut, T>(&'a self, concurrency: usize, f: F) -> PoolReport<T>
+    where
+        F: Fn(&'a str, Client) -> Fut,
+        Fut: Future<Output = Result<T, SharesightReqwestError>>,
+    {
+        let results = stream::iter(&self.accounts)
+            .map(|account| {
+                let f = &f;
+
+                async move {
+                    let result = match self.client_for(account.store.as_ref()).await {
+                        Ok(client) => f(&account.key, client).await.map_err(PoolError::from),
+                        Err(e) => Err(e),
+                    };
+
+                    (account.key.clone(), result)
+                }
+            })
+            .buffered(concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut report = PoolReport {
+            successes: Vec::new(),
+            failures: Vec::new(),
+        };
+        for (key, result) in results {
+            match result {
+                Ok(success) => report.successes.push((key, success)),
+                Err(e) => report.failures.push((key, e)),
+            }
+        }
+
+        report
+    }
+}
+
+/// The results of running an operation for every account of a pool, by account key.
+#[derive(Debug)]
+pub struct PoolReport<T> {
+    pub successes: Vec<(String, T)>,
+    pub failures: Vec<(String, PoolError)>,
+}
+
+impl<T> PoolReport<T> {
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl<T> fmt::Display for PoolReport<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} accounts succeeded, {} failed",
+            self.successes.len(),
+            self.failures.len()
+        )?;
+        for (key, e) in &self.failures {
+            write!(f, "\n{}: {}", key, e)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod pool_tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use sharesight_types::{Auth, AuthWithDetails};
+
+    use super::{ClientPool, PoolError};
+    use crate::{MemoryTokenStore, SharesightReqwestError};
+
+    fn store(access_token: &str, refresh_token: Option<&str>) -> MemoryTokenStore {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        MemoryTokenStore::new(&AuthWithDetails {
+            auth: Auth {
+                access_token: access_token.to_string(),
+                expires_in: if refresh_token.is_some() { 1800 } else { 0 },
+                refresh_token: refresh_token.map(str::to_string),
+                created_at: now,
+            },
+            host: "api.example.com".to_string(),
+            client_id: "id".to_string(),
+            client_secret: "secret".to_string(),
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn for_each() {
+        let mut pool = ClientPool::new();
+        pool.insert("a@example.com", store("a", Some("refresh")));
+        pool.insert("b@example.com", store("b", Some("refresh")));
+        // Expired, with no refresh token.
+        pool.insert("c@example.com", store("c", None));
+        pool.insert("b@example.com", store("b", Some("refresh")));
+
+        let report = pool
+            .for_each(2, |key, _| async move {
+                if key.starts_with('a') {
+                    Ok(key.len())
+                } else {
+                    Err(SharesightReqwestError::Config(key.to_string()))
+                }
+            })
+            .await;
+
+        assert_eq!(vec![("a@example.com".to_string(), 13)], report.successes);
+        assert_eq!(
+            vec!["c@example.com", "b@example.com"],
+            report
+                .failures
+                .iter()
+                .map(|(key, _)| key.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(matches!(report.failures[0].1, PoolError::TokenStore(_)));
+        assert!(matches!(report.failures[1].1, PoolError::Request(_)));
+    }
+}
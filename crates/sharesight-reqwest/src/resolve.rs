@@ -0,0 +1,89 @@
+use sharesight_types::{
+    resolve, resolve_exact, CashAccount, CashAccountsList, CashAccountsListParameters,
+    CashAccountsListSuccess, PortfolioList, PortfolioListPortfoliosSuccess, PortfolioListSuccess,
+    Transport, Valuation, ValuationHoldingsSuccess, ValuationParameters, ValuationSuccess,
+};
+
+use crate::{Client, SharesightReqwestError};
+
+/// Look up what a user refers to by an id, exact name, name ignoring case or unique name prefix,
+/// failing with a [`ResolveError`](sharesight_types::ResolveError) naming the candidates. The
+/// `_exact` methods only accept an id or exact name, for commands that change what they resolve.
+impl<H: Transport> Client<H> {
+    pub async fn resolve_portfolio(
+        &self,
+        query: &str,
+    ) -> Result<PortfolioListPortfoliosSuccess, SharesightReqwestError> {
+        Ok(resolve(&self.list_portfolios().await?, query)?.clone())
+    }
+
+    pub async fn resolve_portfolio_exact(
+        &self,
+        query: &str,
+    ) -> Result<PortfolioListPortfoliosSuccess, SharesightReqwestError> {
+        Ok(resolve_exact(&self.list_portfolios().await?, query)?.clone())
+    }
+
+    pub async fn resolve_cash_account(
+        &self,
+        portfolio_id: i64,
+        query: &str,
+    ) -> Result<CashAccount, SharesightReqwestError> {
+        Ok(resolve(&self.list_cash_accounts(portfolio_id).await?, query)?.clone())
+    }
+
+    pub async fn resolve_cash_account_exact(
+        &self,
+        portfolio_id: i64,
+        query: &str,
+    ) -> Result<CashAccount, SharesightReqwestError> {
+        Ok(resolve_exact(&self.list_cash_accounts(portfolio_id).await?, query)?.clone())
+    }
+
+    /// Holdings are referred to by their instrument's symbol or name, among the holdings of the
+    /// portfolio's valuation today.
+    pub async fn resolve_holding(
+        &self,
+        portfolio_id: i64,
+        query: &str,
+    ) -> Result<ValuationHoldingsSuccess, SharesightReqwestError> {
+        let ValuationSuccess { holdings, .. } = self
+            .execute::<Valuation, ValuationSuccess>(&ValuationParameters {
+                portfolio_id,
+                consolidated: None,
+                include_sales: None,
+                grouping: None,
+                custom_group_id: None,
+                balance_date: None,
+            })
+            .await?;
+
+        Ok(resolve(&holdings, query)?.clone())
+    }
+
+    async fn list_portfolios(
+        &self,
+    ) -> Result<Vec<PortfolioListPortfoliosSuccess>, SharesightReqwestError> {
+        let PortfolioListSuccess { portfolios, .. } = self
+            .execute::<PortfolioList, PortfolioListSuccess>(&())
+            .await?;
+
+        Ok(portfolios)
+    }
+
+    async fn list_cash_accounts(
+        &self,
+        portfolio_id: i64,
+    ) -> Result<Vec<CashAccount>, SharesightReqwestError> {
+        let CashAccountsListSuccess { cash_accounts, .. } = self
+            .execute::<CashAccountsList, CashAccountsListSuccess>(&CashAccountsListParameters {
+                date: None,
+            })
+            .await?;
+
+        Ok(cash_accounts
+            .into_iter()
+            .filter(|a| a.portfolio_id == portfolio_id)
+            .collect())
+    }
+}
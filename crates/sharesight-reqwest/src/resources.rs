@@ -0,0 +1,968 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use sharesight_types::*;
+
+use crate::{Client, Download, ReqwestTransport, SharesightReqwestError};
+
+impl<H: Transport> Client<H> {
+    pub fn portfolio(&self, id: i64) -> PortfolioResource<'_, H> {
+        PortfolioResource {
+            client: self,
+            portfolio_id: id,
+        }
+    }
+
+    pub fn cash_account(&self, id: i64) -> CashAccountResource<'_, H> {
+        CashAccountResource {
+            client: self,
+            cash_account_id: id,
+        }
+    }
+
+    pub fn cash_account_transaction(&self, id: i64) -> CashAccountTransactionResource<'_, H> {
+        CashAccountTransactionResource {
+            client: self,
+            cash_account_transaction_id: id,
+        }
+    }
+
+    pub fn cash_accounts(&self) -> CashAccountsResource<'_, H> {
+        CashAccountsResource { client: self }
+    }
+
+    pub fn document(&self, id: i64) -> DocumentResource<'_, H> {
+        DocumentResource {
+            client: self,
+            document_id: id,
+        }
+    }
+
+    pub fn groups(&self) -> GroupsResource<'_, H> {
+        GroupsResource { client: self }
+    }
+
+    pub fn holding(&self, id: i64) -> HoldingResource<'_, H> {
+        HoldingResource {
+            client: self,
+            holding_id: id,
+        }
+    }
+
+    pub fn user_instruments(&self) -> UserInstrumentsResource<'_, H> {
+        UserInstrumentsResource { client: self }
+    }
+
+    pub fn memberships(&self) -> MembershipsResource<'_, H> {
+        MembershipsResource { client: self }
+    }
+
+    pub fn membership(&self, id: i64) -> MembershipResource<'_, H> {
+        MembershipResource {
+            client: self,
+            membership_id: id,
+        }
+    }
+
+    pub fn currencies(&self) -> CurrenciesResource<'_, H> {
+        CurrenciesResource { client: self }
+    }
+
+    pub fn payouts(&self) -> PayoutsResource<'_, H> {
+        PayoutsResource { client: self }
+    }
+
+    pub fn payout(&self, id: i64) -> PayoutResource<'_, H> {
+        PayoutResource {
+            client: self,
+            payout_id: id,
+        }
+    }
+
+    pub fn portfolios(&self) -> PortfoliosResource<'_, H> {
+        PortfoliosResource { client: self }
+    }
+
+    pub fn trades(&self) -> TradesResource<'_, H> {
+        TradesResource { client: self }
+    }
+
+    pub fn trade(&self, id: i64) -> TradeResource<'_, H> {
+        TradeResource {
+            client: self,
+            trade_id: id,
+        }
+    }
+
+    /// Single sign on authorisation. The single sign-on operation returns a URL that will allow the user to login to their Sharesight account without the need to enter their email address and password. The URL is valid for one minute. A single sign-on link or button in your application should be implemented so that the user click initiates this API call and then the URL returned is launched in the user’s browser. A "redirect_to" parameter can be appended to the login url. After successfully been logged in, the user will be then redirected to the specified redirect_to path; example: https://api.sharesight.com/users/sign_in?signon-token=token&amp;redirect_to=/portfolios/1
+    pub async fn single_sign_on(
+        &self,
+    ) -> Result<RequestSingleSignOnSuccess, SharesightReqwestError> {
+        let parameters = ();
+        self.execute::<RequestSingleSignOn, _>(&parameters).await
+    }
+
+    /// Access information on the current user
+    pub async fn my_user(&self) -> Result<MyUserSuccess, SharesightReqwestError> {
+        let parameters = ();
+        self.execute::<MyUser, _>(&parameters).await
+    }
+}
+
+pub struct PortfolioResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    portfolio_id: i64,
+}
+
+impl<'a, H: Transport> PortfolioResource<'a, H> {
+    pub fn cash_accounts(&self) -> PortfolioCashAccountsResource<'_, H> {
+        PortfolioCashAccountsResource {
+            client: self.client,
+            portfolio_id: self.portfolio_id,
+        }
+    }
+
+    pub fn holding_merges(&self) -> PortfolioHoldingMergesResource<'_, H> {
+        PortfolioHoldingMergesResource {
+            client: self.client,
+            portfolio_id: self.portfolio_id,
+        }
+    }
+
+    pub fn holding_merge(&self, id: i64) -> PortfolioHoldingMergeResource<'_, H> {
+        PortfolioHoldingMergeResource {
+            client: self.client,
+            portfolio_id: self.portfolio_id,
+            holding_merge_id: id,
+        }
+    }
+
+    pub fn payouts(&self) -> PortfolioPayoutsResource<'_, H> {
+        PortfolioPayoutsResource {
+            client: self.client,
+            portfolio_id: self.portfolio_id,
+        }
+    }
+
+    pub fn trades(&self) -> PortfolioTradesResource<'_, H> {
+        PortfolioTradesResource {
+            client: self.client,
+            portfolio_id: self.portfolio_id,
+        }
+    }
+
+    /// Delete an existing user portfolio
+    pub async fn delete(&self) -> Result<PortfolioDeleteSuccess, SharesightReqwestError> {
+        let parameters = PortfolioDeleteParameters {
+            id: self.portfolio_id,
+        };
+        self.client.execute::<PortfolioDelete, _>(&parameters).await
+    }
+
+    /// Returns a single portfolio with settings for the user.
+    pub async fn show(&self) -> Result<PortfolioShowSuccess, SharesightReqwestError> {
+        let parameters = PortfolioShowParameters {
+            id: self.portfolio_id,
+        };
+        self.client.execute::<PortfolioShow, _>(&parameters).await
+    }
+
+    /// Update an existing portfolio for the user.
+    pub async fn update(
+        &self,
+        portfolio: PortfolioUpdatePortfolioParameters,
+    ) -> Result<PortfolioUpdateSuccess, SharesightReqwestError> {
+        let parameters = PortfolioUpdateParameters {
+            portfolio,
+            id: self.portfolio_id,
+        };
+        self.client.execute::<PortfolioUpdate, _>(&parameters).await
+    }
+
+    /// Return a report on capital gains tax (for Australian portfolios only)
+    pub async fn capital_gains(
+        &self,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+    ) -> Result<CapitalGainsSuccess, SharesightReqwestError> {
+        let parameters = CapitalGainsParameters {
+            portfolio_id: self.portfolio_id,
+            start_date,
+            end_date,
+        };
+        self.client.execute::<CapitalGains, _>(&parameters).await
+    }
+
+    /// Retrieves the Diversity Report for the underlying portfolio
+    pub async fn diversity(
+        &self,
+        consolidated: Option<bool>,
+        date: Option<NaiveDate>,
+        grouping: Option<Grouping>,
+        custom_group_id: Option<i64>,
+    ) -> Result<DiversitySuccess, SharesightReqwestError> {
+        let parameters = DiversityParameters {
+            portfolio_id: self.portfolio_id,
+            consolidated,
+            date,
+            grouping,
+            custom_group_id,
+        };
+        self.client.execute::<Diversity, _>(&parameters).await
+    }
+
+    /// Retrieves the Performance Report for the underlying portfolio.
+    ///
+    /// ## Holding Limit
+    ///
+    /// Depending on user's plan the number of holdings shown in the report is limited. In that case   the following response headers will be set:   <ul>     <li>`X-HoldingLimit-Limit`: The plan's holding limit.</li>     <li>`X-HoldingLimit-Total`: The number of holding in the requested portfolio.</li>     <li>`X-HoldingLimit-Reason`: A human-readable string describing why the number of holdings is limited.</li>   </ul>
+    ///
+    /// ## Remarks
+    ///
+    /// <sup>1</sup> Infinity is represented by string values "Infinity" or "-Infinity"
+    pub async fn performance(
+        &self,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+        consolidated: Option<bool>,
+        include_sales: Option<bool>,
+        grouping: Option<Grouping>,
+        custom_group_id: Option<i64>,
+    ) -> Result<PerformanceSuccess, SharesightReqwestError> {
+        let parameters = PerformanceParameters {
+            start_date,
+            end_date,
+            portfolio_id: self.portfolio_id,
+            consolidated,
+            include_sales,
+            grouping,
+            custom_group_id,
+        };
+        self.client.execute::<Performance, _>(&parameters).await
+    }
+
+    /// Return a report on unrealised capital gains tax (for Australian portfolios only)
+    pub async fn unrealised_cgt(
+        &self,
+        balance_date: NaiveDate,
+    ) -> Result<UnrealisedCgtSuccess, SharesightReqwestError> {
+        let parameters = UnrealisedCgtParameters {
+            portfolio_id: self.portfolio_id,
+            balance_date,
+        };
+        self.client.execute::<UnrealisedCgt, _>(&parameters).await
+    }
+
+    /// Retrieves the Valuation Report for the underlying portfolio.
+    pub async fn valuation(
+        &self,
+        balance_date: Option<NaiveDate>,
+        consolidated: Option<bool>,
+        include_sales: Option<bool>,
+        grouping: Option<Grouping>,
+        custom_group_id: Option<i64>,
+    ) -> Result<ValuationSuccess, SharesightReqwestError> {
+        let parameters = ValuationParameters {
+            balance_date,
+            portfolio_id: self.portfolio_id,
+            consolidated,
+            include_sales,
+            grouping,
+            custom_group_id,
+        };
+        self.client.execute::<Valuation, _>(&parameters).await
+    }
+}
+
+pub struct PortfolioCashAccountsResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    portfolio_id: i64,
+}
+
+impl<'a, H: Transport> PortfolioCashAccountsResource<'a, H> {
+    /// Creates a new cash account within a portfolio.
+    pub async fn create(
+        &self,
+        cash_account: CashAccountCreateCashAccountParameters,
+    ) -> Result<CashAccountCreateSuccess, SharesightReqwestError> {
+        let parameters = CashAccountCreateParameters {
+            portfolio_id: self.portfolio_id,
+            cash_account,
+        };
+        self.client
+            .execute::<CashAccountCreate, _>(&parameters)
+            .await
+    }
+}
+
+pub struct CashAccountResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    cash_account_id: i64,
+}
+
+impl<'a, H: Transport> CashAccountResource<'a, H> {
+    pub fn transactions(&self) -> CashAccountTransactionsResource<'_, H> {
+        CashAccountTransactionsResource {
+            client: self.client,
+            cash_account_id: self.cash_account_id,
+        }
+    }
+
+    /// Deletes an existing cash account.
+    pub async fn delete(&self) -> Result<(), SharesightReqwestError> {
+        let parameters = CashAccountDeleteParameters {
+            id: self.cash_account_id,
+        };
+        self.client
+            .execute::<CashAccountDelete, _>(&parameters)
+            .await
+    }
+
+    /// Returns details about a cash account including its balance on a specified date.
+    pub async fn show(
+        &self,
+        date: Option<NaiveDate>,
+    ) -> Result<CashAccountShowSuccess, SharesightReqwestError> {
+        let parameters = CashAccountShowParameters {
+            id: self.cash_account_id,
+            date,
+        };
+        self.client.execute::<CashAccountShow, _>(&parameters).await
+    }
+
+    /// Updates attributes on an existing cash account.
+    pub async fn update(
+        &self,
+        name: String,
+        currency: Currency,
+    ) -> Result<CashAccountUpdateSuccess, SharesightReqwestError> {
+        let parameters = CashAccountUpdateParameters {
+            id: self.cash_account_id,
+            name,
+            currency,
+        };
+        self.client
+            .execute::<CashAccountUpdate, _>(&parameters)
+            .await
+    }
+}
+
+pub struct CashAccountTransactionsResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    cash_account_id: i64,
+}
+
+impl<'a, H: Transport> CashAccountTransactionsResource<'a, H> {
+    /// Creates a new cash transaction within a cash account.
+    pub async fn create(
+        &self,
+        description: String,
+        amount: Number,
+        type_name: CashAccountTransactionTypeName,
+        date_time: DateTime<FixedOffset>,
+        foreign_identifier: Option<String>,
+    ) -> Result<CashAccountTransactionCreateSuccess, SharesightReqwestError> {
+        let parameters = CashAccountTransactionCreateParameters {
+            cash_account_id: self.cash_account_id,
+            description,
+            amount,
+            type_name,
+            date_time,
+            foreign_identifier,
+        };
+        self.client
+            .execute::<CashAccountTransactionCreate, _>(&parameters)
+            .await
+    }
+
+    /// Returns list of transactions for a cash account.
+    pub async fn list(
+        &self,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        description: Option<String>,
+        foreign_identifier: Option<String>,
+    ) -> Result<CashAccountTransactionsListSuccess, SharesightReqwestError> {
+        let parameters = CashAccountTransactionsListParameters {
+            cash_account_id: self.cash_account_id,
+            from,
+            to,
+            description,
+            foreign_identifier,
+        };
+        self.client
+            .execute::<CashAccountTransactionsList, _>(&parameters)
+            .await
+    }
+}
+
+pub struct CashAccountTransactionResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    cash_account_transaction_id: i64,
+}
+
+impl<'a, H: Transport> CashAccountTransactionResource<'a, H> {
+    /// Deletes an existing cash account transaction.
+    pub async fn delete(&self) -> Result<(), SharesightReqwestError> {
+        let parameters = CashAccountTransactionDeleteParameters {
+            id: self.cash_account_transaction_id,
+        };
+        self.client
+            .execute::<CashAccountTransactionDelete, _>(&parameters)
+            .await
+    }
+
+    /// Update an existing cash account transaction.
+    pub async fn update(
+        &self,
+        description: String,
+        amount: Number,
+        type_name: CashAccountTransactionTypeName,
+        date_time: DateTime<FixedOffset>,
+        foreign_identifier: Option<String>,
+    ) -> Result<CashAccountTransactionUpdateSuccess, SharesightReqwestError> {
+        let parameters = CashAccountTransactionUpdateParameters {
+            id: self.cash_account_transaction_id,
+            description,
+            amount,
+            type_name,
+            date_time,
+            foreign_identifier,
+        };
+        self.client
+            .execute::<CashAccountTransactionUpdate, _>(&parameters)
+            .await
+    }
+}
+
+pub struct CashAccountsResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+}
+
+impl<'a, H: Transport> CashAccountsResource<'a, H> {
+    /// Returns list of cash accounts for a portfolio. If you want to only access the cash account of one particular portfolios, use https://api.sharesight.com/api/v2/portfolios/:id/cash_accounts.json. The response will be the same.
+    pub async fn list(
+        &self,
+        date: Option<NaiveDate>,
+    ) -> Result<CashAccountsListSuccess, SharesightReqwestError> {
+        let parameters = CashAccountsListParameters { date };
+        self.client
+            .execute::<CashAccountsList, _>(&parameters)
+            .await
+    }
+}
+
+pub struct DocumentResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    document_id: i64,
+}
+
+impl<'a, H: Transport> DocumentResource<'a, H> {
+    /// Returns a specific document.
+    pub async fn show(&self) -> Result<Download, SharesightReqwestError> {
+        let parameters = DocumentShowParameters {
+            id: self.document_id,
+        };
+        self.client.download::<DocumentShow>(&parameters).await
+    }
+}
+
+pub struct GroupsResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+}
+
+impl<'a, H: Transport> GroupsResource<'a, H> {
+    /// List all custom (and regular) groups which a user has defined
+    pub async fn list(&self) -> Result<GroupsListSuccess, SharesightReqwestError> {
+        let parameters = ();
+        self.client.execute::<GroupsList, _>(&parameters).await
+    }
+}
+
+pub struct PortfolioHoldingMergesResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    portfolio_id: i64,
+}
+
+impl<'a, H: Transport> PortfolioHoldingMergesResource<'a, H> {
+    /// The Create a holding merge endpoint is designed to record a company merger in the   situation where you hold shares in a listed company that is wholly acquired by another   listed company.
+    ///
+    /// It creates a merge (cancel-trade) transaction against the cancelled holding.   This transaction is similar to a sell trade.   <br>   It also creates a merge (buy-trade) transaction within the new holding and   contains both the cost base and market value from the cancelled holding.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        holding_id: i64,
+        merge_date: NaiveDate,
+        quantity: Number,
+        symbol: String,
+        market: Market,
+        cancelled_price: Option<Number>,
+        comments: Option<String>,
+        unique_identifier: Option<String>,
+        attachment: Option<String>,
+        attachment_filename: Option<String>,
+    ) -> Result<HoldingMergesCreateSuccess, SharesightReqwestError> {
+        let parameters = HoldingMergesCreateParameters {
+            portfolio_id: self.portfolio_id,
+            holding_id,
+            merge_date,
+            quantity,
+            symbol,
+            market,
+            cancelled_price,
+            comments,
+            unique_identifier,
+            attachment,
+            attachment_filename,
+        };
+        self.client
+            .execute::<HoldingMergesCreate, _>(&parameters)
+            .await
+    }
+}
+
+pub struct PortfolioHoldingMergeResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    portfolio_id: i64,
+    holding_merge_id: i64,
+}
+
+impl<'a, H: Transport> PortfolioHoldingMergeResource<'a, H> {
+    /// Update a holding merge.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &self,
+        merge_date: Option<NaiveDate>,
+        quantity: Option<Number>,
+        symbol: Option<String>,
+        market: Option<Market>,
+        cancelled_price: Option<Number>,
+        comments: Option<String>,
+        unique_identifier: Option<String>,
+        attachment: Option<String>,
+        attachment_filename: Option<String>,
+    ) -> Result<HoldingMergesUpdateSuccess, SharesightReqwestError> {
+        let parameters = HoldingMergesUpdateParameters {
+            portfolio_id: self.portfolio_id,
+            id: self.holding_merge_id,
+            merge_date,
+            quantity,
+            symbol,
+            market,
+            cancelled_price,
+            comments,
+            unique_identifier,
+            attachment,
+            attachment_filename,
+        };
+        self.client
+            .execute::<HoldingMergesUpdate, _>(&parameters)
+            .await
+    }
+}
+
+pub struct HoldingResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    holding_id: i64,
+}
+
+impl<'a, H: Transport> HoldingResource<'a, H> {
+    pub fn trades(&self) -> HoldingTradesResource<'_, H> {
+        HoldingTradesResource {
+            client: self.client,
+            holding_id: self.holding_id,
+        }
+    }
+
+    pub fn rejected_trades(&self) -> HoldingRejectedTradesResource<'_, H> {
+        HoldingRejectedTradesResource {
+            client: self.client,
+            holding_id: self.holding_id,
+        }
+    }
+
+    pub fn payouts(&self) -> HoldingPayoutsResource<'_, H> {
+        HoldingPayoutsResource {
+            client: self.client,
+            holding_id: self.holding_id,
+        }
+    }
+}
+
+pub struct HoldingTradesResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    holding_id: i64,
+}
+
+impl<'a, H: Transport> HoldingTradesResource<'a, H> {
+    /// Returns trade transactions for a holding.
+    pub async fn list(
+        &self,
+        unique_identifier: Option<String>,
+    ) -> Result<HoldingTradesSuccess, SharesightReqwestError> {
+        let parameters = HoldingTradesParameters {
+            holding_id: self.holding_id.to_string(),
+            unique_identifier,
+        };
+        self.client.execute::<HoldingTrades, _>(&parameters).await
+    }
+}
+
+pub struct HoldingRejectedTradesResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    holding_id: i64,
+}
+
+impl<'a, H: Transport> HoldingRejectedTradesResource<'a, H> {
+    /// Returns reject trade transactions for the holding.
+    pub async fn list(&self) -> Result<HoldingTradesRejectedSuccess, SharesightReqwestError> {
+        let parameters = HoldingTradesRejectedParameters {
+            holding_id: self.holding_id.to_string(),
+        };
+        self.client
+            .execute::<HoldingTradesRejected, _>(&parameters)
+            .await
+    }
+}
+
+pub struct UserInstrumentsResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+}
+
+impl<'a, H: Transport> UserInstrumentsResource<'a, H> {
+    /// Returns a list of instruments in the current user's portfolios.
+    pub async fn list(&self) -> Result<ListUserInstrumentsSuccess, SharesightReqwestError> {
+        let parameters = ();
+        self.client
+            .execute::<ListUserInstruments, _>(&parameters)
+            .await
+    }
+}
+
+pub struct MembershipsResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+}
+
+impl<'a, H: Transport> MembershipsResource<'a, H> {
+    /// Create a new membership for a user's portfolio, using an existing user id or by creating a new user (providing email, etc.).
+    ///
+    /// You may only use this endpoint if the user is on the required plan (not free, etc.).
+    pub async fn create(
+        &self,
+        membership: MembershipCreateMembershipParameters,
+        user: Option<MembershipCreateUserParameters>,
+        invitation: MembershipCreateInvitationParameters,
+    ) -> Result<MembershipCreateSuccess, SharesightReqwestError> {
+        let parameters = MembershipCreateParameters {
+            membership,
+            user,
+            invitation,
+        };
+        self.client
+            .execute::<MembershipCreate, _>(&parameters)
+            .await
+    }
+
+    /// Lists memberships for the current user's portfolios.
+    ///
+    /// If you want to only access the memberships of one particular portfolio, use `https://api.sharesight.com/api/v2/portfolios/:id/memberships.json`. The response will be the same.
+    ///
+    /// You may only use this endpoint if the user is on the required plan (not free, etc.).
+    ///
+    /// To access the memberships of all the user's portfolios, use this common endpoint:
+    pub async fn list(&self) -> Result<MembershipListSuccess, SharesightReqwestError> {
+        let parameters = ();
+        self.client.execute::<MembershipList, _>(&parameters).await
+    }
+}
+
+pub struct MembershipResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    membership_id: i64,
+}
+
+impl<'a, H: Transport> MembershipResource<'a, H> {
+    /// Delete an existing user membership.
+    ///
+    /// You may only use this endpoint if the user is on the required plan (not free, etc.).
+    pub async fn delete(&self) -> Result<(), SharesightReqwestError> {
+        let parameters = MembershipDeleteParameters {
+            id: self.membership_id,
+        };
+        self.client
+            .execute::<MembershipDelete, _>(&parameters)
+            .await
+    }
+
+    /// Update an existing user membership.
+    ///
+    /// You may only use this endpoint if the user is on the required plan (not free, etc.).
+    pub async fn update(
+        &self,
+        access_code: AccessCode,
+    ) -> Result<MembershipUpdateSuccess, SharesightReqwestError> {
+        let parameters = MembershipUpdateParameters {
+            id: self.membership_id,
+            access_code,
+        };
+        self.client
+            .execute::<MembershipUpdate, _>(&parameters)
+            .await
+    }
+}
+
+pub struct CurrenciesResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+}
+
+impl<'a, H: Transport> CurrenciesResource<'a, H> {
+    /// Access currency definitions
+    pub async fn list(&self) -> Result<CurrenciesSuccess, SharesightReqwestError> {
+        let parameters = ();
+        self.client.execute::<Currencies, _>(&parameters).await
+    }
+}
+
+pub struct HoldingPayoutsResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    holding_id: i64,
+}
+
+impl<'a, H: Transport> HoldingPayoutsResource<'a, H> {
+    /// Returns payouts for the user’s holding between the two supplied dates.
+    pub async fn list(
+        &self,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+        use_date: Option<UseDate>,
+    ) -> Result<ListHoldingPayoutsSuccess, SharesightReqwestError> {
+        let parameters = ListHoldingPayoutsParameters {
+            holding_id: self.holding_id,
+            start_date,
+            end_date,
+            use_date,
+        };
+        self.client
+            .execute::<ListHoldingPayouts, _>(&parameters)
+            .await
+    }
+}
+
+pub struct PortfolioPayoutsResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    portfolio_id: i64,
+}
+
+impl<'a, H: Transport> PortfolioPayoutsResource<'a, H> {
+    /// Returns payouts for the user’s portfolio between the two supplied dates.
+    pub async fn list(
+        &self,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+        use_date: Option<UseDate>,
+    ) -> Result<ListPortfolioPayoutsSuccess, SharesightReqwestError> {
+        let parameters = ListPortfolioPayoutsParameters {
+            portfolio_id: self.portfolio_id,
+            start_date,
+            end_date,
+            use_date,
+        };
+        self.client
+            .execute::<ListPortfolioPayouts, _>(&parameters)
+            .await
+    }
+}
+
+pub struct PayoutsResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+}
+
+impl<'a, H: Transport> PayoutsResource<'a, H> {
+    /// Confirm a payout. Tnis can be either a payout based on a company event or a payout based on the payment date of an interest payment.
+    pub async fn confirm(
+        &self,
+        payout: PayoutConfirmPayoutParameters,
+    ) -> Result<PayoutConfirmSuccess, SharesightReqwestError> {
+        let parameters = PayoutConfirmParameters { payout };
+        self.client.execute::<PayoutConfirm, _>(&parameters).await
+    }
+
+    /// Creates a new payout for this portfolio.
+    pub async fn create(
+        &self,
+        payout: PayoutCreatePayoutParameters,
+    ) -> Result<PayoutCreateSuccess, SharesightReqwestError> {
+        let parameters = PayoutCreateParameters { payout };
+        self.client.execute::<PayoutCreate, _>(&parameters).await
+    }
+
+    /// Rejects an unconfirmed payout (based on a corporate action event). A payout derived from the linked corporate action will no longer appear in the users portfolio. (This is similar to the 'Reject this Payout' button in the UI). See Delete Payout in order to delete a confirmed payout.
+    pub async fn reject(
+        &self,
+        holding_id: i64,
+        company_event_id: i64,
+        state: String,
+    ) -> Result<PayoutRejectSuccess, SharesightReqwestError> {
+        let parameters = PayoutRejectParameters {
+            holding_id,
+            company_event_id,
+            state,
+        };
+        self.client.execute::<PayoutReject, _>(&parameters).await
+    }
+}
+
+pub struct PayoutResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    payout_id: i64,
+}
+
+impl<'a, H: Transport> PayoutResource<'a, H> {
+    /// Deletes a confirmed payout, leaving an unconfirmed payout derived from the linked corporate action. (This is similar to the 'Reset this payout') button in the UI). See Reject Payout in order to hide the corporate action altogether.
+    pub async fn delete(&self) -> Result<PayoutDeleteSuccess, SharesightReqwestError> {
+        let parameters = PayoutDeleteParameters { id: self.payout_id };
+        self.client.execute::<PayoutDelete, _>(&parameters).await
+    }
+
+    /// Returns a specific payout.
+    pub async fn show(&self) -> Result<PayoutShowSuccess, SharesightReqwestError> {
+        let parameters = PayoutShowParameters { id: self.payout_id };
+        self.client.execute::<PayoutShow, _>(&parameters).await
+    }
+
+    /// Updates a payout with the provided parameters
+    pub async fn update(
+        &self,
+        payout: PayoutUpdatePayoutParameters,
+    ) -> Result<PayoutUpdateSuccess, SharesightReqwestError> {
+        let parameters = PayoutUpdateParameters {
+            id: self.payout_id,
+            payout,
+        };
+        self.client.execute::<PayoutUpdate, _>(&parameters).await
+    }
+}
+
+pub struct PortfoliosResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+}
+
+impl<'a, H: Transport> PortfoliosResource<'a, H> {
+    /// Create a new portfolio for the current user.
+    pub async fn create(
+        &self,
+        portfolio: PortfolioCreatePortfolioParameters,
+    ) -> Result<PortfolioCreateSuccess, SharesightReqwestError> {
+        let parameters = PortfolioCreateParameters { portfolio };
+        self.client.execute::<PortfolioCreate, _>(&parameters).await
+    }
+
+    /// Returns list of portfolios for the user. If the user owns the portfolio, all the info related to it will be displayed. Otherwise only basic info is returned.
+    pub async fn list(&self) -> Result<PortfolioListSuccess, SharesightReqwestError> {
+        let parameters = ();
+        self.client.execute::<PortfolioList, _>(&parameters).await
+    }
+}
+
+pub struct TradesResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+}
+
+impl<'a, H: Transport> TradesResource<'a, H> {
+    /// Confirm a trade. This can be either a trade based on a company event or a sell trade based on the maturity date of an interest instrument.
+    pub async fn confirm(
+        &self,
+        holding_id: i64,
+        company_event_id: i64,
+        transaction_date: NaiveDate,
+        state: String,
+    ) -> Result<TradeConfirmSuccess, SharesightReqwestError> {
+        let parameters = TradeConfirmParameters {
+            holding_id,
+            company_event_id,
+            transaction_date,
+            state,
+        };
+        self.client.execute::<TradeConfirm, _>(&parameters).await
+    }
+
+    /// Reject a trade. This can be either a trade based on a company event or a sell trade based on the maturity date of an interest instrument.
+    pub async fn reject(
+        &self,
+        holding_id: i64,
+        company_event_id: i64,
+        transaction_date: NaiveDate,
+        state: String,
+    ) -> Result<TradeRejectSuccess, SharesightReqwestError> {
+        let parameters = TradeRejectParameters {
+            holding_id,
+            company_event_id,
+            transaction_date,
+            state,
+        };
+        self.client.execute::<TradeReject, _>(&parameters).await
+    }
+
+    /// Create a trade against a Portfolio or an existing Holding.
+    pub async fn create(
+        &self,
+        trade: TradesCreateTradeParameters,
+    ) -> Result<TradesCreateSuccess, SharesightReqwestError> {
+        let parameters = TradesCreateParameters { trade };
+        self.client.execute::<TradesCreate, _>(&parameters).await
+    }
+}
+
+pub struct PortfolioTradesResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    portfolio_id: i64,
+}
+
+impl<'a, H: Transport> PortfolioTradesResource<'a, H> {
+    /// Returns trade transactions for the user’s portfolio between the two supplied dates. If you want to only access the trades of a particular holding, use `https://api.sharesight.com/api/v2/holdings/:holding_id/trades.json`. The response will be the same.
+    pub async fn list(
+        &self,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+        unique_identifier: Option<String>,
+    ) -> Result<TradesSuccess, SharesightReqwestError> {
+        let parameters = TradesParameters {
+            portfolio_id: self.portfolio_id.to_string(),
+            start_date,
+            end_date,
+            unique_identifier,
+        };
+        self.client.execute::<Trades, _>(&parameters).await
+    }
+}
+
+pub struct TradeResource<'a, H: Transport = ReqwestTransport> {
+    client: &'a Client<H>,
+    trade_id: i64,
+}
+
+impl<'a, H: Transport> TradeResource<'a, H> {
+    /// Deletes a trade
+    pub async fn delete(&self) -> Result<TradesDestroySuccess, SharesightReqwestError> {
+        let parameters = TradesDestroyParameters { id: self.trade_id };
+        self.client.execute::<TradesDestroy, _>(&parameters).await
+    }
+
+    /// Returns a trade transaction.
+    pub async fn show(&self) -> Result<TradesShowSuccess, SharesightReqwestError> {
+        let parameters = TradesShowParameters {
+            id: self.trade_id.to_string(),
+        };
+        self.client.execute::<TradesShow, _>(&parameters).await
+    }
+
+    /// Update an existing trade for the user. The trade is identified by the 'id'. Any parameter supplied will replace that field on the trade. Other fields will remain unchanged.
+    pub async fn update(
+        &self,
+        trade: TradesUpdateTradeParameters,
+    ) -> Result<TradesUpdateSuccess, SharesightReqwestError> {
+        let parameters = TradesUpdateParameters {
+            id: self.trade_id,
+            trade,
+        };
+        self.client.execute::<TradesUpdate, _>(&parameters).await
+    }
+}
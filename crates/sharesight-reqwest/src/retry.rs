@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use sharesight_types::{ApiHttpMethod, HttpResponse};
+
+use crate::SharesightReqwestError;
+
+/// When the client sends a request again, whatever its transport. A rate limited response, or an
+/// error before the request reached the API, is retried for any method. A `502`, `503` or `504`
+/// response, or another error, is only retried for `GET`, `PUT` and `DELETE` requests, as the API
+/// may already have created the record of a `POST` request.
+///
+/// The default policy never retries.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new(0)
+    }
+}
+
+impl RetryPolicy {
+    /// Send each request at most `max_retries` more times, waiting half a second before the first
+    /// retry and doubling the wait for each one after it.
+    pub fn new(max_retries: u32) -> Self {
+        RetryPolicy {
+            max_retries,
+            backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+
+    /// The wait before the first retry, which is doubled for each retry after it.
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// The longest wait before a retry, including one asked for by a `Retry-After` header.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// The wait before sending the request again after the attempt, or `None` if it isn't
+    /// retried, given the number of times it has already been retried.
+    pub(crate) fn delay(
+        &self,
+        retries: u32,
+        method: ApiHttpMethod,
+        attempt: Attempt<'_>,
+    ) -> Option<Duration> {
+        let idempotent = !matches!(method, ApiHttpMethod::Post);
+        let retry = match attempt {
+            Attempt::Response(429, _) | Attempt::NotSent => true,
+            Attempt::Response(502..=504, _) | Attempt::Failed => idempotent,
+            Attempt::Response(..) | Attempt::Final => false,
+        };
+        if !retry || retries >= self.max_retries {
+            return None;
+        }
+
+        let retry_after = match attempt {
+            Attempt::Response(_, Some(retry_after)) => retry_after.trim().parse().ok(),
+            _ => None,
+        };
+        let delay = match retry_after {
+            Some(seconds) => Duration::from_secs(seconds),
+            None => self
+                .backoff
+                .checked_mul(2u32.saturating_pow(retries))
+                .unwrap_or(self.max_backoff),
+        };
+
+        Some(delay.min(self.max_backoff))
+    }
+}
+
+/// The outcome of sending a request, as far as retrying it is concerned.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Attempt<'a> {
+    /// A response with the status and `Retry-After` header.
+    Response(u16, Option<&'a str>),
+    /// An error before the request reached the API, such as failing to connect.
+    NotSent,
+    /// Another error sending the request, after which it may have reached the API.
+    Failed,
+    /// An error that sending the request again won't change, such as a missing cassette entry.
+    Final,
+}
+
+impl<'a> Attempt<'a> {
+    pub(crate) fn of(result: &'a Result<HttpResponse, SharesightReqwestError>) -> Self {
+        match result {
+            Ok(resp) => Attempt::Response(resp.status, resp.header("retry-after")),
+            Err(SharesightReqwestError::Reqwest(e)) => Attempt::of_reqwest(e),
+            Err(SharesightReqwestError::Transport(_)) => Attempt::Failed,
+            Err(_) => Attempt::Final,
+        }
+    }
+
+    pub(crate) fn of_streaming(result: &'a Result<reqwest::Response, reqwest::Error>) -> Self {
+        match result {
+            Ok(resp) => Attempt::Response(
+                resp.status().as_u16(),
+                resp.headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            ),
+            Err(e) => Attempt::of_reqwest(e),
+        }
+    }
+
+    fn of_reqwest(e: &reqwest::Error) -> Self {
+        if e.is_connect() {
+            Attempt::NotSent
+        } else if e.is_builder() {
+            Attempt::Final
+        } else {
+            Attempt::Failed
+        }
+    }
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use std::time::Duration;
+
+    use sharesight_types::ApiHttpMethod;
+
+    use super::{Attempt, RetryPolicy};
+
+    #[test]
+    fn delays() {
+        let policy = RetryPolicy::new(3)
+            .backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(10));
+        let delay =
+            |retries, method, attempt| policy.delay(retries, method, attempt).map(|d| d.as_secs());
+
+        assert_eq!(Some(1), delay(0, ApiHttpMethod::Get, Attempt::Failed));
+        assert_eq!(Some(4), delay(2, ApiHttpMethod::Get, Attempt::NotSent));
+        assert_eq!(None, delay(3, ApiHttpMethod::Get, Attempt::NotSent));
+        assert_eq!(
+            Some(7),
+            delay(0, ApiHttpMethod::Post, Attempt::Response(429, Some("7")))
+        );
+        assert_eq!(
+            Some(10),
+            delay(0, ApiHttpMethod::Get, Attempt::Response(429, Some("60")))
+        );
+        assert_eq!(
+            Some(1),
+            delay(0, ApiHttpMethod::Put, Attempt::Response(503, None))
+        );
+        assert_eq!(
+            None,
+            delay(0, ApiHttpMethod::Post, Attempt::Response(503, None))
+        );
+        assert_eq!(None, delay(0, ApiHttpMethod::Post, Attempt::Failed));
+        assert_eq!(Some(1), delay(0, ApiHttpMethod::Post, Attempt::NotSent));
+        assert_eq!(
+            None,
+            delay(0, ApiHttpMethod::Get, Attempt::Response(404, None))
+        );
+        assert_eq!(None, delay(0, ApiHttpMethod::Get, Attempt::Final));
+        assert_eq!(
+            None,
+            RetryPolicy::default().delay(0, ApiHttpMethod::Get, Attempt::Failed)
+        );
+    }
+}
@@ -0,0 +1,554 @@
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use serde::{Deserialize, Serialize};
+use sharesight_types::AuthWithDetails;
+
+use crate::{AuthError, OAuthClient};
+
+/// The PBKDF2 rounds used to derive the key of a new encrypted token file.
+const PBKDF2_ROUNDS: u32 = 600_000;
+
+/// The fewest PBKDF2 rounds an encrypted token file is read with, so a file can't be swapped for
+/// one whose passphrase is cheap to guess.
+const MIN_PBKDF2_ROUNDS: u32 = 100_000;
+
+pub type TokenStoreFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, TokenStoreError>> + Send + 'a>>;
+
+/// Where the credentials and tokens of a user are kept between runs.
+///
+/// Refreshing a token invalidates the old refresh token, so processes sharing a store hold its
+/// lock while they refresh; see [`fresh_auth`].
+pub trait TokenStore: Send + Sync {
+    fn load(&self) -> TokenStoreFuture<'_, Option<AuthWithDetails>>;
+
+    fn save<'a>(&'a self, auth: &'a AuthWithDetails) -> TokenStoreFuture<'a, ()>;
+
+    /// Wait for exclusive use of the store, which lasts until the lock is dropped.
+    fn lock(&self) -> TokenStoreFuture<'_, TokenStoreLock>;
+}
+
+/// Exclusive use of a token store, released when dropped.
+pub struct TokenStoreLock(#[allow(dead_code)] Box<dyn Send + Sync>);
+
+#[derive(Debug, thiserror::Error)]
+pub enum TokenStoreError {
+    #[error("IO error occurred\n{0:?}")]
+    Io(#[from] std::io::Error),
+    #[error("Token store is not valid JSON\n{0:?}")]
+    Json(#[from] serde_json::Error),
+    #[error("Token store could not be decrypted, the passphrase may be wrong")]
+    Decrypt,
+    #[error("Token store uses {0} PBKDF2 rounds, fewer than the minimum of {1}")]
+    TooFewRounds(u32, u32),
+    #[error("Token store has no credentials")]
+    Empty,
+    #[error("Credentials have no refresh token")]
+    NoRefreshToken,
+    #[error("Auth error occurred\n{0}")]
+    Auth(#[from] AuthError),
+}
+
+/// The credentials in the store, refreshed and saved first when they expire within
+/// `min_validity`. The store is locked meanwhile, so when processes share it only one refreshes,
+/// and the others load the tokens it saved.
+pub async fn fresh_auth<S: TokenStore + ?Sized>(
+    store: &S,
+    min_validity: Duration,
+) -> Result<AuthWithDetails, TokenStoreError> {
+    fresh_auth_with(store, min_validity, |auth| {
+        OAuthClient::new(&auth.host, &auth.client_id, &auth.client_secret)
+    })
+    .await
+}
+
+/// The credentials in the store, refreshed with the OAuth client for them when they expire, such
+/// as one sending its requests to a stub.
+pub(crate) async fn fresh_auth_with<S, F>(
+    store: &S,
+    min_validity: Duration,
+    oauth_client: F,
+) -> Result<AuthWithDetails, TokenStoreError>
+where
+    S: TokenStore + ?Sized,
+    F: FnOnce(&AuthWithDetails) -> OAuthClient,
+{
+    let _lock = store.lock().await?;
+    let auth = store.load().await?.ok_or(TokenStoreError::Empty)?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    let expires_at = auth.auth.created_at + i64::from(auth.auth.expires_in);
+    if now + min_validity.as_secs() as i64 <= expires_at {
+        return Ok(auth);
+    }
+
+    let refresh_token = auth
+        .auth
+        .refresh_token
+        .as_deref()
+        .ok_or(TokenStoreError::NoRefreshToken)?;
+    let refreshed = oauth_client(&auth).refresh(refresh_token).await?;
+    let auth = AuthWithDetails {
+        auth: refreshed,
+        ..auth
+    };
+    store.save(&auth).await?;
+
+    Ok(auth)
+}
+
+/// Keeps credentials in memory, for tests or processes that authorize on start up.
+#[derive(Default)]
+pub struct MemoryTokenStore {
+    auth: Mutex<Option<Vec<u8>>>,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new(auth: &AuthWithDetails) -> Result<Self, TokenStoreError> {
+        let store = MemoryTokenStore::default();
+        *store.auth.lock().unwrap() = Some(serde_json::to_vec(auth)?);
+
+        Ok(store)
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> TokenStoreFuture<'_, Option<AuthWithDetails>> {
+        let auth = self
+            .auth
+            .lock()
+            .unwrap()
+            .as_deref()
+            .map(serde_json::from_slice)
+            .transpose()
+            .map_err(Into::into);
+
+        Box::pin(async move { auth })
+    }
+
+    fn save<'a>(&'a self, auth: &'a AuthWithDetails) -> TokenStoreFuture<'a, ()> {
+        let saved = serde_json::to_vec(auth)
+            .map(|auth| *self.auth.lock().unwrap() = Some(auth))
+            .map_err(Into::into);
+
+        Box::pin(async move { saved })
+    }
+
+    fn lock(&self) -> TokenStoreFuture<'_, TokenStoreLock> {
+        Box::pin(async move {
+            let guard = self.lock.clone().lock_owned().await;
+
+            Ok(TokenStoreLock(Box::new(guard)))
+        })
+    }
+}
+
+/// Keeps credentials in a JSON file, readable only by its owner on Unix. The lock is an OS file
+/// lock on the file with `.lock` appended to its name, so it works across processes.
+pub struct FileTokenStore {
+    path: PathBuf,
+}
+
+impl FileTokenStore {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        FileTokenStore { path: path.into() }
+    }
+
+    /// Delete the file, such as once its tokens have been revoked. The lock file is kept, as
+    /// another process may be waiting on it.
+    pub async fn remove(&self) -> Result<(), TokenStoreError> {
+        match tokio::fs::remove_file(&self.path).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self) -> TokenStoreFuture<'_, Option<AuthWithDetails>> {
+        Box::pin(async move {
+            match read(&self.path).await? {
+                Some(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+                None => Ok(None),
+            }
+        })
+    }
+
+    fn save<'a>(&'a self, auth: &'a AuthWithDetails) -> TokenStoreFuture<'a, ()> {
+        Box::pin(async move { write(&self.path, &serde_json::to_vec_pretty(auth)?).await })
+    }
+
+    fn lock(&self) -> TokenStoreFuture<'_, TokenStoreLock> {
+        Box::pin(lock_file(&self.path))
+    }
+}
+
+/// Keeps credentials in a file encrypted with ChaCha20-Poly1305, using a key derived from a
+/// passphrase with PBKDF2-HMAC-SHA256. It is locked like a [`FileTokenStore`].
+pub struct EncryptedFileTokenStore {
+    path: PathBuf,
+    passphrase: String,
+    rounds: u32,
+    min_rounds: u32,
+}
+
+#[derive(Deserialize, Serialize)]
+struct EncryptedFile {
+    rounds: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl EncryptedFileTokenStore {
+    pub fn new<P: Into<PathBuf>>(path: P, passphrase: &str) -> Self {
+        EncryptedFileTokenStore {
+            path: path.into(),
+            passphrase: passphrase.to_string(),
+            rounds: PBKDF2_ROUNDS,
+            min_rounds: MIN_PBKDF2_ROUNDS,
+        }
+    }
+
+    fn cipher(&self, salt: &[u8], rounds: u32) -> ChaCha20Poly1305 {
+        let mut key = Key::default();
+        pbkdf2::pbkdf2_hmac::<sha2::Sha256>(self.passphrase.as_bytes(), salt, rounds, &mut key);
+
+        ChaCha20Poly1305::new(&key)
+    }
+
+    fn encrypt(&self, plaintext: &[u8]) -> Result<EncryptedFile, TokenStoreError> {
+        let mut salt = [0; 16];
+        let mut nonce = Nonce::default();
+        getrandom::getrandom(&mut salt).map_err(AuthError::Random)?;
+        getrandom::getrandom(&mut nonce).map_err(AuthError::Random)?;
+
+        let ciphertext = self
+            .cipher(&salt, self.rounds)
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| TokenStoreError::Decrypt)?;
+
+        Ok(EncryptedFile {
+            rounds: self.rounds,
+            salt: STANDARD.encode(salt),
+            nonce: STANDARD.encode(nonce),
+            ciphertext: STANDARD.encode(ciphertext),
+        })
+    }
+
+    fn decrypt(&self, file: &EncryptedFile) -> Result<Vec<u8>, TokenStoreError> {
+        let decode = |s: &str| STANDARD.decode(s).map_err(|_| TokenStoreError::Decrypt);
+        if file.rounds < self.min_rounds {
+            return Err(TokenStoreError::TooFewRounds(file.rounds, self.min_rounds));
+        }
+
+        let salt = decode(&file.salt)?;
+        let nonce = decode(&file.nonce)?;
+        if nonce.len() != Nonce::default().len() {
+            return Err(TokenStoreError::Decrypt);
+        }
+
+        self.cipher(&salt, file.rounds)
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                decode(&file.ciphertext)?.as_slice(),
+            )
+            .map_err(|_| TokenStoreError::Decrypt)
+    }
+}
+
+impl TokenStore for EncryptedFileTokenStore {
+    fn load(&self) -> TokenStoreFuture<'_, Option<AuthWithDetails>> {
+        Box::pin(async move {
+            match read(&self.path).await? {
+                Some(bytes) => {
+                    let file = serde_json::from_slice::<EncryptedFile>(&bytes)?;
+
+                    Ok(Some(serde_json::from_slice(&self.decrypt(&file)?)?))
+                }
+                None => Ok(None),
+            }
+        })
+    }
+
+    fn save<'a>(&'a self, auth: &'a AuthWithDetails) -> TokenStoreFuture<'a, ()> {
+        Box::pin(async move {
+            let file = self.encrypt(&serde_json::to_vec(auth)?)?;
+
+            write(&self.path, &serde_json::to_vec_pretty(&file)?).await
+        })
+    }
+
+    fn lock(&self) -> TokenStoreFuture<'_, TokenStoreLock> {
+        Box::pin(lock_file(&self.path))
+    }
+}
+
+async fn read(path: &Path) -> Result<Option<Vec<u8>>, TokenStoreError> {
+    match tokio::fs::read(path).await {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// The path with the suffix appended to its file name, so `tokens.json` and `tokens.dat` don't
+/// share a `tokens.lock`.
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+
+    path.into()
+}
+
+/// Replace the file, so a reader never sees it half written.
+async fn write(path: &Path, contents: &[u8]) -> Result<(), TokenStoreError> {
+    let tmp = with_suffix(path, ".tmp");
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let mut file = options.open(&tmp).await?;
+        file.write_all(contents).await?;
+        file.sync_all().await?;
+    }
+    tokio::fs::rename(&tmp, path).await?;
+
+    Ok(())
+}
+
+async fn lock_file(path: &Path) -> Result<TokenStoreLock, TokenStoreError> {
+    let path = with_suffix(path, ".lock");
+    let file = tokio::task::spawn_blocking(move || {
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        fs2::FileExt::lock_exclusive(&file)?;
+
+        Ok::<_, std::io::Error>(file)
+    })
+    .await
+    .map_err(std::io::Error::other)??;
+
+    Ok(TokenStoreLock(Box::new(file)))
+}
+
+#[cfg(test)]
+mod token_store_tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use sharesight_types::{Auth, AuthWithDetails};
+
+    use super::{
+        fresh_auth, fresh_auth_with, with_suffix, EncryptedFileTokenStore, FileTokenStore,
+        MemoryTokenStore, TokenStore, TokenStoreError,
+    };
+    use crate::{fake_server::FakeServer, OAuthClient};
+
+    fn auth() -> AuthWithDetails {
+        AuthWithDetails {
+            auth: Auth {
+                access_token: "access".to_string(),
+                expires_in: 1800,
+                refresh_token: Some("refresh".to_string()),
+                created_at: 1_700_000_000,
+            },
+            host: "api.example.com".to_string(),
+            client_id: "id".to_string(),
+            client_secret: "secret".to_string(),
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("sharesight-{}-{}", name, std::process::id()))
+    }
+
+    #[tokio::test]
+    async fn memory() {
+        let store = MemoryTokenStore::default();
+        assert!(store.load().await.unwrap().is_none());
+
+        store.save(&auth()).await.unwrap();
+        assert_eq!(
+            "access",
+            store.load().await.unwrap().unwrap().auth.access_token
+        );
+
+        let _lock = store.lock().await.unwrap();
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), store.lock())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn file() {
+        let path = temp_path("tokens.json");
+        let store = FileTokenStore::new(&path);
+        assert!(store.load().await.unwrap().is_none());
+
+        let lock = store.lock().await.unwrap();
+        store.save(&auth()).await.unwrap();
+        assert_eq!(
+            Some("refresh".to_string()),
+            store.load().await.unwrap().unwrap().auth.refresh_token
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(0o600, mode & 0o777);
+        }
+
+        // The lock is held across processes, so a second handle to the file waits for it too.
+        let other = FileTokenStore::new(&path);
+        let waiting = tokio::spawn(async move { other.lock().await.map(|_| ()) });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiting.is_finished());
+        drop(lock);
+        waiting.await.unwrap().unwrap();
+
+        assert!(!with_suffix(&path, ".tmp").exists());
+        store.remove().await.unwrap();
+        assert!(store.load().await.unwrap().is_none());
+        store.remove().await.unwrap();
+        std::fs::remove_file(with_suffix(&path, ".lock")).unwrap();
+    }
+
+    #[test]
+    fn suffixes() {
+        assert_eq!(
+            PathBuf::from("/tmp/tokens.json.lock"),
+            with_suffix("/tmp/tokens.json".as_ref(), ".lock")
+        );
+        assert_ne!(
+            with_suffix("tokens.json".as_ref(), ".lock"),
+            with_suffix("tokens.dat".as_ref(), ".lock")
+        );
+    }
+
+    #[tokio::test]
+    async fn encrypted_file() {
+        let path = temp_path("tokens");
+        let mut store = EncryptedFileTokenStore::new(&path, "passphrase");
+        store.rounds = 1;
+        store.min_rounds = 1;
+
+        let _lock = store.lock().await.unwrap();
+        store.save(&auth()).await.unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("refresh"));
+        assert_eq!(
+            "refresh",
+            store
+                .load()
+                .await
+                .unwrap()
+                .unwrap()
+                .auth
+                .refresh_token
+                .unwrap()
+        );
+
+        let mut wrong = EncryptedFileTokenStore::new(&path, "wrong");
+        wrong.min_rounds = 1;
+        assert!(matches!(wrong.load().await, Err(TokenStoreError::Decrypt)));
+
+        // A file asking for fewer rounds than the minimum, or none, isn't decrypted.
+        let default = EncryptedFileTokenStore::new(&path, "passphrase");
+        assert!(matches!(
+            default.load().await,
+            Err(TokenStoreError::TooFewRounds(1, _))
+        ));
+        std::fs::write(&path, contents.replace("\"rounds\": 1", "\"rounds\": 0")).unwrap();
+        assert!(matches!(
+            store.load().await,
+            Err(TokenStoreError::TooFewRounds(0, 1))
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(with_suffix(&path, ".lock")).unwrap();
+    }
+
+    #[tokio::test]
+    async fn fresh_auth_keeps_valid_tokens() {
+        let mut valid = auth();
+        valid.auth.created_at = 4_000_000_000;
+        let store = MemoryTokenStore::new(&valid).unwrap();
+
+        // The host is never contacted, as the tokens are still valid.
+        let fresh = fresh_auth(&store, Duration::from_secs(60)).await.unwrap();
+        assert_eq!("access", fresh.auth.access_token);
+        assert_eq!(4_000_000_000, fresh.auth.created_at);
+
+        let mut no_refresh = auth();
+        no_refresh.auth.refresh_token = None;
+        let store = MemoryTokenStore::new(&no_refresh).unwrap();
+        assert!(matches!(
+            fresh_auth(&store, Duration::from_secs(60)).await,
+            Err(TokenStoreError::NoRefreshToken)
+        ));
+
+        assert!(matches!(
+            fresh_auth(&MemoryTokenStore::default(), Duration::from_secs(60)).await,
+            Err(TokenStoreError::Empty)
+        ));
+    }
+
+    #[tokio::test]
+    async fn fresh_auth_refreshes_once() {
+        let server = FakeServer::start(vec![(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n",
+            vec![
+                r#"{"access_token":"new","expires_in":1800,"refresh_token":"new-refresh","created_at":4000000000}"#,
+            ],
+        )])
+        .await;
+        let store = MemoryTokenStore::new(&auth()).unwrap();
+        let oauth_client = |auth: &AuthWithDetails| {
+            OAuthClient::new(&auth.host, &auth.client_id, &auth.client_secret)
+                .with_base_url(server.url.clone())
+        };
+
+        // The server only answers once, so the second call must wait for the first to save the
+        // new tokens rather than refreshing the old refresh token too.
+        let (first, second) = tokio::join!(
+            fresh_auth_with(&store, Duration::from_secs(60), oauth_client),
+            fresh_auth_with(&store, Duration::from_secs(60), oauth_client),
+        );
+        assert_eq!("new", first.unwrap().auth.access_token);
+        assert_eq!("new", second.unwrap().auth.access_token);
+        assert_eq!(
+            Some("new-refresh".to_string()),
+            store.load().await.unwrap().unwrap().auth.refresh_token
+        );
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("POST /oauth2/token "));
+        assert!(requests[0].contains("grant_type=refresh_token&refresh_token=refresh&"));
+    }
+}
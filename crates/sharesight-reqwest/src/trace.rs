@@ -0,0 +1,294 @@
+use std::future::Future;
+
+use sharesight_types::{ApiEndpoint, ApiHttpMethod, HttpResponse};
+
+use crate::SharesightReqwestError;
+
+/// A `tracing` span around a request, recording the endpoint, its templated URL path, the number
+/// of times it was retried, and the status, size and latency of the response. The URL with ids filled in and the headers, which
+/// hold the access token, are never recorded, so a failed request only records the kind of error.
+#[cfg(feature = "tracing")]
+pub(crate) struct RequestSpan {
+    span: tracing::Span,
+    start: std::time::Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl RequestSpan {
+    pub(crate) fn new<'a, T: ApiEndpoint<'a>>() -> Self {
+        RequestSpan::named(crate::endpoint_name::<T>(), T::HTTP_METHOD, T::URL_PATH)
+    }
+
+    /// A span for an endpoint chosen at run time.
+    pub(crate) fn named(endpoint: &str, method: ApiHttpMethod, url_path: &str) -> Self {
+        let span = tracing::info_span!(
+            "sharesight_request",
+            endpoint,
+            method = ?method,
+            url_path,
+            status = tracing::field::Empty,
+            response_size = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            retries = tracing::field::Empty,
+            error = tracing::field::Empty,
+        );
+
+        RequestSpan {
+            span,
+            start: std::time::Instant::now(),
+        }
+    }
+
+    pub(crate) async fn instrument<F: Future>(&self, future: F) -> F::Output {
+        use tracing::Instrument;
+
+        future.instrument(self.span.clone()).await
+    }
+
+    /// Finish the span with the last response, after the request has been retried `retries`
+    /// times. The latency includes the retries.
+    pub(crate) fn finish(
+        &self,
+        result: &Result<HttpResponse, SharesightReqwestError>,
+        retries: u32,
+    ) {
+        self.span
+            .record("latency_ms", self.start.elapsed().as_millis() as u64);
+        self.span.record("retries", retries);
+
+        match result {
+            Ok(resp) => {
+                self.span.record("status", resp.status);
+                self.span.record("response_size", resp.body.len() as u64);
+            }
+            Err(e) => {
+                self.span.record("error", error_kind(e));
+            }
+        }
+    }
+
+    /// Finish the span once the headers of a streamed response have arrived, recording the size
+    /// from its `Content-Length` header.
+    pub(crate) fn finish_streaming(
+        &self,
+        result: &Result<reqwest::Response, reqwest::Error>,
+        retries: u32,
+    ) {
+        self.span
+            .record("latency_ms", self.start.elapsed().as_millis() as u64);
+        self.span.record("retries", retries);
+
+        match result {
+            Ok(resp) => {
+                self.span.record("status", resp.status().as_u16());
+                if let Some(size) = resp.content_length() {
+                    self.span.record("response_size", size);
+                }
+            }
+            Err(e) => {
+                self.span.record("error", reqwest_error_kind(e));
+            }
+        }
+    }
+}
+
+/// The kind of error a request failed with, as the message of a `reqwest` error includes the URL.
+#[cfg(feature = "tracing")]
+fn error_kind(e: &SharesightReqwestError) -> &'static str {
+    match e {
+        SharesightReqwestError::Reqwest(e) => reqwest_error_kind(e),
+        SharesightReqwestError::Cassette(_) => "cassette",
+        _ => "transport",
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn reqwest_error_kind(e: &reqwest::Error) -> &'static str {
+    if e.is_timeout() {
+        "timeout"
+    } else if e.is_connect() {
+        "connect"
+    } else if e.is_status() {
+        "status"
+    } else if e.is_redirect() {
+        "redirect"
+    } else if e.is_body() {
+        "body"
+    } else if e.is_decode() {
+        "decode"
+    } else if e.is_builder() {
+        "builder"
+    } else {
+        "request"
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct RequestSpan;
+
+#[cfg(not(feature = "tracing"))]
+impl RequestSpan {
+    #[allow(clippy::extra_unused_type_parameters)]
+    pub(crate) fn new<'a, T: ApiEndpoint<'a>>() -> Self {
+        RequestSpan
+    }
+
+    pub(crate) fn named(_endpoint: &str, _method: ApiHttpMethod, _url_path: &str) -> Self {
+        RequestSpan
+    }
+
+    pub(crate) async fn instrument<F: Future>(&self, future: F) -> F::Output {
+        future.await
+    }
+
+    pub(crate) fn finish(
+        &self,
+        _result: &Result<HttpResponse, SharesightReqwestError>,
+        _retries: u32,
+    ) {
+    }
+
+    pub(crate) fn finish_streaming(
+        &self,
+        _result: &Result<reqwest::Response, reqwest::Error>,
+        _retries: u32,
+    ) {
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod trace_tests {
+    use std::{
+        fmt,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use sharesight_types::{DocumentShow, DocumentShowParameters};
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use crate::{fake_server::FakeServer, Client, RetryPolicy};
+
+    /// Keeps the fields recorded on the `sharesight_request` spans.
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<(String, String)>>>);
+
+    struct Fields<'a>(&'a mut Vec<(String, String)>);
+
+    impl<'a> Visit for Fields<'a> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .push((field.name().to_string(), format!("{:?}", value)));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.push((field.name().to_string(), value.to_string()));
+        }
+    }
+
+    impl Capture {
+        fn take(&self) -> Vec<(String, String)> {
+            std::mem::take(&mut *self.0.lock().unwrap())
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            if span.metadata().name() == "sharesight_request" {
+                span.record(&mut Fields(&mut self.0.lock().unwrap()));
+                span::Id::from_u64(1)
+            } else {
+                span::Id::from_u64(2)
+            }
+        }
+
+        fn record(&self, span: &span::Id, values: &span::Record<'_>) {
+            if span.into_u64() == 1 {
+                values.record(&mut Fields(&mut self.0.lock().unwrap()));
+            }
+        }
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    fn field<'a>(fields: &'a [(String, String)], name: &str) -> Option<&'a str> {
+        fields
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[tokio::test]
+    async fn span_fields() {
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+
+        let server = FakeServer::start(vec![("HTTP/1.1 200 OK\r\n", vec!["%PDF-1.4"])]).await;
+        let client = Client::builder("token".to_string())
+            .base_url(format!("{}/api/v2", server.url))
+            .build()
+            .unwrap();
+        client
+            .download::<DocumentShow>(&DocumentShowParameters { id: 123 })
+            .await
+            .unwrap();
+
+        let fields = capture.take();
+        assert_eq!(Some("DocumentShow"), field(&fields, "endpoint"));
+        assert_eq!(Some("/documents/:id.json"), field(&fields, "url_path"));
+        assert_eq!(Some("200"), field(&fields, "status"));
+        assert_eq!(Some("8"), field(&fields, "response_size"));
+        assert_eq!(Some("0"), field(&fields, "retries"));
+        assert_eq!(None, field(&fields, "error"));
+
+        // Nothing listens on the port once the server has answered its one response.
+        assert!(client
+            .download::<DocumentShow>(&DocumentShowParameters { id: 123 })
+            .await
+            .is_err());
+
+        let fields = capture.take();
+        assert_eq!(Some("connect"), field(&fields, "error"));
+        assert!(fields
+            .iter()
+            .all(|(_, value)| !value.contains("documents/123")));
+    }
+
+    #[tokio::test]
+    async fn retries() {
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+
+        let server = FakeServer::start(vec![
+            ("HTTP/1.1 503 Service Unavailable\r\n", vec!["busy"]),
+            ("HTTP/1.1 200 OK\r\n", vec!["%PDF-1.4"]),
+        ])
+        .await;
+        let client = Client::builder("token".to_string())
+            .base_url(format!("{}/api/v2", server.url))
+            .retry(RetryPolicy::new(1).backoff(Duration::ZERO))
+            .build()
+            .unwrap();
+        client
+            .download::<DocumentShow>(&DocumentShowParameters { id: 123 })
+            .await
+            .unwrap();
+
+        let fields = capture.take();
+        assert_eq!(Some("1"), field(&fields, "retries"));
+        assert_eq!(Some("200"), field(&fields, "status"));
+    }
+}
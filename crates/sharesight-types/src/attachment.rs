@@ -0,0 +1,51 @@
+use std::{fs, io, path::Path};
+
+use base64::Engine;
+
+/// A file to attach to a trade, holding merge or payout. The API takes attachments as base64
+/// encoded content alongside the file name, rather than as a multipart upload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attachment {
+    pub filename: String,
+    pub content: Vec<u8>,
+}
+
+impl Attachment {
+    pub fn new<S: Into<String>, B: Into<Vec<u8>>>(filename: S, content: B) -> Self {
+        Attachment {
+            filename: filename.into(),
+            content: content.into(),
+        }
+    }
+
+    /// Read the file at the path, naming the attachment after the file.
+    pub fn read<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no file name"))?;
+
+        Ok(Attachment {
+            filename,
+            content: fs::read(path)?,
+        })
+    }
+
+    /// The content encoded as the API expects it in `attachment` or `file_attachment`.
+    pub fn encoded(&self) -> String {
+        base64::engine::general_purpose::STANDARD.encode(&self.content)
+    }
+}
+
+#[cfg(test)]
+mod attachment_tests {
+    use super::Attachment;
+
+    #[test]
+    fn encoded() {
+        let attachment = Attachment::new("note.txt", "contract note");
+
+        assert_eq!("Y29udHJhY3Qgbm90ZQ==", attachment.encoded());
+    }
+}
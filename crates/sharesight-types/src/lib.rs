@@ -1,15 +1,38 @@
+mod attachment;
 mod auth_types;
 mod codes;
+mod manifest;
+mod new_trade;
+mod resolve;
+mod transport;
 mod types;
 mod types_prelude;
 
+pub use attachment::*;
 pub use auth_types::*;
 pub use codes::*;
+pub use manifest::*;
+pub use new_trade::*;
+pub use resolve::*;
+pub use transport::*;
 pub use types::*;
-pub use types_prelude::{ApiEndpoint, ApiHttpMethod};
+pub use types_prelude::{
+    ApiEndpoint, ApiFileEndpoint, ApiHttpMethod, IntoParametersError, Number, Validate,
+    ValidationError,
+};
 
 pub const DEFAULT_API_HOST: &str = "api.sharesight.com";
 
+/// Whether the success structs keep the fields they don't know about in `extra`, as they do with
+/// the `extra-fields` feature. A dependent crate can't see the features of this one, so it checks
+/// this instead.
+pub const EXTRA_FIELDS: bool = cfg!(feature = "extra-fields");
+
+/// The base URL of the API served by the host.
+pub fn api_base_url(api_host: &str) -> String {
+    format!("https://{}/api/v2", api_host)
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -17,3 +40,151 @@ mod tests {
         assert_eq!(2 + 2, 4);
     }
 }
+
+#[cfg(test)]
+mod conversion_tests {
+    use std::convert::TryFrom;
+
+    use serde_json::{json, Value};
+
+    use crate::{Payout, PayoutUpdateParameters, Trade, TradesUpdateParameters};
+
+    fn trade(transaction_type: &str, value: f64) -> Trade {
+        serde_json::from_value::<Trade>(json!({
+            "id": "1",
+            "unique_identifier": "",
+            "transaction_date": "2024-06-30",
+            "quantity": 10,
+            "price": 2.5,
+            "cost_base": 25,
+            "exchange_rate": 1,
+            "brokerage": 9.95,
+            "brokerage_currency_code": "AUD",
+            "value": value,
+            "paid_on": "2024-06-30",
+            "comments": "",
+            "portfolio_id": 2,
+            "holding_id": 3,
+            "state": "confirmed",
+            "transaction_type": transaction_type,
+            "instrument_id": 4,
+            "symbol": "CBA",
+            "market": "ASX",
+            "confirmed": true,
+        }))
+        .unwrap()
+    }
+
+    fn trade_parameters(trade: Trade) -> Value {
+        serde_json::to_value(TradesUpdateParameters::try_from(trade).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn trade_into_update() {
+        let parameters = trade_parameters(trade("BUY", 25.0));
+
+        assert_eq!(json!(1), parameters["id"]);
+        assert_eq!(json!("CBA"), parameters["trade"]["symbol"]);
+        for key in &[
+            "adjust_cost_base_value",
+            "capital_return_value",
+            "market_country_code",
+            "attachment",
+        ] {
+            assert_eq!(Some(&Value::Null), parameters["trade"].get(key), "{}", key);
+        }
+    }
+
+    #[test]
+    fn trade_values_into_update() {
+        let parameters = trade_parameters(trade("CAPITAL_RETURN", 12.5));
+        assert_eq!(json!(12.5), parameters["trade"]["capital_return_value"]);
+        assert_eq!(Value::Null, parameters["trade"]["adjust_cost_base_value"]);
+
+        let parameters = trade_parameters(trade("CAPITAL_CALL", 7.5));
+        assert_eq!(json!(7.5), parameters["trade"]["capital_return_value"]);
+
+        let parameters = trade_parameters(trade("ADJUST_COST_BASE", -3.0));
+        assert_eq!(json!(-3.0), parameters["trade"]["adjust_cost_base_value"]);
+        assert_eq!(Value::Null, parameters["trade"]["capital_return_value"]);
+    }
+
+    #[test]
+    fn payout_into_update() {
+        let payout = serde_json::from_value::<Payout>(json!({
+            "id": 1,
+            "portfolio_id": 2,
+            "holding_id": 3,
+            "instrument_id": 4,
+            "symbol": "CBA",
+            "market": "ASX",
+            "paid_on": "2024-06-30",
+            "amount": 100,
+            "gross_amount": 100,
+            "currency": "AUD",
+            "exchange_rate": 1,
+            "non_taxable": false,
+            "comments": "",
+            "state": "confirmed",
+        }))
+        .unwrap();
+        let parameters = serde_json::to_value(PayoutUpdateParameters::from(payout)).unwrap();
+
+        assert_ne!(Value::Null, parameters["payout"]["amount"]);
+        for key in &[
+            "banked_amount",
+            "source_adjustment_id",
+            "send_to_xero",
+            "franked_amount",
+            "drp_trade_attributes",
+        ] {
+            assert_eq!(Some(&Value::Null), parameters["payout"].get(key), "{}", key);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "extra-fields"))]
+mod extra_fields_tests {
+    use serde_json::json;
+
+    use crate::TradesShowSuccess;
+
+    #[test]
+    fn unknown_fields_are_kept() {
+        let trade = serde_json::from_value::<TradesShowSuccess>(json!({
+            "id": "1",
+            "unique_identifier": "",
+            "transaction_date": "2024-06-30",
+            "quantity": 10,
+            "price": 2.5,
+            "cost_base": 25,
+            "exchange_rate": 1,
+            "brokerage": 9.95,
+            "brokerage_currency_code": "AUD",
+            "value": 25,
+            "paid_on": "2024-06-30",
+            "comments": "",
+            "portfolio_id": 2,
+            "holding_id": 3,
+            "state": "confirmed",
+            "transaction_type": "BUY",
+            "instrument_id": 4,
+            "symbol": "CBA",
+            "market": "ASX",
+            "confirmed": true,
+            "api_transaction": {"id": 5, "version": 2, "request_id": "abc"},
+            "settlement_date": "2024-07-02",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            Some(&json!("2024-07-02")),
+            trade.extra.get("settlement_date")
+        );
+        assert_eq!(1, trade.extra.len());
+        assert_eq!(
+            Some(&json!("abc")),
+            trade.api_transaction.extra.get("request_id")
+        );
+    }
+}
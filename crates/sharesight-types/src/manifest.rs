@@ -0,0 +1,87 @@
+use crate::{ApiHttpMethod, API_ENDPOINTS};
+
+/// An endpoint as described by the API documentation, for calling endpoints chosen at run time.
+#[derive(Debug)]
+pub struct EndpointManifest {
+    /// The name of the endpoint type, such as `HoldingMergesUpdate`.
+    pub name: &'static str,
+    pub http_method: ApiHttpMethod,
+    /// The path of the URL, with parameters written as `:portfolio_id`.
+    pub url_path: &'static str,
+    pub parameters: &'static [ParameterManifest],
+    /// Whether the endpoint returns a file rather than JSON.
+    pub file: bool,
+}
+
+#[derive(Debug)]
+pub struct ParameterManifest {
+    /// The path of the field, such as `trade.quantity`.
+    pub field: &'static str,
+    pub kind: ParameterKind,
+    pub array: bool,
+    pub optional: bool,
+}
+
+/// The kind of value a parameter field takes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterKind {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    /// A date such as `2024-06-30`.
+    Date,
+    /// An object, either set as a whole or through the fields inside it.
+    Object,
+}
+
+impl EndpointManifest {
+    /// The endpoint with the name, ignoring case.
+    pub fn find(name: &str) -> Option<&'static EndpointManifest> {
+        API_ENDPOINTS
+            .iter()
+            .find(|endpoint| endpoint.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn parameter(&self, field: &str) -> Option<&'static ParameterManifest> {
+        self.parameters.iter().find(|p| p.field == field)
+    }
+
+    /// The names of the parameters in the URL path.
+    pub fn url_parameters(&self) -> impl Iterator<Item = &'static str> {
+        self.url_path
+            .split('/')
+            .filter_map(|s| s.strip_prefix(':'))
+            .filter_map(|s| s.split('.').next())
+    }
+}
+
+#[cfg(test)]
+mod manifest_tests {
+    use super::{EndpointManifest, ParameterKind};
+    use crate::ApiHttpMethod;
+
+    #[test]
+    fn find() {
+        let endpoint = EndpointManifest::find("holdingmergesupdate").unwrap();
+
+        assert_eq!("HoldingMergesUpdate", endpoint.name);
+        assert_eq!(ApiHttpMethod::Put, endpoint.http_method);
+        assert_eq!(
+            vec!["portfolio_id", "id"],
+            endpoint.url_parameters().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            ParameterKind::Date,
+            endpoint.parameter("merge_date").unwrap().kind
+        );
+        assert_eq!(
+            ParameterKind::Object,
+            EndpointManifest::find("TradesCreate")
+                .unwrap()
+                .parameter("trade")
+                .unwrap()
+                .kind
+        );
+    }
+}
@@ -0,0 +1,375 @@
+use crate::attachment::Attachment;
+use crate::types::{TradesCreateParameters, TradesCreateTradeParameters};
+use crate::types_prelude::*;
+
+/// How the instrument of a new trade is identified.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TradeInstrument {
+    /// An existing holding.
+    Holding(i64),
+    /// An instrument by its id, added to a portfolio.
+    Instrument {
+        portfolio_id: i64,
+        instrument_id: i64,
+    },
+    /// An instrument by its symbol and market, added to a portfolio.
+    Symbol {
+        portfolio_id: i64,
+        symbol: String,
+        market: Market,
+    },
+}
+
+/// The fields required by each type of trade that can be created.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NewTradeDetails {
+    Buy {
+        quantity: Number,
+        price: Number,
+        brokerage: Option<Number>,
+        brokerage_currency_code: Option<Currency>,
+    },
+    Sell {
+        quantity: Number,
+        price: Number,
+        brokerage: Option<Number>,
+        brokerage_currency_code: Option<Currency>,
+    },
+    Split {
+        quantity: Number,
+    },
+    Bonus {
+        quantity: Number,
+    },
+    Consold {
+        quantity: Number,
+    },
+    Cancel {
+        quantity: Number,
+    },
+    /// A return of capital, usually linked to a payout paid on `paid_on`.
+    CapitalReturn {
+        capital_return_value: Number,
+        paid_on: Option<NaiveDate>,
+    },
+    CapitalCall {
+        capital_return_value: Number,
+        paid_on: Option<NaiveDate>,
+    },
+    OpeningBalance {
+        quantity: Number,
+        price: Number,
+        cost_base: Number,
+    },
+    AdjustCostBase {
+        adjust_cost_base_value: Number,
+    },
+}
+
+impl NewTradeDetails {
+    pub fn transaction_type(&self) -> TradeDescription {
+        match self {
+            NewTradeDetails::Buy { .. } => TradeDescription::Buy,
+            NewTradeDetails::Sell { .. } => TradeDescription::Sell,
+            NewTradeDetails::Split { .. } => TradeDescription::Split,
+            NewTradeDetails::Bonus { .. } => TradeDescription::Bonus,
+            NewTradeDetails::Consold { .. } => TradeDescription::Consold,
+            NewTradeDetails::Cancel { .. } => TradeDescription::Cancel,
+            NewTradeDetails::CapitalReturn { .. } => TradeDescription::CapitalReturn,
+            NewTradeDetails::CapitalCall { .. } => TradeDescription::CapitalCall,
+            NewTradeDetails::OpeningBalance { .. } => TradeDescription::OpeningBalance,
+            NewTradeDetails::AdjustCostBase { .. } => TradeDescription::AdjustCostBase,
+        }
+    }
+}
+
+/// A trade to create, checked locally before being converted into `TradesCreateParameters`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewTrade {
+    pub instrument: TradeInstrument,
+    pub transaction_date: NaiveDate,
+    pub details: NewTradeDetails,
+    /// The exchange rate as portfolio currency / instrument currency.
+    pub exchange_rate: Option<Number>,
+    /// Up to 255 characters used by Sharesight to detect duplicate trades.
+    pub unique_identifier: Option<String>,
+    pub comments: Option<String>,
+    /// A document such as a contract note to attach to the trade.
+    pub attachment: Option<Attachment>,
+}
+
+impl NewTrade {
+    pub fn new(
+        instrument: TradeInstrument,
+        transaction_date: NaiveDate,
+        details: NewTradeDetails,
+    ) -> Self {
+        NewTrade {
+            instrument,
+            transaction_date,
+            details,
+            exchange_rate: None,
+            unique_identifier: None,
+            comments: None,
+            attachment: None,
+        }
+    }
+}
+
+impl Validate for NewTrade {
+    fn validate(&self) -> Result<(), ValidationError> {
+        fn positive(field: &'static str, value: &Number) -> Result<(), ValidationError> {
+            if *value > Number::default() {
+                Ok(())
+            } else {
+                Err(ValidationError::NotPositive(field))
+            }
+        }
+
+        fn not_negative(field: &'static str, value: &Number) -> Result<(), ValidationError> {
+            if *value >= Number::default() {
+                Ok(())
+            } else {
+                Err(ValidationError::Negative(field))
+            }
+        }
+
+        if let TradeInstrument::Symbol { ref symbol, .. } = self.instrument {
+            if symbol.trim().is_empty() {
+                return Err(ValidationError::Empty("symbol"));
+            }
+        }
+
+        if let Some(ref exchange_rate) = self.exchange_rate {
+            positive("exchange_rate", exchange_rate)?;
+        }
+
+        if let Some(ref unique_identifier) = self.unique_identifier {
+            if unique_identifier.chars().count() > 255 {
+                return Err(ValidationError::TooLong("unique_identifier", 255));
+            }
+        }
+
+        match self.details {
+            NewTradeDetails::Buy {
+                ref quantity,
+                ref price,
+                ref brokerage,
+                ..
+            }
+            | NewTradeDetails::Sell {
+                ref quantity,
+                ref price,
+                ref brokerage,
+                ..
+            } => {
+                positive("quantity", quantity)?;
+                not_negative("price", price)?;
+                if let Some(ref brokerage) = brokerage {
+                    not_negative("brokerage", brokerage)?;
+                }
+            }
+            NewTradeDetails::Split { ref quantity }
+            | NewTradeDetails::Bonus { ref quantity }
+            | NewTradeDetails::Consold { ref quantity }
+            | NewTradeDetails::Cancel { ref quantity } => positive("quantity", quantity)?,
+            NewTradeDetails::OpeningBalance {
+                ref quantity,
+                ref price,
+                ref cost_base,
+            } => {
+                positive("quantity", quantity)?;
+                not_negative("price", price)?;
+                not_negative("cost_base", cost_base)?;
+            }
+            NewTradeDetails::CapitalReturn { .. }
+            | NewTradeDetails::CapitalCall { .. }
+            | NewTradeDetails::AdjustCostBase { .. } => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<NewTrade> for TradesCreateParameters {
+    type Error = ValidationError;
+
+    fn try_from(value: NewTrade) -> Result<Self, Self::Error> {
+        value.validate()?;
+
+        let transaction_type = value.details.transaction_type();
+        let mut trade = TradesCreateTradeParameters {
+            portfolio_id: None,
+            holding_id: None,
+            unique_identifier: value.unique_identifier,
+            company_event_id: None,
+            state: None,
+            transaction_date: Some(value.transaction_date.format("%Y-%m-%d").to_string()),
+            quantity: None,
+            price: None,
+            cost_base: None,
+            exchange_rate: value.exchange_rate,
+            brokerage: None,
+            brokerage_currency_code: None,
+            adjust_cost_base_value: None,
+            capital_return_value: None,
+            paid_on: None,
+            comments: value.comments,
+            instrument_id: None,
+            symbol: None,
+            market: None,
+            market_country_code: None,
+            transaction_type: Some(transaction_type),
+            attachment: value.attachment.as_ref().map(Attachment::encoded),
+            attachment_filename: value.attachment.map(|attachment| attachment.filename),
+        };
+
+        match value.instrument {
+            TradeInstrument::Holding(holding_id) => trade.holding_id = Some(holding_id),
+            TradeInstrument::Instrument {
+                portfolio_id,
+                instrument_id,
+            } => {
+                trade.portfolio_id = Some(portfolio_id);
+                trade.instrument_id = Some(instrument_id.to_string());
+            }
+            TradeInstrument::Symbol {
+                portfolio_id,
+                symbol,
+                market,
+            } => {
+                trade.portfolio_id = Some(portfolio_id);
+                trade.symbol = Some(symbol);
+                trade.market = Some(market);
+            }
+        }
+
+        match value.details {
+            NewTradeDetails::Buy {
+                quantity,
+                price,
+                brokerage,
+                brokerage_currency_code,
+            }
+            | NewTradeDetails::Sell {
+                quantity,
+                price,
+                brokerage,
+                brokerage_currency_code,
+            } => {
+                trade.quantity = Some(quantity);
+                trade.price = Some(price);
+                trade.brokerage = brokerage;
+                trade.brokerage_currency_code = brokerage_currency_code;
+            }
+            NewTradeDetails::Split { quantity }
+            | NewTradeDetails::Bonus { quantity }
+            | NewTradeDetails::Consold { quantity }
+            | NewTradeDetails::Cancel { quantity } => trade.quantity = Some(quantity),
+            NewTradeDetails::CapitalReturn {
+                capital_return_value,
+                paid_on,
+            }
+            | NewTradeDetails::CapitalCall {
+                capital_return_value,
+                paid_on,
+            } => {
+                trade.capital_return_value = Some(capital_return_value);
+                trade.paid_on = paid_on;
+            }
+            NewTradeDetails::OpeningBalance {
+                quantity,
+                price,
+                cost_base,
+            } => {
+                trade.quantity = Some(quantity);
+                trade.price = Some(price);
+                trade.cost_base = Some(cost_base);
+            }
+            NewTradeDetails::AdjustCostBase {
+                adjust_cost_base_value,
+            } => trade.adjust_cost_base_value = Some(adjust_cost_base_value),
+        }
+
+        Ok(TradesCreateParameters { trade })
+    }
+}
+
+#[cfg(test)]
+mod new_trade_tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, 7, 1).unwrap()
+    }
+
+    #[test]
+    fn buy_by_symbol() {
+        let trade = NewTrade::new(
+            TradeInstrument::Symbol {
+                portfolio_id: 1,
+                symbol: "AIR".to_string(),
+                market: Market::NZX,
+            },
+            date(),
+            NewTradeDetails::Buy {
+                quantity: Number::from(10),
+                price: Number::from(2),
+                brokerage: None,
+                brokerage_currency_code: None,
+            },
+        );
+
+        let TradesCreateParameters { trade } = TradesCreateParameters::try_from(trade).unwrap();
+
+        assert_eq!(Some(TradeDescription::Buy), trade.transaction_type);
+        assert_eq!(Some("2023-07-01".to_string()), trade.transaction_date);
+        assert_eq!(Some(1), trade.portfolio_id);
+        assert_eq!(None, trade.holding_id);
+        assert_eq!(Some(Market::NZX), trade.market);
+        assert_eq!(Some(Number::from(10)), trade.quantity);
+        assert_eq!(None, trade.cost_base);
+    }
+
+    #[test]
+    fn brokerage_currency() {
+        let trade = NewTrade::new(
+            TradeInstrument::Holding(1),
+            date(),
+            NewTradeDetails::Buy {
+                quantity: Number::from(10),
+                price: Number::from(2),
+                brokerage: Some(Number::from(5)),
+                brokerage_currency_code: Some(Currency::USD),
+            },
+        );
+        let parameters = TradesCreateParameters::try_from(trade).unwrap();
+
+        assert_eq!(
+            Err(ValidationError::Currency("brokerage_currency_code")),
+            parameters.validate_brokerage_currency(&[Currency::NZD, Currency::AUD])
+        );
+        assert_eq!(
+            Ok(()),
+            parameters.validate_brokerage_currency(&[Currency::NZD, Currency::USD])
+        );
+    }
+
+    #[test]
+    fn opening_balance_with_zero_quantity() {
+        let trade = NewTrade::new(
+            TradeInstrument::Holding(1),
+            date(),
+            NewTradeDetails::OpeningBalance {
+                quantity: Number::default(),
+                price: Number::from(2),
+                cost_base: Number::from(20),
+            },
+        );
+
+        assert_eq!(
+            Err(ValidationError::NotPositive("quantity")),
+            TradesCreateParameters::try_from(trade).map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::fmt;
+
+use crate::{CashAccount, Portfolio, PortfolioListPortfoliosSuccess, ValuationHoldingsSuccess};
+
+/// Something a user can refer to by its id or name, such as a portfolio.
+pub trait Resolvable {
+    /// What it is, such as `portfolio`, for error messages.
+    const KIND: &'static str;
+    const KIND_PLURAL: &'static str;
+
+    fn id(&self) -> i64;
+
+    fn name(&self) -> &str;
+
+    /// Another name it can be referred to by, such as the instrument name of a holding.
+    fn alt_name(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl Resolvable for Portfolio {
+    const KIND: &'static str = "portfolio";
+    const KIND_PLURAL: &'static str = "portfolios";
+
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+// The portfolio list has fewer fields than a single portfolio, so it isn't shared as `Portfolio`.
+impl Resolvable for PortfolioListPortfoliosSuccess {
+    const KIND: &'static str = "portfolio";
+    const KIND_PLURAL: &'static str = "portfolios";
+
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Resolvable for CashAccount {
+    const KIND: &'static str = "cash account";
+    const KIND_PLURAL: &'static str = "cash accounts";
+
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Resolvable for ValuationHoldingsSuccess {
+    const KIND: &'static str = "holding";
+    const KIND_PLURAL: &'static str = "holdings";
+
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    // Holdings are usually referred to by symbol, so it's the name shown in errors.
+    #[allow(clippy::misnamed_getters)]
+    fn name(&self) -> &str {
+        &self.symbol
+    }
+
+    fn alt_name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+}
+
+/// A reference that doesn't pick out exactly one item, with the candidates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveError {
+    NotFound {
+        kind: &'static str,
+        kind_plural: &'static str,
+        query: String,
+        /// The names of every item.
+        candidates: Vec<String>,
+    },
+    Ambiguous {
+        kind: &'static str,
+        query: String,
+        /// The ids and names of the items the query matches, as their names may be the same.
+        candidates: Vec<(i64, String)>,
+    },
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolveError::NotFound {
+                kind,
+                kind_plural,
+                query,
+                candidates,
+            } => {
+                write!(f, "Unknown {}: {}, ", kind, query)?;
+
+                match candidates.as_slice() {
+                    [] => write!(f, "there are no {}", kind_plural),
+                    [name] => write!(f, "the only {} is: {}", kind, name),
+                    names => write!(f, "the {} are: {}", kind_plural, or_list(names)),
+                }
+            }
+            ResolveError::Ambiguous {
+                kind,
+                query,
+                candidates,
+            } => {
+                let candidates = candidates
+                    .iter()
+                    .map(|(id, name)| format!("{} (id {})", name, id))
+                    .collect::<Vec<_>>();
+
+                write!(
+                    f,
+                    "Ambiguous {}: {}, it could be: {}",
+                    kind,
+                    query,
+                    or_list(&candidates)
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+/// The names as `a, b or c`.
+fn or_list(names: &[String]) -> String {
+    match names {
+        [init @ .., last] if !init.is_empty() => format!("{} or {}", init.join(", "), last),
+        names => names.join(""),
+    }
+}
+
+/// The item the query refers to, trying in turn its id, its exact name, its name ignoring case
+/// and a unique prefix of its name ignoring case. Alternative names are tried along with names.
+pub fn resolve<'a, T: Resolvable>(items: &'a [T], query: &str) -> Result<&'a T, ResolveError> {
+    resolve_with(items, query, false)
+}
+
+/// The item the query refers to by its id or exact name, for commands that change or delete what
+/// they resolve, where a different case or a prefix could pick out the wrong item.
+pub fn resolve_exact<'a, T: Resolvable>(
+    items: &'a [T],
+    query: &str,
+) -> Result<&'a T, ResolveError> {
+    resolve_with(items, query, true)
+}
+
+fn resolve_with<'a, T: Resolvable>(
+    items: &'a [T],
+    query: &str,
+    exact: bool,
+) -> Result<&'a T, ResolveError> {
+    let names = |item: &'a T| std::iter::once(item.name()).chain(item.alt_name());
+    let lowercase_query = query.to_lowercase();
+
+    let matchers: [&dyn Fn(&'a T) -> bool; 4] = [
+        &|item| query.parse() == Ok(item.id()),
+        &|item| names(item).any(|name| name == query),
+        &|item| names(item).any(|name| name.to_lowercase() == lowercase_query),
+        &|item| names(item).any(|name| name.to_lowercase().starts_with(&lowercase_query)),
+    ];
+    let matchers = if exact { &matchers[..2] } else { &matchers[..] };
+
+    for matches in matchers {
+        let found = items
+            .iter()
+            .filter(|item| matches(item))
+            .collect::<Vec<_>>();
+
+        match found.as_slice() {
+            [] => continue,
+            [item] => return Ok(item),
+            found => {
+                return Err(ResolveError::Ambiguous {
+                    kind: T::KIND,
+                    query: query.to_string(),
+                    candidates: found
+                        .iter()
+                        .map(|item| (item.id(), item.name().to_string()))
+                        .collect(),
+                })
+            }
+        }
+    }
+
+    Err(ResolveError::NotFound {
+        kind: T::KIND,
+        kind_plural: T::KIND_PLURAL,
+        query: query.to_string(),
+        candidates: items.iter().map(|item| item.name().to_string()).collect(),
+    })
+}
+
+#[cfg(test)]
+mod resolve_tests {
+    use super::{resolve, resolve_exact, Resolvable, ResolveError};
+
+    #[derive(Debug)]
+    struct Item(i64, &'static str);
+
+    impl Resolvable for Item {
+        const KIND: &'static str = "portfolio";
+        const KIND_PLURAL: &'static str = "portfolios";
+
+        fn id(&self) -> i64 {
+            self.0
+        }
+
+        fn name(&self) -> &str {
+            self.1
+        }
+    }
+
+    #[test]
+    fn resolves() {
+        let items = [
+            Item(1, "Retirement"),
+            Item(2, "retirement"),
+            Item(3, "Trading"),
+            Item(4, "Trust"),
+            Item(5, "2"),
+        ];
+        let id = |query| resolve(&items, query).map(|item| item.0);
+
+        assert_eq!(Ok(2), id("2"));
+        assert_eq!(Ok(1), id("Retirement"));
+        assert_eq!(Ok(3), id("TRADING"));
+        assert_eq!(Ok(4), id("tru"));
+        assert_eq!(
+            "Ambiguous portfolio: RETIREMENT, it could be: Retirement (id 1) or retirement (id 2)",
+            id("RETIREMENT").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "Ambiguous portfolio: tr, it could be: Trading (id 3) or Trust (id 4)",
+            id("tr").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "Unknown portfolio: Super, the portfolios are: Retirement, retirement, Trading, Trust or 2",
+            id("Super").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "Unknown portfolio: Super, the only portfolio is: Trust",
+            resolve(&items[3..4], "Super").unwrap_err().to_string()
+        );
+        assert!(matches!(
+            resolve::<Item>(&[], "Super"),
+            Err(ResolveError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn resolves_exactly() {
+        let items = [Item(1, "Retirement"), Item(2, "Trading")];
+        let id = |query| resolve_exact(&items, query).map(|item| item.0);
+
+        assert_eq!(Ok(1), id("1"));
+        assert_eq!(Ok(2), id("Trading"));
+        assert!(matches!(id("trading"), Err(ResolveError::NotFound { .. })));
+        assert!(matches!(id("Trad"), Err(ResolveError::NotFound { .. })));
+    }
+
+    #[test]
+    fn same_names() {
+        let items = [Item(1, "Cash"), Item(2, "Cash")];
+
+        assert_eq!(
+            "Ambiguous portfolio: Cash, it could be: Cash (id 1) or Cash (id 2)",
+            resolve_exact(&items, "Cash").unwrap_err().to_string()
+        );
+        assert_eq!(Ok(2), resolve_exact(&items, "2").map(|item| item.0));
+    }
+}
@@ -0,0 +1,46 @@
+use std::{error::Error, future::Future, pin::Pin};
+
+use crate::types_prelude::ApiHttpMethod;
+
+/// An HTTP request built by a client for a transport to send.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: ApiHttpMethod,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// The HTTP response received by a transport.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// The value of the first header with the name, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+pub type TransportFuture<'a, E> =
+    Pin<Box<dyn Future<Output = Result<HttpResponse, E>> + Send + 'a>>;
+
+/// Sends the HTTP requests of a client, so a client can use `reqwest`, another HTTP library or a
+/// fake in tests while sharing how requests are built, retried and decoded. A transport sends
+/// each request once; the client decides whether to send it again.
+pub trait Transport {
+    type Error: Into<Box<dyn Error + Send + Sync>>;
+
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_, Self::Error>;
+}
@@ -1,5 +1,407 @@
 use crate::types_prelude::*;
 
+/// The state of the trade, can be any of "confirmed", "unconfirmed" or "rejected".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TradeState {
+    #[serde(rename = "confirmed")]
+    Confirmed,
+    #[serde(rename = "unconfirmed")]
+    Unconfirmed,
+    #[serde(rename = "rejected")]
+    Rejected,
+}
+
+/// Access level (one of NONE, READ, EDIT, ADMIN)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AccessCode {
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "READ")]
+    Read,
+    #[serde(rename = "EDIT")]
+    Edit,
+    #[serde(rename = "ADMIN")]
+    Admin,
+    #[serde(rename = "OWNER")]
+    Owner,
+}
+
+/// Which payout date to use when filtering. Allowed values: `"paid_on"`, `"ex_date"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum UseDate {
+    #[serde(rename = "paid_on")]
+    PaidOn,
+    #[serde(rename = "ex_date")]
+    ExDate,
+}
+
+/// The state of the payout, can be any of `"confirmed"`, `"unconfirmed"` or `"rejected"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum PayoutState {
+    #[serde(rename = "confirmed")]
+    Confirmed,
+    #[serde(rename = "unconfirmed")]
+    Unconfirmed,
+    #[serde(rename = "rejected")]
+    Rejected,
+}
+
+/// Interest method calculation: `"simple"` or `"compound"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum InterestMethod {
+    #[serde(rename = "simple")]
+    Simple,
+    #[serde(rename = "compound")]
+    Compound,
+}
+
+/// For Canadian portfolios, the type of tax processing (`"non_registered"`, `"rrsp"` or `"rrif"`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TaxEntityType {
+    #[serde(rename = "non_registered")]
+    NonRegistered,
+    #[serde(rename = "rrsp")]
+    Rrsp,
+    #[serde(rename = "rrif")]
+    Rrif,
+}
+
+/// One of: market(s), industry_classification(s), sector_classification(s), investment_type(s), countries/country, portfolio, ungrouped or custom_group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Grouping {
+    #[serde(rename = "market", alias = "markets")]
+    Market,
+    #[serde(rename = "industry_classification", alias = "industry_classifications")]
+    IndustryClassification,
+    #[serde(rename = "sector_classification", alias = "sector_classifications")]
+    SectorClassification,
+    #[serde(rename = "investment_type", alias = "investment_types")]
+    InvestmentType,
+    #[serde(rename = "countries", alias = "country")]
+    Countries,
+    #[serde(rename = "portfolio")]
+    Portfolio,
+    #[serde(rename = "ungrouped")]
+    Ungrouped,
+    #[serde(rename = "custom_group")]
+    CustomGroup,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct Trade {
+    /// The trade ID. Maybe nil if the trade is based on an adjustment and has not yet been confirmed by the user.
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub id: String,
+    /// A unique identifier associated with this trade
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub unique_identifier: String,
+    /// The trade date (format YYYY-MM-DD).
+    #[serde_as(as = "DeserializeDate")]
+    pub transaction_date: NaiveDate,
+    /// Number of shares sold/bought.
+    pub quantity: Number,
+    /// Price paid/received.
+    pub price: Number,
+    /// For an opening balance, the cost base of the trade. Always returned in the portfolio currency
+    pub cost_base: Number,
+    /// The trade's exchange rate as portfolio currency / instrument currency.
+    pub exchange_rate: Number,
+    /// The trade's brokerage.
+    pub brokerage: Number,
+    /// The ISO code of the brokerage currency, must be either Portfolio or Instrument currency. If the instrument is a cryptocurrency, any valid brokerage currency is supported.
+    pub brokerage_currency_code: Currency,
+    /// The value for the trade as displayed in the 'value' column of the UI. For a return of capital, this will be the (signed) capital return value. For a capital call, this will be the (positive) capital return value. For a cost base adjustment, this will be the value of the adjustment. For an opening balance, this will be the market value: the market price x quantity at the opening balance date In each case this is in portfolio currency (rounded to 2 decimal places).
+    pub value: Number,
+    /// For a CAPITAL_RETURN or other trade with a linked payout, this is the paid on date
+    #[serde_as(as = "DeserializeDate")]
+    pub paid_on: NaiveDate,
+    /// The company event linked to the transaction.
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub company_event_id: Option<i64>,
+    /// Any comments for that trade.
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub comments: String,
+    /// Portfolio ID of the trade.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub portfolio_id: i64,
+    /// Holding ID of the trade.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub holding_id: i64,
+    /// The state of the trade, can be any of "confirmed", "unconfirmed" or "rejected".
+    pub state: TradeState,
+    /// The trade type ('BUY','SELL','SPLIT','BONUS','CONSOLD','CANCEL','CAPITAL_RETURN','OPENING_BALANCE','ADJUST_COST_BASE','CAPITAL_CALL').
+    pub transaction_type: TradeDescription,
+    /// Instrument ID of the related Holding.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub instrument_id: i64,
+    /// The instrument code/symbol
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub symbol: String,
+    /// The market code (eg. `ASX`, `NZX`, etc).
+    pub market: Market,
+    /// The filename of any attachment
+    #[serde(default)]
+    pub attachment_filename: Option<String>,
+    /// The document id of any attachment, for use with the Show Document API (v2)
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub attachment_id: Option<i64>,
+    /// Returns 'true' if trade is confirmed.  DEPRECATED: Use the state field to determine 'confirmed' vs. 'rejected' vs. 'unconfirmed' instead.
+    pub confirmed: bool,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct Parcel {
+    /// The market symbol
+    pub market: Market,
+    /// The instrument symbol
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub symbol: String,
+    /// The name of the instrument
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub name: String,
+    /// The allocation method used: see <a href="/api/2/codes#sale_allocation_method">valid methods by country</a>
+    pub allocation_method: SaleAllocationMethod,
+    /// The purchase date for this parcel (format `YYYY-MM-DD`).
+    #[serde_as(as = "DeserializeDate")]
+    pub purchase_date: NaiveDate,
+    /// The quantity of stock held
+    pub quantity: Number,
+    /// The adjusted total cost of the parcel (rounded to 2 decimal places).
+    pub cost_base: Number,
+    /// The market value of the parcel at the balance date (rounded to 2 decimal places).
+    pub market_value: Number,
+    /// The capital gain (negative for losses, rounded to 2 decimal places).
+    pub gain: Number,
+    /// The date the gain is calculated for (format `YYYY-MM-DD`)
+    #[serde_as(as = "DeserializeDate")]
+    pub gain_date: NaiveDate,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct CashAccount {
+    /// The cash account ID.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub id: i64,
+    /// The cash account name.
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub name: String,
+    /// The cash account currency code.
+    pub currency: Currency,
+    /// The cash accounts portfolio currency code.
+    pub portfolio_currency: Currency,
+    /// The portfolio ID
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub portfolio_id: i64,
+    /// The cash accounts balance on date (format `YYYY-MM-DD`).
+    #[serde_as(as = "DeserializeDate")]
+    pub date: NaiveDate,
+    /// The cash account balance on a given date (rounded to 2 decimal places).
+    pub balance: Number,
+    /// The cash account balance converted into the portfolios currency (rounded to 2 decimal places).
+    pub balance_in_portfolio_currency: Number,
+    /// List of links for this cash account
+    pub links: CashAccountLinks,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct CashAccountLinks {
+    /// Url of the portfolio of this cash account
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct Payout {
+    /// The payout ID.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub id: i64,
+    /// The portfolio ID.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub portfolio_id: i64,
+    /// The holding ID.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub holding_id: i64,
+    /// The instrument ID.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub instrument_id: i64,
+    /// Payout company/instrument symbol on the market.
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub symbol: String,
+    /// Market code.
+    pub market: Market,
+    /// The payout date (format `YYYY-MM-DD`).
+    #[serde_as(as = "DeserializeDate")]
+    pub paid_on: NaiveDate,
+    /// The payout ex date (format `YYYY-MM-DD`).
+    #[serde_as(as = "Option<DeserializeDate>")]
+    #[serde(default)]
+    pub ex_date: Option<NaiveDate>,
+    /// The payout amount.
+    pub amount: Number,
+    /// The calculated gross amount
+    pub gross_amount: Number,
+    /// The payout resident withholding tax amount. Always returned in the portfolio currency.
+    #[serde(default)]
+    pub resident_withholding_tax: Option<Number>,
+    /// The payout non-resident withholding tax amount.
+    #[serde(default)]
+    pub non_resident_withholding_tax: Option<Number>,
+    /// The payout tax credit amount. Always returned in the portfolio currency.
+    #[serde(default)]
+    pub tax_credit: Option<Number>,
+    /// Payout currency code, using 3-letter ISO 4217 code.
+    pub currency: Currency,
+    /// The payout's exchange rate.
+    pub exchange_rate: Number,
+    /// If `true`, payout is non taxable.
+    pub non_taxable: bool,
+    /// Any comments for that payout.
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub comments: String,
+    /// ID of the company event the given payout is based on (nil if not based on any).
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub company_event_id: Option<i64>,
+    /// The state of the payout, can be any of `"confirmed"`, `"unconfirmed"` or `"rejected"`.
+    pub state: PayoutState,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct Portfolio {
+    /// The portfolio ID.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub id: i64,
+    /// The portfolio name.
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub name: String,
+    /// The default sale allocation method for tax reporting.
+    pub default_sale_allocation_method: SaleAllocationMethod,
+    /// Discount for Capital Gains Tax.
+    #[serde(default)]
+    pub cg_discount: Option<String>,
+    /// New Zealand Resident Withholding Tax Rate. As a percentage to 1 decimal place (for example, 33.1% comes as `33.1`).
+    pub rwtr_rate: Number,
+    /// Tax Status (`true`: Trade, `false`: Investor). Can be `null`.
+    pub trader: bool,
+    /// Automatic Transactions are disabled (`true`) or enabled (`false`).
+    pub disable_automatic_transactions: bool,
+    /// For Canadian portfolios, the type of tax processing (`"non_registered"`, `"rrsp"` or `"rrif"`). Can be `null`.
+    #[serde(default)]
+    pub tax_entity_type: Option<TaxEntityType>,
+    /// `true` if the broker import email is enabled. Present for portfolio admins
+    pub broker_email_api_enabled: bool,
+    /// Email prefix of the broker import email address. Present for portfolio admins
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub broker_email_key: String,
+    /// (deprecated) Financial Year end month (`1`: Jan, `2`: Feb, etc.).
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub financial_year_end_month_id: i64,
+    /// Financial Year end date MM-DD.
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub financial_year_end: String,
+    /// Performance Calculation Method
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub interest_method: String,
+    /// ISO code of the portfolio country (see <a href="https://en.wikipedia.org/wiki/ISO_3166-1">ISO 3166-1</a>)
+    pub country_code: Country,
+    /// ISO code of the portfolio currency (see <a href="https://en.wikipedia.org/wiki/ISO_4217">ISO 4217</a>)
+    pub currency_code: Currency,
+    /// Portfolio's inception date (first trade record). Format: dd mmm yyyy
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub inception_date: String,
+    /// Time zone name
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub tz_name: String,
+    /// Calculates accrual adjustments against any portfolio cash accounts to allow for unsettled trades and unpaid dividends
+    pub apply_cash_account_adjustments: bool,
+    /// Specifies the number of working days between the buy trade date and settlement in the cash account. Can be `null`.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub buy_trade_settlement_delay: i64,
+    /// Specifies the number of working days between the sell trade date and settlement in the cash account. Can be `null`.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub sell_trade_settlement_delay: i64,
+    /// Accounts for the fact that bank statement data is delayed by a day due to overnight processing
+    pub account_for_delayed_cash_transactions: bool,
+    /// All buys and sells will generate a corresponding deposit/withdrawal in the selected 'trading' account. Can be `null`.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub trade_sync_cash_account_id: i64,
+    /// All payouts will generate a corresponding deposit in the selected 'payout' account. Can be `null`.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub payout_sync_cash_account_id: i64,
+    /// Typically used by professionals to identify the tax entity owner of the portfolio
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub external_identifier: String,
+    /// List of links for this portfolio
+    pub links: PortfolioLinks,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct PortfolioLinks {
+    /// Url of this portfolio
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub portfolio: String,
+    /// Url of this portfolio
+    #[serde(rename = "self")]
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub itself: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
 /// Creates a new cash account within a portfolio.
 pub struct CashAccountCreate;
 
@@ -53,44 +455,15 @@ pub struct CashAccountCreateCashAccountParameters {
 #[derive(Debug, Clone, Deserialize)]
 pub struct CashAccountCreateSuccess {
     pub cash_account: CashAccountCreateCashAccountSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct CashAccountCreateCashAccountSuccess {
-    /// The cash account ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub id: i64,
-    /// The cash account name.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub name: String,
-    /// The cash account currency code.
-    pub currency: Currency,
-    /// The cash accounts portfolio currency code.
-    pub portfolio_currency: Currency,
-    /// The portfolio ID
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub portfolio_id: i64,
-    /// The cash accounts balance on date (format `YYYY-MM-DD`).
-    #[serde_as(as = "DeserializeDate")]
-    pub date: NaiveDate,
-    /// The cash account balance on a given date (rounded to 2 decimal places).
-    pub balance: Number,
-    /// The cash account balance converted into the portfolios currency (rounded to 2 decimal places).
-    pub balance_in_portfolio_currency: Number,
-    /// List of links for this cash account
-    pub links: CashAccountCreateCashAccountLinksSuccess,
-}
+pub type CashAccountCreateCashAccountSuccess = CashAccount;
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct CashAccountCreateCashAccountLinksSuccess {
-    /// Url of the portfolio of this cash account
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub portfolio: String,
-}
+pub type CashAccountCreateCashAccountLinksSuccess = CashAccountLinks;
 
 /// Deletes an existing cash account.
 pub struct CashAccountDelete;
@@ -190,6 +563,10 @@ pub struct CashAccountShowSuccess {
     pub balance_in_portfolio_currency: Number,
     /// List of links for this cash account
     pub links: CashAccountShowLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -204,6 +581,10 @@ pub struct CashAccountShowLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub itself: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Creates a new cash transaction within a cash account.
@@ -254,6 +635,7 @@ pub struct CashAccountTransactionCreateParameters {
     pub date_time: DateTime<FixedOffset>,
     /// The new transaction foreign-identifier.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foreign_identifier: Option<String>,
 }
 
@@ -261,6 +643,10 @@ pub struct CashAccountTransactionCreateParameters {
 #[derive(Debug, Clone, Deserialize)]
 pub struct CashAccountTransactionCreateSuccess {
     pub cash_account_transaction: CashAccountTransactionCreateCashAccountTransactionSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -297,6 +683,10 @@ pub struct CashAccountTransactionCreateCashAccountTransactionSuccess {
     pub cash_account_transaction_type: CashAccountTransactionType,
     /// List of links for this cash account transaction
     pub links: CashAccountTransactionCreateCashAccountTransactionLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -306,6 +696,10 @@ pub struct CashAccountTransactionCreateCashAccountTransactionLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes an existing cash account transaction.
@@ -393,6 +787,10 @@ pub struct CashAccountTransactionUpdateParameters {
 #[derive(Debug, Clone, Deserialize)]
 pub struct CashAccountTransactionUpdateSuccess {
     pub cash_account_transaction: CashAccountTransactionUpdateCashAccountTransactionSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -429,6 +827,10 @@ pub struct CashAccountTransactionUpdateCashAccountTransactionSuccess {
     pub cash_account_transaction_type: CashAccountTransactionType,
     /// List of links for this cash account transaction
     pub links: CashAccountTransactionUpdateCashAccountTransactionLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -438,6 +840,10 @@ pub struct CashAccountTransactionUpdateCashAccountTransactionLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Returns list of transactions for a cash account.
@@ -499,6 +905,10 @@ pub struct CashAccountTransactionsListSuccess {
     pub cash_account_transactions: Vec<CashAccountTransactionsListCashAccountTransactionsSuccess>,
     /// List of links for this resource
     pub links: CashAccountTransactionsListLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -535,6 +945,10 @@ pub struct CashAccountTransactionsListCashAccountTransactionsSuccess {
     pub cash_account_transaction_type: CashAccountTransactionType,
     /// List of links for this cash account transaction
     pub links: CashAccountTransactionsListCashAccountTransactionsLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -544,6 +958,10 @@ pub struct CashAccountTransactionsListCashAccountTransactionsLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -554,6 +972,10 @@ pub struct CashAccountTransactionsListLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub itself: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates attributes on an existing cash account.
@@ -596,42 +1018,9 @@ pub struct CashAccountUpdateParameters {
     pub currency: Currency,
 }
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct CashAccountUpdateSuccess {
-    /// The cash account ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub id: i64,
-    /// The cash account name.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub name: String,
-    /// The cash account currency code.
-    pub currency: Currency,
-    /// The cash accounts portfolio currency code.
-    pub portfolio_currency: Currency,
-    /// The portfolio ID
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub portfolio_id: i64,
-    /// The cash accounts balance on date (format `YYYY-MM-DD`).
-    #[serde_as(as = "DeserializeDate")]
-    pub date: NaiveDate,
-    /// The cash account balance on a given date (rounded to 2 decimal places).
-    pub balance: Number,
-    /// The cash account balance converted into the portfolios currency (rounded to 2 decimal places).
-    pub balance_in_portfolio_currency: Number,
-    /// List of links for this cash account
-    pub links: CashAccountUpdateLinksSuccess,
-}
+pub type CashAccountUpdateSuccess = CashAccount;
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct CashAccountUpdateLinksSuccess {
-    /// Url of the portfolio of this cash account
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub portfolio: String,
-}
+pub type CashAccountUpdateLinksSuccess = CashAccountLinks;
 
 /// Returns list of cash accounts for a portfolio. If you want to only access the cash account of one particular portfolios, use https://api.sharesight.com/api/v2/portfolios/:id/cash_accounts.json. The response will be the same.
 pub struct CashAccountsList;
@@ -645,65 +1034,36 @@ impl<'a> ApiEndpoint<'a> for CashAccountsList {
     type Success = CashAccountsListSuccess;
 
     fn url_path(_parameters: &'a Self::Parameters) -> Self::UrlDisplay {
-        "/cash_accounts.json"
-    }
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Serialize)]
-pub struct CashAccountsListParameters {
-    /// Cash Account balance on date (format `YYYY-MM-DD`).
-    #[serde_as(as = "Option<DeserializeDate>")]
-    #[serde(default)]
-    pub date: Option<NaiveDate>,
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct CashAccountsListSuccess {
-    /// List of cash accounts.
-    pub cash_accounts: Vec<CashAccountsListCashAccountsSuccess>,
-    /// List of links for this resource
-    pub links: CashAccountsListLinksSuccess,
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct CashAccountsListCashAccountsSuccess {
-    /// The cash account ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub id: i64,
-    /// The cash account name.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub name: String,
-    /// The cash account currency code.
-    pub currency: Currency,
-    /// The portfolio ID
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub portfolio_id: i64,
-    /// The portfolio currency
-    pub portfolio_currency: Currency,
-    /// The cash accounts balance on date (format `YYYY-MM-DD`).
-    #[serde_as(as = "DeserializeDate")]
-    pub date: NaiveDate,
-    /// The cash account balance on a given date (rounded to 2 decimal places).
-    pub balance: Number,
-    /// The cash account balance converted into the portfolios currency (rounded to 2 decimal places).
-    pub balance_in_portfolio_currency: Number,
-    /// List of links for this cash account
-    pub links: CashAccountsListCashAccountsLinksSuccess,
+        "/cash_accounts.json"
+    }
 }
 
 #[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct CashAccountsListCashAccountsLinksSuccess {
-    /// Url of the portfolio of this cash account
+#[derive(Debug, Clone, Serialize)]
+pub struct CashAccountsListParameters {
+    /// Cash Account balance on date (format `YYYY-MM-DD`).
+    #[serde_as(as = "Option<DeserializeDate>")]
     #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub portfolio: String,
+    pub date: Option<NaiveDate>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct CashAccountsListSuccess {
+    /// List of cash accounts.
+    pub cash_accounts: Vec<CashAccountsListCashAccountsSuccess>,
+    /// List of links for this resource
+    pub links: CashAccountsListLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+pub type CashAccountsListCashAccountsSuccess = CashAccount;
+
+pub type CashAccountsListCashAccountsLinksSuccess = CashAccountLinks;
+
 #[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 pub struct CashAccountsListLinksSuccess {
@@ -712,6 +1072,10 @@ pub struct CashAccountsListLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub itself: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Returns a specific document.
@@ -723,13 +1087,15 @@ impl<'a> ApiEndpoint<'a> for DocumentShow {
 
     type UrlDisplay = DocumentShowUrlDisplay<'a>;
     type Parameters = DocumentShowParameters;
-    type Success = DocumentShowSuccess;
+    type Success = ();
 
     fn url_path(parameters: &'a Self::Parameters) -> Self::UrlDisplay {
         DocumentShowUrlDisplay(parameters)
     }
 }
 
+impl<'a> ApiFileEndpoint<'a> for DocumentShow {}
+
 pub struct DocumentShowUrlDisplay<'a>(&'a DocumentShowParameters);
 
 impl<'a> fmt::Display for DocumentShowUrlDisplay<'a> {
@@ -748,13 +1114,6 @@ pub struct DocumentShowParameters {
     pub id: i64,
 }
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct DocumentShowSuccess {
-    /// The file itself
-    pub file: (),
-}
-
 /// List all custom (and regular) groups which a user has defined
 pub struct GroupsList;
 
@@ -776,6 +1135,10 @@ impl<'a> ApiEndpoint<'a> for GroupsList {
 pub struct GroupsListSuccess {
     /// List of custom groups.
     pub groups: Vec<GroupsListGroupsSuccess>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -792,6 +1155,10 @@ pub struct GroupsListGroupsSuccess {
     /// A list of portfolio ids a custom group can be used with (Custom groups can only be used on their creators portfolios)
     #[serde(default)]
     pub portfolio_ids: Option<Vec<i64>>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The Create a holding merge endpoint is designed to record a company merger in the   situation where you hold shares in a listed company that is wholly acquired by another   listed company.
@@ -810,6 +1177,10 @@ impl<'a> ApiEndpoint<'a> for HoldingMergesCreate {
     fn url_path(parameters: &'a Self::Parameters) -> Self::UrlDisplay {
         HoldingMergesCreateUrlDisplay(parameters)
     }
+
+    fn validate(parameters: &Self::Parameters) -> Result<(), ValidationError> {
+        parameters.validate()
+    }
 }
 
 pub struct HoldingMergesCreateUrlDisplay<'a>(&'a HoldingMergesCreateParameters);
@@ -848,18 +1219,23 @@ pub struct HoldingMergesCreateParameters {
     pub market: Market,
     /// The cancelled price
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cancelled_price: Option<Number>,
     /// Your comments against the trade
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<String>,
     /// This is a string of up to 255 characters used to identify duplicate trades. Generate an identifier for each trade and Sharesight will check on upload that this trade has not already been loaded.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unique_identifier: Option<String>,
     /// Base64 encoded file to be attached to both trades (cancel- and buy-trade)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attachment: Option<String>,
     /// File name for the attachment. This parameter is required if attachment is set.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attachment_filename: Option<String>,
 }
 
@@ -867,6 +1243,10 @@ pub struct HoldingMergesCreateParameters {
 #[derive(Debug, Clone, Deserialize)]
 pub struct HoldingMergesCreateSuccess {
     pub holding_merge: HoldingMergesCreateHoldingMergeSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -877,6 +1257,10 @@ pub struct HoldingMergesCreateHoldingMergeSuccess {
     pub id: i64,
     /// List of both trades created, cancel- and buy-trade
     pub trades: Vec<HoldingMergesCreateHoldingMergeTradesSuccess>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -933,15 +1317,17 @@ pub struct HoldingMergesCreateHoldingMergeTradesSuccess {
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub instrument_id: i64,
     /// The state of the trade, can be any of "confirmed", "unconfirmed" or "rejected".
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: TradeState,
     /// Filename of attachmented file, if present.
     #[serde(default)]
     pub attachment_filename: Option<String>,
     /// Id of the attachment, if present. Use the documents endpoint to get a copy of the file.
     #[serde(default)]
     pub attachment_id: Option<String>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Update a holding merge.
@@ -958,6 +1344,10 @@ impl<'a> ApiEndpoint<'a> for HoldingMergesUpdate {
     fn url_path(parameters: &'a Self::Parameters) -> Self::UrlDisplay {
         HoldingMergesUpdateUrlDisplay(parameters)
     }
+
+    fn validate(parameters: &Self::Parameters) -> Result<(), ValidationError> {
+        parameters.validate()
+    }
 }
 
 pub struct HoldingMergesUpdateUrlDisplay<'a>(&'a HoldingMergesUpdateParameters);
@@ -1017,6 +1407,10 @@ pub struct HoldingMergesUpdateParameters {
 #[derive(Debug, Clone, Deserialize)]
 pub struct HoldingMergesUpdateSuccess {
     pub holding_merge: HoldingMergesUpdateHoldingMergeSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1027,6 +1421,10 @@ pub struct HoldingMergesUpdateHoldingMergeSuccess {
     pub id: i64,
     /// List of both trades created, cancel- and buy-trade
     pub trades: Vec<HoldingMergesUpdateHoldingMergeTradesSuccess>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1083,15 +1481,17 @@ pub struct HoldingMergesUpdateHoldingMergeTradesSuccess {
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub instrument_id: i64,
     /// The state of the trade, can be any of "confirmed", "unconfirmed" or "rejected".
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: TradeState,
     /// Filename of attachmented file, if present.
     #[serde(default)]
     pub attachment_filename: Option<String>,
     /// Id of the attachment, if present. Use the documents endpoint to get a copy of the file.
     #[serde(default)]
     pub attachment_id: Option<String>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Returns trade transactions for a holding.
@@ -1140,78 +1540,13 @@ pub struct HoldingTradesSuccess {
     pub trades: Vec<String>,
     /// The current API Transaction.
     pub api_transaction: HoldingTradesApiTransactionSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct HoldingTradesTradesSuccess {
-    /// The trade ID. Maybe nil if the trade is based on an adjustment and has not yet been confirmed by the user.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub id: String,
-    /// A unique identifier associated with this trade
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub unique_identifier: String,
-    /// The trade date (format YYYY-MM-DD).
-    #[serde_as(as = "DeserializeDate")]
-    pub transaction_date: NaiveDate,
-    /// Number of shares sold/bought.
-    pub quantity: Number,
-    /// Price paid/received.
-    pub price: Number,
-    /// For an opening balance, the cost base of the trade. Always returned in the portfolio currency
-    pub cost_base: Number,
-    /// The trade's exchange rate as portfolio currency / instrument currency.
-    pub exchange_rate: Number,
-    /// The trade's brokerage.
-    pub brokerage: Number,
-    /// The ISO code of the brokerage currency, must be either Portfolio or Instrument currency. If the instrument is a cryptocurrency, any valid brokerage currency is supported.
-    pub brokerage_currency_code: Currency,
-    /// The value for the trade as displayed in the 'value' column of the UI. For a return of capital, this will be the (signed) capital return value. For a capital call, this will be the (positive) capital return value. For a cost base adjustment, this will be the value of the adjustment. For an opening balance, this will be the market value: the market price x quantity at the opening balance date In each case this is in portfolio currency (rounded to 2 decimal places).
-    pub value: Number,
-    /// For a CAPITAL_RETURN or other trade with a linked payout, this is the paid on date
-    #[serde_as(as = "DeserializeDate")]
-    pub paid_on: NaiveDate,
-    /// The company event linked to the transaction.
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub company_event_id: Option<i64>,
-    /// Any comments for that trade.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub comments: String,
-    /// Portfolio ID of the trade.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub portfolio_id: i64,
-    /// Holding ID of the trade.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub holding_id: i64,
-    /// The state of the trade, can be any of "confirmed", "unconfirmed" or "rejected".
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
-    /// The trade type ('BUY','SELL','SPLIT','BONUS','CONSOLD','CANCEL','CAPITAL_RETURN','OPENING_BALANCE','ADJUST_COST_BASE','CAPITAL_CALL').
-    pub transaction_type: TradeDescription,
-    /// Instrument ID of the related Holding.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub instrument_id: i64,
-    /// The instrument code/symbol
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub symbol: String,
-    /// The market code (eg. `ASX`, `NZX`, etc).
-    pub market: Market,
-    /// The filename of any attachment
-    #[serde(default)]
-    pub attachment_filename: Option<String>,
-    /// The document id of any attachment, for use with the Show Document API (v2)
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub attachment_id: Option<i64>,
-    /// Returns 'true' if trade is confirmed.  DEPRECATED: Use the state field to determine 'confirmed' vs. 'rejected' vs. 'unconfirmed' instead.
-    pub confirmed: bool,
-}
+pub type HoldingTradesTradesSuccess = Trade;
 
 #[serde_as]
 #[derive(Debug, Clone, Deserialize)]
@@ -1230,6 +1565,10 @@ pub struct HoldingTradesApiTransactionSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub timestamp: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Returns reject trade transactions for the holding.
@@ -1279,78 +1618,13 @@ pub struct HoldingTradesRejectedSuccess {
     pub trades: Vec<String>,
     /// The current API Transaction.
     pub api_transaction: HoldingTradesRejectedApiTransactionSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct HoldingTradesRejectedTradesSuccess {
-    /// The trade ID. Maybe nil if the trade is based on an adjustment and has not yet been confirmed by the user.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub id: String,
-    /// A unique identifier associated with this trade
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub unique_identifier: String,
-    /// The trade date (format YYYY-MM-DD).
-    #[serde_as(as = "DeserializeDate")]
-    pub transaction_date: NaiveDate,
-    /// Number of shares sold/bought.
-    pub quantity: Number,
-    /// Price paid/received.
-    pub price: Number,
-    /// For an opening balance, the cost base of the trade. Always returned in the portfolio currency
-    pub cost_base: Number,
-    /// The trade's exchange rate as portfolio currency / instrument currency.
-    pub exchange_rate: Number,
-    /// The trade's brokerage.
-    pub brokerage: Number,
-    /// The ISO code of the brokerage currency, must be either Portfolio or Instrument currency. If the instrument is a cryptocurrency, any valid brokerage currency is supported.
-    pub brokerage_currency_code: Currency,
-    /// The value for the trade as displayed in the 'value' column of the UI. For a return of capital, this will be the (signed) capital return value. For a capital call, this will be the (positive) capital return value. For a cost base adjustment, this will be the value of the adjustment. For an opening balance, this will be the market value: the market price x quantity at the opening balance date In each case this is in portfolio currency (rounded to 2 decimal places).
-    pub value: Number,
-    /// For a CAPITAL_RETURN or other trade with a linked payout, this is the paid on date
-    #[serde_as(as = "DeserializeDate")]
-    pub paid_on: NaiveDate,
-    /// The company event linked to the transaction.
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub company_event_id: Option<i64>,
-    /// Any comments for that trade.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub comments: String,
-    /// Portfolio ID of the trade.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub portfolio_id: i64,
-    /// Holding ID of the trade.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub holding_id: i64,
-    /// The state of the trade, can be any of "confirmed", "unconfirmed" or "rejected".
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
-    /// The trade type ('BUY','SELL','SPLIT','BONUS','CONSOLD','CANCEL','CAPITAL_RETURN','OPENING_BALANCE','ADJUST_COST_BASE','CAPITAL_CALL').
-    pub transaction_type: TradeDescription,
-    /// Instrument ID of the related Holding.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub instrument_id: i64,
-    /// The instrument code/symbol
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub symbol: String,
-    /// The market code (eg. `ASX`, `NZX`, etc).
-    pub market: Market,
-    /// The filename of any attachment
-    #[serde(default)]
-    pub attachment_filename: Option<String>,
-    /// The document id of any attachment, for use with the Show Document API (v2)
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub attachment_id: Option<i64>,
-    /// Returns 'true' if trade is confirmed.  DEPRECATED: Use the state field to determine 'confirmed' vs. 'rejected' vs. 'unconfirmed' instead.
-    pub confirmed: bool,
-}
+pub type HoldingTradesRejectedTradesSuccess = Trade;
 
 #[serde_as]
 #[derive(Debug, Clone, Deserialize)]
@@ -1369,6 +1643,10 @@ pub struct HoldingTradesRejectedApiTransactionSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub timestamp: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This takes a Google id token, from an approved mobile app. The token will be validated. If the email matches one registered with Sharesight (as the primary or Google ID email) then an access_token will be returned. Otherwise, a 404 error will be generated.
@@ -1418,6 +1696,10 @@ pub struct IdentityByTokenSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub token_type: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This takes a Google id token, from an approved mobile app. The token will be validated. A new Sharesight account (with no holdings) will be created. If the email matches one registered with Sharesight (as the primary or Google ID email) then an error will be returned. The access and refresh tokens will be returned. (Note that the user must create a holding before the account becomes useful).
@@ -1469,6 +1751,10 @@ pub struct IdentitySignupByTokenSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub token_type: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Returns a list of instruments in the current user's portfolios.
@@ -1492,6 +1778,10 @@ impl<'a> ApiEndpoint<'a> for ListUserInstruments {
 pub struct ListUserInstrumentsSuccess {
     /// List of instruments.
     pub instruments: Vec<ListUserInstrumentsInstrumentsSuccess>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1545,6 +1835,10 @@ pub struct ListUserInstrumentsInstrumentsSuccess {
     /// The instrument registry.
     #[serde(default)]
     pub registry_name: Option<String>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Create a new membership for a user's portfolio, using an existing user id or by creating a new user (providing email, etc.).
@@ -1571,6 +1865,7 @@ pub struct MembershipCreateParameters {
     pub membership: MembershipCreateMembershipParameters,
     /// Details about the new user to create. You have to provide the user_id or a user hash.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<MembershipCreateUserParameters>,
     /// Details about the invitation created together with the membership
     pub invitation: MembershipCreateInvitationParameters,
@@ -1583,12 +1878,11 @@ pub struct MembershipCreateMembershipParameters {
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub portfolio_id: i64,
     /// Access level (one of NONE, READ, EDIT, ADMIN)
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub access_code: String,
+    pub access_code: AccessCode,
     /// User ID to use for the new membership. You have to provide the user_id or a user hash.
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<i64>,
 }
 
@@ -1614,9 +1908,11 @@ pub struct MembershipCreateUserParameters {
 pub struct MembershipCreateInvitationParameters {
     /// Invitation text sent to the new member. No HTML tags are allowed here but line-breaks are converted into paragraphs and HTML line breaks (e.g. "\n" into `\>br /\<` and "\n\n" into wrapping `\>p\<...\>/p\<` elements).
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
     /// Set to true if you don't want an invitation email being sent to the new member.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_email: Option<bool>,
 }
 
@@ -1627,9 +1923,7 @@ pub struct MembershipCreateSuccess {
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub id: i64,
     /// Access level (one of NONE, READ, EDIT, ADMIN)
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub access_code: String,
+    pub access_code: AccessCode,
     /// The portfolio ID.
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub portfolio_id: i64,
@@ -1649,6 +1943,10 @@ pub struct MembershipCreateSuccess {
     pub invitation: MembershipCreateInvitationSuccess,
     /// List of links for this membership
     pub links: MembershipCreateLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1666,6 +1964,10 @@ pub struct MembershipCreateUserSuccess {
     /// The user's email.
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub email: i64,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1686,6 +1988,10 @@ pub struct MembershipCreateInvitationSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub url: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1695,6 +2001,10 @@ pub struct MembershipCreateLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Delete an existing user membership.
@@ -1768,6 +2078,10 @@ pub struct MembershipListSuccess {
     pub shared_with_organisation: bool,
     /// List of links for this resource
     pub links: MembershipListLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1777,9 +2091,7 @@ pub struct MembershipListMembershipsSuccess {
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub id: i64,
     /// Access level (one of NONE, READ, EDIT, ADMIN, OWNER)
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub access_code: String,
+    pub access_code: AccessCode,
     /// The portfolio ID.
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub portfolio_id: i64,
@@ -1794,6 +2106,10 @@ pub struct MembershipListMembershipsSuccess {
     pub invitation: MembershipListMembershipsInvitationSuccess,
     /// List of links for this membership
     pub links: MembershipListMembershipsLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1814,6 +2130,10 @@ pub struct MembershipListMembershipsUserSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub email: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1825,6 +2145,10 @@ pub struct MembershipListPortfoliosSuccess {
     /// Notifications for Trade Confirmation Emails.  One of: 0 (no emails), 1 (all emails), 2 (email errors only).
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub contract_note_email_notification: i64,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1845,6 +2169,10 @@ pub struct MembershipListMembershipsInvitationSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub status: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1854,6 +2182,10 @@ pub struct MembershipListMembershipsLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1864,6 +2196,10 @@ pub struct MembershipListLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub itself: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Update an existing user membership.
@@ -1901,9 +2237,7 @@ pub struct MembershipUpdateParameters {
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub id: i64,
     /// Access level (one of NONE, READ, EDIT, ADMIN)
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub access_code: String,
+    pub access_code: AccessCode,
 }
 
 #[serde_as]
@@ -1913,9 +2247,7 @@ pub struct MembershipUpdateSuccess {
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub id: i64,
     /// Access level (one of NONE, READ, EDIT, ADMIN)
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub access_code: String,
+    pub access_code: AccessCode,
     /// The portfolio ID.
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub portfolio_id: i64,
@@ -1930,6 +2262,10 @@ pub struct MembershipUpdateSuccess {
     pub invitation: MembershipUpdateInvitationSuccess,
     /// List of links for this membership
     pub links: MembershipUpdateLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1950,6 +2286,10 @@ pub struct MembershipUpdateUserSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub email: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1973,6 +2313,10 @@ pub struct MembershipUpdateInvitationSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub invitation_path: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -1982,6 +2326,10 @@ pub struct MembershipUpdateLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Access currency definitions
@@ -2005,6 +2353,10 @@ impl<'a> ApiEndpoint<'a> for Currencies {
 pub struct CurrenciesSuccess {
     /// List of currency definitions
     pub currencies: Vec<CurrenciesCurrenciesSuccess>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2032,6 +2384,10 @@ pub struct CurrenciesCurrenciesSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub source_feeds: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Returns payouts for the user’s holding between the two supplied dates.
@@ -2076,7 +2432,7 @@ pub struct ListHoldingPayoutsParameters {
     pub end_date: Option<NaiveDate>,
     /// Which payout date to use when filtering. Allowed values: `"paid_on"`, `"ex_date"`.
     #[serde(default)]
-    pub use_date: Option<String>,
+    pub use_date: Option<UseDate>,
 }
 
 #[serde_as]
@@ -2086,6 +2442,10 @@ pub struct ListHoldingPayoutsSuccess {
     pub payouts: Vec<ListHoldingPayoutsPayoutsSuccess>,
     /// List of links for this resource
     pub links: ListHoldingPayoutsLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2143,9 +2503,7 @@ pub struct ListHoldingPayoutsPayoutsSuccess {
     #[serde(default)]
     pub company_event_id: Option<i64>,
     /// The state of the payout, can be any of `"confirmed"`, `"unconfirmed"` or `"rejected"`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: PayoutState,
     /// Parameters when the payout is reinvested.
     #[serde(default)]
     pub drp_trade_attributes: Option<ListHoldingPayoutsPayoutsDrpTradeAttributesSuccess>,
@@ -2190,6 +2548,10 @@ pub struct ListHoldingPayoutsPayoutsSuccess {
     pub amit_increase_amount: Option<Number>,
     /// List of links for this payout
     pub links: ListHoldingPayoutsPayoutsLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2205,6 +2567,10 @@ pub struct ListHoldingPayoutsPayoutsDrpTradeAttributesSuccess {
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
     pub source_adjustment_id: Option<i64>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2214,6 +2580,10 @@ pub struct ListHoldingPayoutsPayoutsLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2224,6 +2594,10 @@ pub struct ListHoldingPayoutsLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub itself: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Returns payouts for the user’s portfolio between the two supplied dates.
@@ -2268,7 +2642,7 @@ pub struct ListPortfolioPayoutsParameters {
     pub end_date: Option<NaiveDate>,
     /// Which payout date to use when filtering. Allowed values: `"paid_on"`, `"ex_date"`.
     #[serde(default)]
-    pub use_date: Option<String>,
+    pub use_date: Option<UseDate>,
 }
 
 #[serde_as]
@@ -2278,6 +2652,10 @@ pub struct ListPortfolioPayoutsSuccess {
     pub payouts: Vec<ListPortfolioPayoutsPayoutsSuccess>,
     /// List of links for this resource
     pub links: ListPortfolioPayoutsLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2340,9 +2718,7 @@ pub struct ListPortfolioPayoutsPayoutsSuccess {
     #[serde(default)]
     pub company_event_id: Option<i64>,
     /// The state of the payout, can be any of `"confirmed"`, `"unconfirmed"` or `"rejected"`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: PayoutState,
     /// Parameters when the payout is reinvested.
     #[serde(default)]
     pub drp_trade_attributes: Option<ListPortfolioPayoutsPayoutsDrpTradeAttributesSuccess>,
@@ -2384,6 +2760,10 @@ pub struct ListPortfolioPayoutsPayoutsSuccess {
     pub amit_increase_amount: Option<Number>,
     /// List of links for this payout
     pub links: ListPortfolioPayoutsPayoutsLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2399,6 +2779,10 @@ pub struct ListPortfolioPayoutsPayoutsDrpTradeAttributesSuccess {
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
     pub source_adjustment_id: Option<i64>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2408,6 +2792,10 @@ pub struct ListPortfolioPayoutsPayoutsLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2418,6 +2806,10 @@ pub struct ListPortfolioPayoutsLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub itself: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Confirm a payout. Tnis can be either a payout based on a company event or a payout based on the payment date of an interest payment.
@@ -2454,101 +2846,51 @@ pub struct PayoutConfirmPayoutParameters {
     pub state: String,
     /// Parameters when the payout is reinvested.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub drp_trade_attributes: Option<PayoutConfirmPayoutDrpTradeAttributesParameters>,
 }
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize)]
-pub struct PayoutConfirmPayoutDrpTradeAttributesParameters {
-    /// `true` for a reinvested payout.
-    #[serde(default)]
-    pub dividend_reinvested: Option<bool>,
-    /// How many units are reinvested.
-    #[serde(default)]
-    pub quantity: Option<Number>,
-    /// Price per reinvested unit.
-    #[serde(default)]
-    pub price: Option<Number>,
-    /// ID of any source adjustment for the reinvested amount (same as `company_event_id`).
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub source_adjustment_id: Option<i64>,
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Serialize)]
-pub struct PayoutConfirmParameters {
-    pub payout: PayoutConfirmPayoutParameters,
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct PayoutConfirmSuccess {
-    /// The confirmed payout.
-    pub payout: PayoutConfirmPayoutSuccess,
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct PayoutConfirmPayoutSuccess {
-    /// The payout ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub id: i64,
-    /// The portfolio ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub portfolio_id: i64,
-    /// The holding ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub holding_id: i64,
-    /// The instrument ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub instrument_id: i64,
-    /// Payout company/instrument symbol on the market.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub symbol: String,
-    /// Market code.
-    pub market: Market,
-    /// The payout date (format `YYYY-MM-DD`).
-    #[serde_as(as = "DeserializeDate")]
-    pub paid_on: NaiveDate,
-    /// The payout ex date (format `YYYY-MM-DD`).
-    #[serde_as(as = "Option<DeserializeDate>")]
-    #[serde(default)]
-    pub ex_date: Option<NaiveDate>,
-    /// The payout amount.
-    pub amount: Number,
-    /// The calculated gross amount
-    pub gross_amount: Number,
-    /// The payout resident withholding tax amount. Always returned in the portfolio currency.
-    #[serde(default)]
-    pub resident_withholding_tax: Option<Number>,
-    /// The payout non-resident withholding tax amount.
+pub struct PayoutConfirmPayoutDrpTradeAttributesParameters {
+    /// `true` for a reinvested payout.
     #[serde(default)]
-    pub non_resident_withholding_tax: Option<Number>,
-    /// The payout tax credit amount. Always returned in the portfolio currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dividend_reinvested: Option<bool>,
+    /// How many units are reinvested.
     #[serde(default)]
-    pub tax_credit: Option<Number>,
-    /// Payout currency code, using 3-letter ISO 4217 code.
-    pub currency: Currency,
-    /// The payout's exchange rate.
-    pub exchange_rate: Number,
-    /// If `true`, payout is non taxable.
-    pub non_taxable: bool,
-    /// Any comments for that payout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<Number>,
+    /// Price per reinvested unit.
     #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub comments: String,
-    /// ID of the company event the given payout is based on (nil if not based on any).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Number>,
+    /// ID of any source adjustment for the reinvested amount (same as `company_event_id`).
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
-    pub company_event_id: Option<i64>,
-    /// The state of the payout, can be any of `"confirmed"`, `"unconfirmed"` or `"rejected"`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_adjustment_id: Option<i64>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct PayoutConfirmParameters {
+    pub payout: PayoutConfirmPayoutParameters,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct PayoutConfirmSuccess {
+    /// The confirmed payout.
+    pub payout: PayoutConfirmPayoutSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+pub type PayoutConfirmPayoutSuccess = Payout;
+
 /// Creates a new payout for this portfolio.
 pub struct PayoutCreate;
 
@@ -2563,6 +2905,10 @@ impl<'a> ApiEndpoint<'a> for PayoutCreate {
     fn url_path(_parameters: &'a Self::Parameters) -> Self::UrlDisplay {
         "/payouts"
     }
+
+    fn validate(parameters: &Self::Parameters) -> Result<(), ValidationError> {
+        parameters.validate()
+    }
 }
 
 #[serde_as]
@@ -2577,16 +2923,20 @@ pub struct PayoutCreatePayoutParameters {
     /// Portfolio ID to create the payout for. This needs to be specified together with market and symbol, unless you specify a holding_id (for an existing Holding).
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub portfolio_id: Option<i64>,
     /// Holding ID to create the trade for. If you include this, you do not need to specify portfolio_id, market and symbol parameters.
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub holding_id: Option<i64>,
     /// Payout company/instrument symbol on the market. This is not mandatory if holding_id for an existing Holding is specified.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub symbol: Option<String>,
     /// Market code (like `"NZX"` or `"ASX"`). This is not mandatory if holding_id for an existing Holding is specified.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub market: Option<Market>,
     /// The date of the payout (format `YYYY-MM-DD`).
     #[serde_as(as = "DeserializeDate")]
@@ -2598,85 +2948,112 @@ pub struct PayoutCreatePayoutParameters {
     /// The ex date for the payout (format `YYYY-MM-DD`).
     #[serde_as(as = "Option<DeserializeDate>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub goes_ex_on: Option<NaiveDate>,
     /// Resident withholding tax for the payout.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub resident_withholding_tax: Option<Number>,
     /// Non-resident withholding tax for the payout
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub non_resident_withholding_tax: Option<Number>,
     /// Tax credit for the payout.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_credit: Option<Number>,
     /// Exchange rate for other currency payout.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub exchange_rate: Option<Number>,
     /// The ID of any adjustment.
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub adjustment_id: Option<i64>,
     /// Any comments to be saved on the payout.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<String>,
     /// `true` if this payout is non-taxable.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub non_taxable: Option<bool>,
     /// Date of the source payment
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source_payment_date: Option<String>,
     /// If `true`, sync this payout to xero
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub send_to_xero: Option<bool>,
     /// Banked amount for this payout.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub banked_amount: Option<Number>,
     /// Parameters when the payout is reinvested.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub drp_trade_attributes: Option<PayoutCreatePayoutDrpTradeAttributesParameters>,
     /// Franked amount in the payout. (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub franked_amount: Option<Number>,
     /// Unfranked amount in the payout (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unfranked_amount: Option<Number>,
     /// `true` if this payout is for a trust. (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub trust: Option<bool>,
     /// Extra interest amount in this payout. (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub extra_interest_payment_amount: Option<Number>,
     /// Capital gain amount in this payout. (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub capital_gains: Option<Number>,
     /// Discounted capital gain amount in this payout. (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub discounted_capital_gains: Option<Number>,
     /// Amount of foreign income in this payout. (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foreign_source_income: Option<Number>,
     /// The amount of an LIC dividend that is attributable to an LIC capital gain. (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lic_capital_gain: Option<Number>,
     /// Any non-tax assessable amount.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub non_assessable: Option<Number>,
     /// Value of deferred income in this payout. (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deferred_income: Option<Number>,
     /// Value of CGT concession in this payout. (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cgt_concession_amount: Option<Number>,
     /// Relevant for attribution managed investment trusts (AMIT) when the taxable income attributed to you is less than the cash distribution you received. This amount is non-assessable and is used to decrease your cost base for cgt purposes (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub amit_decrease_amount: Option<Number>,
     /// Relevant for attribution managed investment trusts (AMIT) when the taxable income attributed to you is more than the cash distribution you received. This amount is non-assessable and is used to increase your cost base for cgt purposes (Australia only)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub amit_increase_amount: Option<Number>,
     /// File name for the attachment. This parameter is required if attachment is set.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_name: Option<String>,
     /// Base64 encoded attachment file to save against the payout.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_attachment: Option<String>,
 }
 
@@ -2685,16 +3062,20 @@ pub struct PayoutCreatePayoutParameters {
 pub struct PayoutCreatePayoutDrpTradeAttributesParameters {
     /// `true` for a reinvested payout.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dividend_reinvested: Option<bool>,
     /// How many units are reinvested.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub quantity: Option<Number>,
     /// Price per reinvested unit.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<Number>,
     /// ID of any source adjustment for the reinvested amount.
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source_adjustment_id: Option<i64>,
 }
 
@@ -2703,6 +3084,10 @@ pub struct PayoutCreatePayoutDrpTradeAttributesParameters {
 pub struct PayoutCreateSuccess {
     /// The new payout
     pub payout: PayoutCreatePayoutSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2765,9 +3150,7 @@ pub struct PayoutCreatePayoutSuccess {
     #[serde(default)]
     pub company_event_id: Option<i64>,
     /// The state of the payout, can be any of `"confirmed"`, `"unconfirmed"` or `"rejected"`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: PayoutState,
     /// Parameters when the payout is reinvested.
     #[serde(default)]
     pub drp_trade_attributes: Option<PayoutCreatePayoutDrpTradeAttributesSuccess>,
@@ -2815,6 +3198,10 @@ pub struct PayoutCreatePayoutSuccess {
     pub attachment_id: Option<String>,
     /// List of links for this payout
     pub links: PayoutCreatePayoutLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2830,6 +3217,10 @@ pub struct PayoutCreatePayoutDrpTradeAttributesSuccess {
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
     pub source_adjustment_id: Option<i64>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -2839,6 +3230,10 @@ pub struct PayoutCreatePayoutLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes a confirmed payout, leaving an unconfirmed payout derived from the linked corporate action. (This is similar to the 'Reset this payout') button in the UI). See Reject Payout in order to hide the corporate action altogether.
@@ -2880,6 +3275,10 @@ pub struct PayoutDeleteParameters {
 pub struct PayoutDeleteSuccess {
     /// The payout has been deleted.
     pub deleted: bool,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Rejects an unconfirmed payout (based on a corporate action event). A payout derived from the linked corporate action will no longer appear in the users portfolio. (This is similar to the 'Reject this Payout' button in the UI). See Delete Payout in order to delete a confirmed payout.
@@ -2918,68 +3317,13 @@ pub struct PayoutRejectParameters {
 pub struct PayoutRejectSuccess {
     /// The rejected payout.
     pub payout: PayoutRejectPayoutSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct PayoutRejectPayoutSuccess {
-    /// The payout ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub id: i64,
-    /// The portfolio ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub portfolio_id: i64,
-    /// The holding ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub holding_id: i64,
-    /// The instrument ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub instrument_id: i64,
-    /// Payout company/instrument symbol on the market.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub symbol: String,
-    /// Market code.
-    pub market: Market,
-    /// The payout date (format `YYYY-MM-DD`).
-    #[serde_as(as = "DeserializeDate")]
-    pub paid_on: NaiveDate,
-    /// The payout ex date (format `YYYY-MM-DD`).
-    #[serde_as(as = "Option<DeserializeDate>")]
-    #[serde(default)]
-    pub ex_date: Option<NaiveDate>,
-    /// The payout amount.
-    pub amount: Number,
-    /// The calculated gross amount
-    pub gross_amount: Number,
-    /// The payout resident withholding tax amount. Always returned in the portfolio currency.
-    #[serde(default)]
-    pub resident_withholding_tax: Option<Number>,
-    /// The payout non-resident withholding tax amount.
-    #[serde(default)]
-    pub non_resident_withholding_tax: Option<Number>,
-    /// The payout tax credit amount. Always returned in the portfolio currency.
-    #[serde(default)]
-    pub tax_credit: Option<Number>,
-    /// Payout currency code, using 3-letter ISO 4217 code.
-    pub currency: Currency,
-    /// The payout's exchange rate.
-    pub exchange_rate: Number,
-    /// If `true`, payout is non taxable.
-    pub non_taxable: bool,
-    /// Any comments for that payout.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub comments: String,
-    /// ID of the company event the given payout is based on (nil if not based on any).
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub company_event_id: Option<i64>,
-    /// The state of the payout, can be any of "confirmed", "unconfirmed" or "rejected".
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
-}
+pub type PayoutRejectPayoutSuccess = Payout;
 
 /// Returns a specific payout.
 pub struct PayoutShow;
@@ -3076,9 +3420,7 @@ pub struct PayoutShowSuccess {
     #[serde(default)]
     pub company_event_id: Option<i64>,
     /// The state of the payout, can be any of "confirmed", "unconfirmed" or "rejected".
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: PayoutState,
     /// Parameters when the payout is reinvested.
     #[serde(default)]
     pub drp_trade_attributes: Option<PayoutShowDrpTradeAttributesSuccess>,
@@ -3126,6 +3468,10 @@ pub struct PayoutShowSuccess {
     pub attachment_id: Option<String>,
     /// List of links for this payout
     pub links: PayoutShowLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -3141,6 +3487,10 @@ pub struct PayoutShowDrpTradeAttributesSuccess {
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
     pub source_adjustment_id: Option<i64>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -3155,6 +3505,10 @@ pub struct PayoutShowLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates a payout with the provided parameters
@@ -3171,6 +3525,10 @@ impl<'a> ApiEndpoint<'a> for PayoutUpdate {
     fn url_path(parameters: &'a Self::Parameters) -> Self::UrlDisplay {
         PayoutUpdateUrlDisplay(parameters)
     }
+
+    fn validate(parameters: &Self::Parameters) -> Result<(), ValidationError> {
+        parameters.validate()
+    }
 }
 
 pub struct PayoutUpdateUrlDisplay<'a>(&'a PayoutUpdateParameters);
@@ -3354,9 +3712,7 @@ pub struct PayoutUpdateSuccess {
     #[serde(default)]
     pub company_event_id: Option<i64>,
     /// The state of the payout, can be any of `"confirmed"`, `"unconfirmed"` or `"rejected"`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: PayoutState,
     /// Parameters when the payout is reinvested.
     #[serde(default)]
     pub drp_trade_attributes: Option<PayoutUpdateDrpTradeAttributesSuccess>,
@@ -3404,6 +3760,10 @@ pub struct PayoutUpdateSuccess {
     pub attachment_id: Option<String>,
     /// List of links for this payout
     pub links: PayoutUpdateLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -3419,6 +3779,10 @@ pub struct PayoutUpdateDrpTradeAttributesSuccess {
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
     pub source_adjustment_id: Option<i64>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -3428,6 +3792,10 @@ pub struct PayoutUpdateLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub portfolio: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Create a new portfolio for the current user.
@@ -3471,17 +3839,14 @@ pub struct PortfolioCreatePortfolioParameters {
     /// Default sale allocation method. See <a href="/api/2/codes#sale_allocation_method">valid methods by country</a>.
     pub default_sale_allocation_method: SaleAllocationMethod,
     /// Interest method calculation: `"simple"` or `"compound"`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub interest_method: String,
+    pub interest_method: InterestMethod,
     /// Tax Status (`true`: Trader, `false`: Investor)
     pub trader: bool,
     /// For Canadian portfolios, the type of tax processing (`"non_registered"`, `"rrsp"` or `"rrif"`)
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub tax_entity_type: String,
+    pub tax_entity_type: TaxEntityType,
     /// Disable Automatic Transactions for this portfolio (`true`: disable, `false`: enable)
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_automatic_transactions: Option<bool>,
     /// `1`: Individuals / Trust, `2`: Self Managed Super Fund, `3`: Company. Defaults to Individuals / Trust for AU portfolios.
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
@@ -3490,23 +3855,29 @@ pub struct PortfolioCreatePortfolioParameters {
     pub rwtr_rate: Number,
     /// ISO code of the portfolio country (see <a href="https://en.wikipedia.org/wiki/ISO_3166-1">ISO 3166-1</a>). Defaults to the portfolio owner's default country.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country_code: Option<Country>,
     /// `true` in order to calculate accrual adjustments against any portfolio cash accounts to allow for unsettled trades and unpaid dividends
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub apply_cash_account_adjustments: Option<bool>,
     /// Specifies the number of working days between the buy trade date and settlement in the cash account
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub buy_trade_settlement_delay: Option<i64>,
     /// Specifies the number of working days between the sell trade date and settlement in the cash account
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sell_trade_settlement_delay: Option<i64>,
     /// `true` in order to account for the fact that bank statement data is delayed by a day due to overnight processing
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub account_for_delayed_cash_transactions: Option<bool>,
     /// Typically used by professionals to identify the tax entity owner of the portfolio
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub external_identifier: Option<String>,
 }
 
@@ -3534,8 +3905,7 @@ pub struct PortfolioCreateSuccess {
     pub disable_automatic_transactions: bool,
     /// For Canadian portfolios, the type of tax processing (`"non_registered"`, `"rrsp"` or `"rrif"`). Can be `null`.
     #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub tax_entity_type: String,
+    pub tax_entity_type: Option<TaxEntityType>,
     /// `true` if the broker import email is enabled. Present for portfolio admins
     pub broker_email_api_enabled: bool,
     /// Email prefix of the broker import email address. Present for portfolio admins
@@ -3587,6 +3957,10 @@ pub struct PortfolioCreateSuccess {
     pub external_identifier: String,
     /// List of links for this portfolio
     pub links: PortfolioCreateLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -3601,29 +3975,54 @@ pub struct PortfolioCreateLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub itself: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Delete an existing user portfolio
 pub struct PortfolioDelete;
 
 impl<'a> ApiEndpoint<'a> for PortfolioDelete {
-    const URL_PATH: &'static str = "/portfolios/{id}.json";
+    const URL_PATH: &'static str = "/portfolios/:id.json";
     const HTTP_METHOD: ApiHttpMethod = ApiHttpMethod::Delete;
 
-    type UrlDisplay = &'static str;
-    type Parameters = ();
+    type UrlDisplay = PortfolioDeleteUrlDisplay<'a>;
+    type Parameters = PortfolioDeleteParameters;
     type Success = PortfolioDeleteSuccess;
 
-    fn url_path(_parameters: &'a Self::Parameters) -> Self::UrlDisplay {
-        "/portfolios/{id}.json"
+    fn url_path(parameters: &'a Self::Parameters) -> Self::UrlDisplay {
+        PortfolioDeleteUrlDisplay(parameters)
+    }
+}
+
+pub struct PortfolioDeleteUrlDisplay<'a>(&'a PortfolioDeleteParameters);
+
+impl<'a> fmt::Display for PortfolioDeleteUrlDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parameters = self.0;
+
+        write!(f, "/portfolios/{}.json", parameters.id)
     }
 }
 
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioDeleteParameters {
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub id: i64,
+}
+
 #[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 pub struct PortfolioDeleteSuccess {
     /// The portfolio was successfully deleted.
     pub status: (),
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Returns list of portfolios for the user. If the user owns the portfolio, all the info related to it will be displayed. Otherwise only basic info is returned.
@@ -3648,6 +4047,10 @@ pub struct PortfolioListSuccess {
     pub portfolios: Vec<PortfolioListPortfoliosSuccess>,
     /// List of links for this resource
     pub links: PortfolioListLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -3674,8 +4077,7 @@ pub struct PortfolioListPortfoliosSuccess {
     pub disable_automatic_transactions: bool,
     /// For Canadian portfolios, the type of tax processing (`"non_registered"`, `"rrsp"` or `"rrif"`). Can be `null`.
     #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub tax_entity_type: String,
+    pub tax_entity_type: Option<TaxEntityType>,
     /// `true` if the broker import email is enabled. Present for portfolio admins
     pub broker_email_api_enabled: bool,
     /// Email prefix of the broker import email address. Present for portfolio admins
@@ -3709,151 +4111,37 @@ pub struct PortfolioListPortfoliosSuccess {
     pub apply_cash_account_adjustments: bool,
     /// Specifies the number of working days between the buy trade date and settlement in the cash account. Can be `null`.
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub buy_trade_settlement_delay: Option<i64>,
-    /// Specifies the number of working days between the sell trade date and settlement in the cash account. Can be `null`.
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub sell_trade_settlement_delay: Option<i64>,
-    /// Accounts for the fact that bank statement data is delayed by a day due to overnight processing
-    pub account_for_delayed_cash_transactions: bool,
-    /// All buys and sells will generate a corresponding deposit/withdrawal in the selected 'trading' account. Can be `null`.
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub trade_sync_cash_account_id: Option<i64>,
-    /// All payouts will generate a corresponding deposit in the selected 'payout' account. Can be `null`.
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub payout_sync_cash_account_id: Option<i64>,
-    /// Typically used by professionals to identify the tax entity owner of the portfolio
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub external_identifier: String,
-    /// List of links for this portfolio
-    pub links: PortfolioListPortfoliosLinksSuccess,
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct PortfolioListPortfoliosLinksSuccess {
-    /// Url of this portfolio
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub portfolio: String,
-    /// Url of this portfolio
-    #[serde(rename = "self")]
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub itself: String,
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct PortfolioListLinksSuccess {
-    /// Url to list of portfolios
-    #[serde(rename = "self")]
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub itself: String,
-}
-
-/// Returns a single portfolio with settings for the user.
-pub struct PortfolioShow;
-
-impl<'a> ApiEndpoint<'a> for PortfolioShow {
-    const URL_PATH: &'static str = "/portfolios/{id}.json";
-    const HTTP_METHOD: ApiHttpMethod = ApiHttpMethod::Get;
-
-    type UrlDisplay = &'static str;
-    type Parameters = ();
-    type Success = PortfolioShowSuccess;
-
-    fn url_path(_parameters: &'a Self::Parameters) -> Self::UrlDisplay {
-        "/portfolios/{id}.json"
-    }
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct PortfolioShowSuccess {
-    /// The portfolio ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub id: i64,
-    /// The portfolio name.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub name: String,
-    /// The default sale allocation method for tax reporting.
-    pub default_sale_allocation_method: SaleAllocationMethod,
-    /// Discount for Capital Gains Tax.
-    #[serde(default)]
-    pub cg_discount: Option<String>,
-    /// New Zealand Resident Withholding Tax Rate. As a percentage to 1 decimal place (for example, 33.1% comes as `33.1`).
-    pub rwtr_rate: Number,
-    /// Tax Status (`true`: Trade, `false`: Investor). Can be `null`.
-    pub trader: bool,
-    /// Automatic Transactions are disabled (`true`) or enabled (`false`).
-    pub disable_automatic_transactions: bool,
-    /// For Canadian portfolios, the type of tax processing (`"non_registered"`, `"rrsp"` or `"rrif"`). Can be `null`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub tax_entity_type: String,
-    /// `true` if the broker import email is enabled. Present for portfolio admins
-    pub broker_email_api_enabled: bool,
-    /// Email prefix of the broker import email address. Present for portfolio admins
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub broker_email_key: String,
-    /// (deprecated) Financial Year end month (`1`: Jan, `2`: Feb, etc.).
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub financial_year_end_month_id: i64,
-    /// Financial Year end date MM-DD.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub financial_year_end: String,
-    /// Performance Calculation Method
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub interest_method: String,
-    /// ISO code of the portfolio country (see <a href="https://en.wikipedia.org/wiki/ISO_3166-1">ISO 3166-1</a>)
-    pub country_code: Country,
-    /// ISO code of the portfolio currency (see <a href="https://en.wikipedia.org/wiki/ISO_4217">ISO 4217</a>)
-    pub currency_code: Currency,
-    /// Portfolio's inception date (first trade record). Format: dd mmm yyyy
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub inception_date: String,
-    /// Time zone name
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub tz_name: String,
-    /// Calculates accrual adjustments against any portfolio cash accounts to allow for unsettled trades and unpaid dividends
-    pub apply_cash_account_adjustments: bool,
-    /// Specifies the number of working days between the buy trade date and settlement in the cash account. Can be `null`.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub buy_trade_settlement_delay: i64,
+    #[serde(default)]
+    pub buy_trade_settlement_delay: Option<i64>,
     /// Specifies the number of working days between the sell trade date and settlement in the cash account. Can be `null`.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub sell_trade_settlement_delay: i64,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub sell_trade_settlement_delay: Option<i64>,
     /// Accounts for the fact that bank statement data is delayed by a day due to overnight processing
     pub account_for_delayed_cash_transactions: bool,
     /// All buys and sells will generate a corresponding deposit/withdrawal in the selected 'trading' account. Can be `null`.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub trade_sync_cash_account_id: i64,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub trade_sync_cash_account_id: Option<i64>,
     /// All payouts will generate a corresponding deposit in the selected 'payout' account. Can be `null`.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub payout_sync_cash_account_id: i64,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub payout_sync_cash_account_id: Option<i64>,
     /// Typically used by professionals to identify the tax entity owner of the portfolio
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub external_identifier: String,
     /// List of links for this portfolio
-    pub links: PortfolioShowLinksSuccess,
+    pub links: PortfolioListPortfoliosLinksSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
 #[derive(Debug, Clone, Deserialize)]
-pub struct PortfolioShowLinksSuccess {
+pub struct PortfolioListPortfoliosLinksSuccess {
     /// Url of this portfolio
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
@@ -3863,21 +4151,86 @@ pub struct PortfolioShowLinksSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub itself: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct PortfolioListLinksSuccess {
+    /// Url to list of portfolios
+    #[serde(rename = "self")]
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub itself: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Returns a single portfolio with settings for the user.
+pub struct PortfolioShow;
+
+impl<'a> ApiEndpoint<'a> for PortfolioShow {
+    const URL_PATH: &'static str = "/portfolios/:id.json";
+    const HTTP_METHOD: ApiHttpMethod = ApiHttpMethod::Get;
+
+    type UrlDisplay = PortfolioShowUrlDisplay<'a>;
+    type Parameters = PortfolioShowParameters;
+    type Success = PortfolioShowSuccess;
+
+    fn url_path(parameters: &'a Self::Parameters) -> Self::UrlDisplay {
+        PortfolioShowUrlDisplay(parameters)
+    }
+}
+
+pub struct PortfolioShowUrlDisplay<'a>(&'a PortfolioShowParameters);
+
+impl<'a> fmt::Display for PortfolioShowUrlDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parameters = self.0;
+
+        write!(f, "/portfolios/{}.json", parameters.id)
+    }
 }
 
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioShowParameters {
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub id: i64,
+}
+
+pub type PortfolioShowSuccess = Portfolio;
+
+pub type PortfolioShowLinksSuccess = PortfolioLinks;
+
 /// Update an existing portfolio for the user.
 pub struct PortfolioUpdate;
 
 impl<'a> ApiEndpoint<'a> for PortfolioUpdate {
-    const URL_PATH: &'static str = "/portfolios/{id}.json";
+    const URL_PATH: &'static str = "/portfolios/:id.json";
     const HTTP_METHOD: ApiHttpMethod = ApiHttpMethod::Put;
 
-    type UrlDisplay = &'static str;
+    type UrlDisplay = PortfolioUpdateUrlDisplay<'a>;
     type Parameters = PortfolioUpdateParameters;
     type Success = PortfolioUpdateSuccess;
 
-    fn url_path(_parameters: &'a Self::Parameters) -> Self::UrlDisplay {
-        "/portfolios/{id}.json"
+    fn url_path(parameters: &'a Self::Parameters) -> Self::UrlDisplay {
+        PortfolioUpdateUrlDisplay(parameters)
+    }
+}
+
+pub struct PortfolioUpdateUrlDisplay<'a>(&'a PortfolioUpdateParameters);
+
+impl<'a> fmt::Display for PortfolioUpdateUrlDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parameters = self.0;
+
+        write!(f, "/portfolios/{}.json", parameters.id)
     }
 }
 
@@ -3885,6 +4238,8 @@ impl<'a> ApiEndpoint<'a> for PortfolioUpdate {
 #[derive(Debug, Clone, Serialize)]
 pub struct PortfolioUpdateParameters {
     pub portfolio: PortfolioUpdatePortfolioParameters,
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub id: i64,
 }
 
 #[serde_as]
@@ -3906,15 +4261,11 @@ pub struct PortfolioUpdatePortfolioParameters {
     /// Default sale allocation method. See <a href="/api/2/codes#sale_allocation_method">valid methods by country</a>.
     pub default_sale_allocation_method: SaleAllocationMethod,
     /// Interest method calculation: `"simple"` or `"compound"`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub interest_method: String,
+    pub interest_method: InterestMethod,
     /// Tax Status (`true`: Trader, `false`: Investor)
     pub trader: bool,
     /// For Canadian portfolios, the type of tax processing (`"non_registered"`, `"rrsp"` or `"rrif"`)
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub tax_entity_type: String,
+    pub tax_entity_type: TaxEntityType,
     /// Disable Automatic Transactions for this portfolio (`true`: disable, `false`: enable)
     #[serde(default)]
     pub disable_automatic_transactions: Option<bool>,
@@ -3953,97 +4304,9 @@ pub struct PortfolioUpdatePortfolioParameters {
     pub external_identifier: Option<String>,
 }
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct PortfolioUpdateSuccess {
-    /// The portfolio ID.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub id: i64,
-    /// The portfolio name.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub name: String,
-    /// The default sale allocation method for tax reporting.
-    pub default_sale_allocation_method: SaleAllocationMethod,
-    /// Discount for Capital Gains Tax.
-    #[serde(default)]
-    pub cg_discount: Option<String>,
-    /// New Zealand Resident Withholding Tax Rate. As a percentage to 1 decimal place (for example, 33.1% comes as `33.1`).
-    pub rwtr_rate: Number,
-    /// Tax Status (`true`: Trade, `false`: Investor). Can be `null`.
-    pub trader: bool,
-    /// Automatic Transactions are disabled (`true`) or enabled (`false`).
-    pub disable_automatic_transactions: bool,
-    /// For Canadian portfolios, the type of tax processing (`"non_registered"`, `"rrsp"` or `"rrif"`). Can be `null`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub tax_entity_type: String,
-    /// `true` if the broker import email is enabled. Present for portfolio admins
-    pub broker_email_api_enabled: bool,
-    /// Email prefix of the broker import email address. Present for portfolio admins
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub broker_email_key: String,
-    /// (deprecated) Financial Year end month (`1`: Jan, `2`: Feb, etc.).
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub financial_year_end_month_id: i64,
-    /// Financial Year end date MM-DD.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub financial_year_end: String,
-    /// Performance Calculation Method
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub interest_method: String,
-    /// ISO code of the portfolio country (see <a href="https://en.wikipedia.org/wiki/ISO_3166-1">ISO 3166-1</a>)
-    pub country_code: Country,
-    /// ISO code of the portfolio currency (see <a href="https://en.wikipedia.org/wiki/ISO_4217">ISO 4217</a>)
-    pub currency_code: Currency,
-    /// Portfolio's inception date (first trade record). Format: dd mmm yyyy
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub inception_date: String,
-    /// Time zone name
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub tz_name: String,
-    /// Calculates accrual adjustments against any portfolio cash accounts to allow for unsettled trades and unpaid dividends
-    pub apply_cash_account_adjustments: bool,
-    /// Specifies the number of working days between the buy trade date and settlement in the cash account. Can be `null`.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub buy_trade_settlement_delay: i64,
-    /// Specifies the number of working days between the sell trade date and settlement in the cash account. Can be `null`.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub sell_trade_settlement_delay: i64,
-    /// Accounts for the fact that bank statement data is delayed by a day due to overnight processing
-    pub account_for_delayed_cash_transactions: bool,
-    /// All buys and sells will generate a corresponding deposit/withdrawal in the selected 'trading' account. Can be `null`.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub trade_sync_cash_account_id: i64,
-    /// All payouts will generate a corresponding deposit in the selected 'payout' account. Can be `null`.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub payout_sync_cash_account_id: i64,
-    /// Typically used by professionals to identify the tax entity owner of the portfolio
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub external_identifier: String,
-    /// List of links for this portfolio
-    pub links: PortfolioUpdateLinksSuccess,
-}
+pub type PortfolioUpdateSuccess = Portfolio;
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct PortfolioUpdateLinksSuccess {
-    /// Url of this portfolio
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub portfolio: String,
-    /// Url of this portfolio
-    #[serde(rename = "self")]
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub itself: String,
-}
+pub type PortfolioUpdateLinksSuccess = PortfolioLinks;
 
 /// Return a report on capital gains tax (for Australian portfolios only)
 pub struct CapitalGains;
@@ -4137,6 +4400,10 @@ pub struct CapitalGainsSuccess {
     /// The portfolio id, as requested
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub portfolio_id: i64,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4157,6 +4424,10 @@ pub struct CapitalGainsDiscountedCapitalGainDistributionsSuccess {
     /// The date the gain is calculated for (format `YYYY-MM-DD`)
     #[serde_as(as = "DeserializeDate")]
     pub gain_date: NaiveDate,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4168,116 +4439,27 @@ pub struct CapitalGainsNonDiscountedCapitalGainDistributionsSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub symbol: String,
-    /// The name of the instrument
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub name: String,
-    /// The capital gain (negative for losses, rounded to 2 decimal places).
-    pub gain: Number,
-    /// The date the gain is calculated for (format `YYYY-MM-DD`)
-    #[serde_as(as = "DeserializeDate")]
-    pub gain_date: NaiveDate,
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct CapitalGainsShortTermParcelsSuccess {
-    /// The market symbol
-    pub market: Market,
-    /// The instrument symbol
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub symbol: String,
-    /// The name of the instrument
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub name: String,
-    /// The allocation method used: see <a href="/api/2/codes#sale_allocation_method">valid methods by country</a>
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub allocation_method: String,
-    /// The purchase date for this parcel (format `YYYY-MM-DD`).
-    #[serde_as(as = "DeserializeDate")]
-    pub purchase_date: NaiveDate,
-    /// The quantity of stock held
-    pub quantity: Number,
-    /// The adjusted total cost of the parcel (rounded to 2 decimal places).
-    pub cost_base: Number,
-    /// The market value of the parcel at the balance date (rounded to 2 decimal places).
-    pub market_value: Number,
-    /// The capital gain (negative for losses, rounded to 2 decimal places).
-    pub gain: Number,
-    /// The date the gain is calculated for (format `YYYY-MM-DD`)
-    #[serde_as(as = "DeserializeDate")]
-    pub gain_date: NaiveDate,
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct CapitalGainsLongTermParcelsSuccess {
-    /// The market symbol
-    pub market: Market,
-    /// The instrument symbol
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub symbol: String,
-    /// The name of the instrument
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub name: String,
-    /// The allocation method used: see <a href="/api/2/codes#sale_allocation_method">valid methods by country</a>
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub allocation_method: String,
-    /// The purchase date for this parcel (format `YYYY-MM-DD`).
-    #[serde_as(as = "DeserializeDate")]
-    pub purchase_date: NaiveDate,
-    /// The quantity of stock held
-    pub quantity: Number,
-    /// The adjusted total cost of the parcel (rounded to 2 decimal places).
-    pub cost_base: Number,
-    /// The market value of the parcel at the balance date (rounded to 2 decimal places).
-    pub market_value: Number,
-    /// The capital gain (negative for losses, rounded to 2 decimal places).
-    pub gain: Number,
-    /// The date the gain is calculated for (format `YYYY-MM-DD`)
-    #[serde_as(as = "DeserializeDate")]
-    pub gain_date: NaiveDate,
-}
-
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct CapitalGainsLossParcelsSuccess {
-    /// The market symbol
-    pub market: Market,
-    /// The instrument symbol
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub symbol: String,
-    /// The name of the instrument
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub name: String,
-    /// The allocation method used: see <a href="/api/2/codes#sale_allocation_method">valid methods by country</a>
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub allocation_method: String,
-    /// The purchase date for this parcel (format `YYYY-MM-DD`).
-    #[serde_as(as = "DeserializeDate")]
-    pub purchase_date: NaiveDate,
-    /// The quantity of stock held
-    pub quantity: Number,
-    /// The adjusted total cost of the parcel (rounded to 2 decimal places).
-    pub cost_base: Number,
-    /// The market value of the parcel at the balance date (rounded to 2 decimal places).
-    pub market_value: Number,
+    /// The name of the instrument
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub name: String,
     /// The capital gain (negative for losses, rounded to 2 decimal places).
     pub gain: Number,
     /// The date the gain is calculated for (format `YYYY-MM-DD`)
     #[serde_as(as = "DeserializeDate")]
     pub gain_date: NaiveDate,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+pub type CapitalGainsShortTermParcelsSuccess = Parcel;
+
+pub type CapitalGainsLongTermParcelsSuccess = Parcel;
+
+pub type CapitalGainsLossParcelsSuccess = Parcel;
+
 /// Retrieves the Diversity Report for the underlying portfolio
 pub struct Diversity;
 
@@ -4319,7 +4501,7 @@ pub struct DiversityParameters {
     pub date: Option<NaiveDate>,
     /// One of: market(s), industry_classification(s), sector_classification(s), investment_type(s), countries/country, portfolio, ungrouped or custom_group.
     #[serde(default)]
-    pub grouping: Option<String>,
+    pub grouping: Option<Grouping>,
     /// If present, the custom group id to group by, as an integer id returned from the CustomGroupsList endpoint. When this is used, the 'grouping' parameter must be set to 'custom_group'.
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
@@ -4338,6 +4520,10 @@ pub struct DiversitySuccess {
     /// The date this report was generated for (format `YYYY-MM-DD`).
     #[serde_as(as = "DeserializeDate")]
     pub date: NaiveDate,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4345,6 +4531,10 @@ pub struct DiversitySuccess {
 pub struct DiversityGroupsSuccess {
     /// The group's contents
     pub group: DiversityGroupsGroupSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4356,6 +4546,10 @@ pub struct DiversityGroupsGroupSuccess {
     pub percentage: Number,
     /// The value of the portfolio components in this group (rounded to 2 decimal places).
     pub value: Number,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4375,6 +4569,10 @@ pub struct DiversityGroupsGroupElementsSuccess {
     pub percentage: Number,
     /// The value of the holding or cash account (rounded to 2 decimal places).
     pub value: Number,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Retrieves the Performance Report for the underlying portfolio.
@@ -4437,7 +4635,7 @@ pub struct PerformanceParameters {
     pub include_sales: Option<bool>,
     /// One of: market(s), industry_classification(s), sector_classification(s), investment_type(s), countries/country, portfolio, ungrouped, or custom_group.
     #[serde(default)]
-    pub grouping: Option<String>,
+    pub grouping: Option<Grouping>,
     /// If present, the custom group id to group by, as an integer id returned from the CustomGroupsList endpoint. When this is used, the 'grouping' parameter must be set to 'custom_group'.
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
@@ -4494,6 +4692,10 @@ pub struct PerformanceSuccess {
     pub cash_accounts: Vec<PerformanceCashAccountsSuccess>,
     /// List of sub-totals for each group.
     pub sub_totals: Vec<PerformanceSubTotalsSuccess>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4543,6 +4745,10 @@ pub struct PerformanceHoldingsSuccess {
     pub total_gain: Number,
     /// Total Gain (percentage, rounded to 2 decimal places, 33% as `33.0`)<sup>1</sup> on the holding
     pub total_gain_percent: Number,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4566,6 +4772,10 @@ pub struct PerformanceCashAccountsSuccess {
     pub currency: Currency,
     /// The ISO currency code (e.g. AUD) of the portfolio
     pub currency_code: Currency,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4593,6 +4803,10 @@ pub struct PerformanceSubTotalsSuccess {
     pub total_gain: Number,
     /// Total Gain (percentage, rounded to 2 decimal places, 33% as `33.0`)<sup>1</sup> on the holdings in this group
     pub total_gain_percent: Number,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Return a report on unrealised capital gains tax (for Australian portfolios only)
@@ -4665,6 +4879,10 @@ pub struct UnrealisedCgtSuccess {
     /// The portfolio id, as requested
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub portfolio_id: i64,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4681,9 +4899,7 @@ pub struct UnrealisedCgtShortTermParcelsSuccess {
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub name: String,
     /// The allocation method used: see <a href="/api/2/codes#sale_allocation_method">valid methods by country</a>
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub allocation_method: String,
+    pub allocation_method: SaleAllocationMethod,
     /// The purchase date for this parcel (format `YYYY-MM-DD`)
     #[serde_as(as = "DeserializeDate")]
     pub purchase_date: NaiveDate,
@@ -4695,6 +4911,10 @@ pub struct UnrealisedCgtShortTermParcelsSuccess {
     pub market_value: Number,
     /// The unrealised capital gain (negative for losses, rounded to 2 decimal places).
     pub unrealised_gain: Number,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4711,9 +4931,7 @@ pub struct UnrealisedCgtLongTermParcelsSuccess {
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub name: String,
     /// The allocation method used: see <a href="/api/2/codes#sale_allocation_method">valid methods by country</a>
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub allocation_method: String,
+    pub allocation_method: SaleAllocationMethod,
     /// The purchase date for this parcel (format `YYYY-MM-DD`)
     #[serde_as(as = "DeserializeDate")]
     pub purchase_date: NaiveDate,
@@ -4725,6 +4943,10 @@ pub struct UnrealisedCgtLongTermParcelsSuccess {
     pub market_value: Number,
     /// The unrealised capital gain (negative for losses, rounded to 2 decimal places).
     pub unrealised_gain: Number,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4741,9 +4963,7 @@ pub struct UnrealisedCgtLossesSuccess {
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub name: String,
     /// The allocation method used: see <a href="/api/2/codes#sale_allocation_method">valid methods by country</a>
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub allocation_method: String,
+    pub allocation_method: SaleAllocationMethod,
     /// The purchase date for this parcel (format `YYYY-MM-DD`)
     #[serde_as(as = "DeserializeDate")]
     pub purchase_date: NaiveDate,
@@ -4755,6 +4975,10 @@ pub struct UnrealisedCgtLossesSuccess {
     pub market_value: Number,
     /// The unrealised capital gain (negative for losses, rounded to 2 decimal places).
     pub unrealised_gain: Number,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Retrieves the Valuation Report for the underlying portfolio.
@@ -4801,7 +5025,7 @@ pub struct ValuationParameters {
     pub include_sales: Option<bool>,
     /// One of: market(s), industry_classification(s), sector_classification(s), investment_type(s), countries/country, portfolio, ungrouped, or custom_group.
     #[serde(default)]
-    pub grouping: Option<String>,
+    pub grouping: Option<Grouping>,
     /// If present, the custom group id to group by, as an integer id returned from the CustomGroupsList endpoint. When this is used, the 'grouping' parameter must be set to 'custom_group'.
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
@@ -4837,6 +5061,10 @@ pub struct ValuationSuccess {
     pub cash_accounts: Vec<ValuationCashAccountsSuccess>,
     /// List of sub-totals for each group.
     pub sub_totals: Vec<ValuationSubTotalsSuccess>,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4870,6 +5098,10 @@ pub struct ValuationHoldingsSuccess {
     pub value: Number,
     /// The quantity of shares or other instruments in the holding
     pub quantity: Number,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4893,6 +5125,10 @@ pub struct ValuationCashAccountsSuccess {
     pub currency: Currency,
     /// The ISO currency code (e.g. AUD) of the portfolio
     pub currency_code: Currency,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -4904,6 +5140,10 @@ pub struct ValuationSubTotalsSuccess {
     pub _group_type_: String,
     /// The total value of the holdings in this group
     pub value: Number,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Single sign on authorisation. The single sign-on operation returns a URL that will allow the user to login to their Sharesight account without the need to enter their email address and password. The URL is valid for one minute. A single sign-on link or button in your application should be implemented so that the user click initiates this API call and then the URL returned is launched in the user’s browser. A "redirect_to" parameter can be appended to the login url. After successfully been logged in, the user will be then redirected to the specified redirect_to path; example: https://api.sharesight.com/users/sign_in?signon-token=token&amp;redirect_to=/portfolios/1
@@ -4929,6 +5169,10 @@ pub struct RequestSingleSignOnSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub login_url: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Confirm a trade. This can be either a trade based on a company event or a sell trade based on the maturity date of an interest instrument.
@@ -4970,6 +5214,10 @@ pub struct TradeConfirmParameters {
 pub struct TradeConfirmSuccess {
     /// The confirmed trade.
     pub trade: TradeConfirmTradeSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -5022,9 +5270,11 @@ pub struct TradeConfirmTradeSuccess {
     #[serde(default)]
     pub company_event_id: Option<i64>,
     /// The state of the trade, can be any of `"confirmed"`, `"unconfirmed"` or `"rejected"`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: TradeState,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Reject a trade. This can be either a trade based on a company event or a sell trade based on the maturity date of an interest instrument.
@@ -5066,6 +5316,10 @@ pub struct TradeRejectParameters {
 pub struct TradeRejectSuccess {
     /// The rejected trade.
     pub trade: TradeRejectTradeSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -5118,9 +5372,11 @@ pub struct TradeRejectTradeSuccess {
     #[serde(default)]
     pub company_event_id: Option<i64>,
     /// The state of the trade, can be any of `"confirmed"`, `"unconfirmed"` or `"rejected"`.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: TradeState,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Returns trade transactions for the user’s portfolio between the two supplied dates. If you want to only access the trades of a particular holding, use `https://api.sharesight.com/api/v2/holdings/:holding_id/trades.json`. The response will be the same.
@@ -5175,6 +5431,10 @@ pub struct TradesSuccess {
     pub trades: Vec<TradesTradesSuccess>,
     /// The current API Transaction.
     pub api_transaction: TradesApiTransactionSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -5225,9 +5485,7 @@ pub struct TradesTradesSuccess {
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub holding_id: i64,
     /// The state of the trade, can be any of "confirmed", "unconfirmed" or "rejected".
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: TradeState,
     /// The trade type ('BUY','SELL','SPLIT','BONUS','CONSOLD','CANCEL','CAPITAL_RETURN','OPENING_BALANCE','ADJUST_COST_BASE','CAPITAL_CALL').
     pub transaction_type: TradeDescription,
     /// Instrument ID of the related Holding.
@@ -5248,6 +5506,10 @@ pub struct TradesTradesSuccess {
     pub attachment_id: Option<i64>,
     /// Returns 'true' if trade is confirmed.  DEPRECATED: Use the state field to determine 'confirmed' vs. 'rejected' vs. 'unconfirmed' instead.
     pub confirmed: bool,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -5267,6 +5529,10 @@ pub struct TradesApiTransactionSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub timestamp: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Create a trade against a Portfolio or an existing Holding.
@@ -5283,6 +5549,24 @@ impl<'a> ApiEndpoint<'a> for TradesCreate {
     fn url_path(_parameters: &'a Self::Parameters) -> Self::UrlDisplay {
         "/trades.json"
     }
+
+    fn validate(parameters: &Self::Parameters) -> Result<(), ValidationError> {
+        parameters.validate()
+    }
+
+    fn validate_quantity(
+        parameters: &Self::Parameters,
+        decimal_markets: &[Market],
+    ) -> Result<(), ValidationError> {
+        parameters.validate_quantity(decimal_markets)
+    }
+
+    fn validate_brokerage_currency(
+        parameters: &Self::Parameters,
+        currencies: &[Currency],
+    ) -> Result<(), ValidationError> {
+        parameters.validate_brokerage_currency(currencies)
+    }
 }
 
 #[serde_as]
@@ -5297,74 +5581,97 @@ pub struct TradesCreateTradeParameters {
     /// Portfolio ID to create the trade against. Alternatively, you can pass a 'holding_id' and also avoid sending instrument lookup parameters.
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub portfolio_id: Option<i64>,
     /// Holding ID to create the trade for. If you specify this, you can avoid specifying 'portfolio_id' and instrument lookup parameters.
     #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub holding_id: Option<i64>,
     /// This is a string of up to 255 characters used to identify duplicate trades. Generate an identifier for each trade and Sharesight will check on upload that this trade has not already been loaded.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unique_identifier: Option<String>,
     /// For an confirm trade, ID used to identify the company event the unconfirmed trade is based on.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub company_event_id: Option<String>,
     /// For an confirm trade, the new state of the trade.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
     /// The transaction or trade date (format YYYY-MM-DD).
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_date: Option<String>,
     /// Number of units in the transaction. Must be a whole number unless the market allows fractional quantities to be traded.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub quantity: Option<Number>,
     /// Currency value per unit.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<Number>,
     /// For an opening balance, the cost base of the trade.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cost_base: Option<Number>,
     /// The exchange rate used for the transaction as portfolio currency / instrument currency.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub exchange_rate: Option<Number>,
     /// The brokerage fee (currency value).
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub brokerage: Option<Number>,
     /// The ISO code of the brokerage currency, must be either Portfolio or Instrument currency. If the instrument is a cryptocurrency, any valid brokerage currency is supported.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub brokerage_currency_code: Option<Currency>,
     /// When transaction_type "ADJUST_COST_BASE" was chosen, this is the required value
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub adjust_cost_base_value: Option<Number>,
     /// When transaction_type "CAPITAL_RETURN" or "CAPITAL_CALL" is chosen, this is the required value
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub capital_return_value: Option<Number>,
     /// When transaction_type "CAPITAL_RETURN" or "CAPITAL_CALL" is chosen, this is the paid on date
     #[serde_as(as = "Option<DeserializeDate>")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paid_on: Option<NaiveDate>,
     /// Comments against the trade.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<String>,
     /// An `instrument_id` to look up the Instrument.  Required unless you specify a `holding_id` or search by `instrument_code` and `instrument_market_code`.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub instrument_id: Option<String>,
     /// Code to look up the Instrument; must be accompanied by `market`. Required unless you specify a `holding_id`.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub symbol: Option<String>,
     /// Market Code to look up the Instrument; must be accompanied by `symbol`.  Required unless you specify a `holding_id`.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub market: Option<Market>,
     /// Market Country Code to look up the Instrument; must be accompanied by `symbol`.  Required unless you specify a `symbol` with `market`, or a `holding_id`.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub market_country_code: Option<String>,
     /// Supported transaction types: 'BUY','SELL','SPLIT','BONUS','CONSOLD','CANCEL','CAPITAL_RETURN','OPENING_BALANCE','ADJUST_COST_BASE','CAPITAL_CALL'.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_type: Option<TradeDescription>,
     /// Base64 encoded file to be attached to the trade.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attachment: Option<String>,
     /// File name for the attachment. This parameter is required if attachment is set.
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attachment_filename: Option<String>,
 }
 
@@ -5374,78 +5681,13 @@ pub struct TradesCreateSuccess {
     pub trade: TradesCreateTradeSuccess,
     /// The current API Transaction.
     pub api_transaction: TradesCreateApiTransactionSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct TradesCreateTradeSuccess {
-    /// The trade ID. Maybe nil if the trade is based on an adjustment and has not yet been confirmed by the user.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub id: String,
-    /// A unique identifier associated with this trade
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub unique_identifier: String,
-    /// The trade date (format YYYY-MM-DD).
-    #[serde_as(as = "DeserializeDate")]
-    pub transaction_date: NaiveDate,
-    /// Number of shares sold/bought.
-    pub quantity: Number,
-    /// Price paid/received.
-    pub price: Number,
-    /// For an opening balance, the cost base of the trade. Always returned in the portfolio currency
-    pub cost_base: Number,
-    /// The trade's exchange rate as portfolio currency / instrument currency.
-    pub exchange_rate: Number,
-    /// The trade's brokerage.
-    pub brokerage: Number,
-    /// The ISO code of the brokerage currency, must be either Portfolio or Instrument currency. If the instrument is a cryptocurrency, any valid brokerage currency is supported.
-    pub brokerage_currency_code: Currency,
-    /// The value for the trade as displayed in the 'value' column of the UI. For a return of capital, this will be the (signed) capital return value. For a capital call, this will be the (positive) capital return value. For a cost base adjustment, this will be the value of the adjustment. For an opening balance, this will be the market value: the market price x quantity at the opening balance date In each case this is in portfolio currency (rounded to 2 decimal places).
-    pub value: Number,
-    /// For a CAPITAL_RETURN or other trade with a linked payout, this is the paid on date
-    #[serde_as(as = "DeserializeDate")]
-    pub paid_on: NaiveDate,
-    /// The company event linked to the transaction.
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub company_event_id: Option<i64>,
-    /// Any comments for that trade.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub comments: String,
-    /// Portfolio ID of the trade.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub portfolio_id: i64,
-    /// Holding ID of the trade.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub holding_id: i64,
-    /// The state of the trade, can be any of "confirmed", "unconfirmed" or "rejected".
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
-    /// The trade type ('BUY','SELL','SPLIT','BONUS','CONSOLD','CANCEL','CAPITAL_RETURN','OPENING_BALANCE','ADJUST_COST_BASE','CAPITAL_CALL').
-    pub transaction_type: TradeDescription,
-    /// Instrument ID of the related Holding.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub instrument_id: i64,
-    /// The instrument code/symbol
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub symbol: String,
-    /// The market code (eg. `ASX`, `NZX`, etc).
-    pub market: Market,
-    /// The filename of any attachment
-    #[serde(default)]
-    pub attachment_filename: Option<String>,
-    /// The document id of any attachment, for use with the Show Document API (v2)
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub attachment_id: Option<i64>,
-    /// Returns 'true' if trade is confirmed.  DEPRECATED: Use the state field to determine 'confirmed' vs. 'rejected' vs. 'unconfirmed' instead.
-    pub confirmed: bool,
-}
+pub type TradesCreateTradeSuccess = Trade;
 
 #[serde_as]
 #[derive(Debug, Clone, Deserialize)]
@@ -5464,6 +5706,10 @@ pub struct TradesCreateApiTransactionSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub timestamp: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes a trade
@@ -5507,6 +5753,10 @@ pub struct TradesDestroySuccess {
     pub deleted: bool,
     /// The current API Transaction.
     pub api_transaction: TradesDestroyApiTransactionSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -5526,6 +5776,10 @@ pub struct TradesDestroyApiTransactionSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub timestamp: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Returns a trade transaction.
@@ -5609,9 +5863,7 @@ pub struct TradesShowSuccess {
     #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
     pub holding_id: i64,
     /// The state of the trade, can be any of "confirmed", "unconfirmed" or "rejected".
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
+    pub state: TradeState,
     /// The trade type ('BUY','SELL','SPLIT','BONUS','CONSOLD','CANCEL','CAPITAL_RETURN','OPENING_BALANCE','ADJUST_COST_BASE','CAPITAL_CALL').
     pub transaction_type: TradeDescription,
     /// Instrument ID of the related Holding.
@@ -5634,6 +5886,10 @@ pub struct TradesShowSuccess {
     pub confirmed: bool,
     /// The current API Transaction.
     pub api_transaction: TradesShowApiTransactionSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -5653,6 +5909,10 @@ pub struct TradesShowApiTransactionSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub timestamp: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Update an existing trade for the user. The trade is identified by the 'id'. Any parameter supplied will replace that field on the trade. Other fields will remain unchanged.
@@ -5669,6 +5929,24 @@ impl<'a> ApiEndpoint<'a> for TradesUpdate {
     fn url_path(parameters: &'a Self::Parameters) -> Self::UrlDisplay {
         TradesUpdateUrlDisplay(parameters)
     }
+
+    fn validate(parameters: &Self::Parameters) -> Result<(), ValidationError> {
+        parameters.validate()
+    }
+
+    fn validate_quantity(
+        parameters: &Self::Parameters,
+        decimal_markets: &[Market],
+    ) -> Result<(), ValidationError> {
+        parameters.validate_quantity(decimal_markets)
+    }
+
+    fn validate_brokerage_currency(
+        parameters: &Self::Parameters,
+        currencies: &[Currency],
+    ) -> Result<(), ValidationError> {
+        parameters.validate_brokerage_currency(currencies)
+    }
 }
 
 pub struct TradesUpdateUrlDisplay<'a>(&'a TradesUpdateParameters);
@@ -5756,78 +6034,13 @@ pub struct TradesUpdateSuccess {
     pub trade: TradesUpdateTradeSuccess,
     /// The current API Transaction.
     pub api_transaction: TradesUpdateApiTransactionSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[serde_as]
-#[derive(Debug, Clone, Deserialize)]
-pub struct TradesUpdateTradeSuccess {
-    /// The trade ID. Maybe nil if the trade is based on an adjustment and has not yet been confirmed by the user.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub id: String,
-    /// A unique identifier associated with this trade
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub unique_identifier: String,
-    /// The trade date (format YYYY-MM-DD).
-    #[serde_as(as = "DeserializeDate")]
-    pub transaction_date: NaiveDate,
-    /// Number of shares sold/bought.
-    pub quantity: Number,
-    /// Price paid/received.
-    pub price: Number,
-    /// For an opening balance, the cost base of the trade. Always returned in the portfolio currency
-    pub cost_base: Number,
-    /// The trade's exchange rate as portfolio currency / instrument currency.
-    pub exchange_rate: Number,
-    /// The trade's brokerage.
-    pub brokerage: Number,
-    /// The ISO code of the brokerage currency, must be either Portfolio or Instrument currency. If the instrument is a cryptocurrency, any valid brokerage currency is supported.
-    pub brokerage_currency_code: Currency,
-    /// The value for the trade as displayed in the 'value' column of the UI. For a return of capital, this will be the (signed) capital return value. For a capital call, this will be the (positive) capital return value. For a cost base adjustment, this will be the value of the adjustment. For an opening balance, this will be the market value: the market price x quantity at the opening balance date In each case this is in portfolio currency (rounded to 2 decimal places).
-    pub value: Number,
-    /// For a CAPITAL_RETURN or other trade with a linked payout, this is the paid on date
-    #[serde_as(as = "DeserializeDate")]
-    pub paid_on: NaiveDate,
-    /// The company event linked to the transaction.
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub company_event_id: Option<i64>,
-    /// Any comments for that trade.
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub comments: String,
-    /// Portfolio ID of the trade.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub portfolio_id: i64,
-    /// Holding ID of the trade.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub holding_id: i64,
-    /// The state of the trade, can be any of "confirmed", "unconfirmed" or "rejected".
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub state: String,
-    /// The trade type ('BUY','SELL','SPLIT','BONUS','CONSOLD','CANCEL','CAPITAL_RETURN','OPENING_BALANCE','ADJUST_COST_BASE','CAPITAL_CALL').
-    pub transaction_type: TradeDescription,
-    /// Instrument ID of the related Holding.
-    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
-    pub instrument_id: i64,
-    /// The instrument code/symbol
-    #[serde(default)]
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    pub symbol: String,
-    /// The market code (eg. `ASX`, `NZX`, etc).
-    pub market: Market,
-    /// The filename of any attachment
-    #[serde(default)]
-    pub attachment_filename: Option<String>,
-    /// The document id of any attachment, for use with the Show Document API (v2)
-    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
-    #[serde(default)]
-    pub attachment_id: Option<i64>,
-    /// Returns 'true' if trade is confirmed.  DEPRECATED: Use the state field to determine 'confirmed' vs. 'rejected' vs. 'unconfirmed' instead.
-    pub confirmed: bool,
-}
+pub type TradesUpdateTradeSuccess = Trade;
 
 #[serde_as]
 #[derive(Debug, Clone, Deserialize)]
@@ -5846,6 +6059,10 @@ pub struct TradesUpdateApiTransactionSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub timestamp: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Access information on the current user
@@ -5869,6 +6086,10 @@ impl<'a> ApiEndpoint<'a> for MyUser {
 pub struct MyUserSuccess {
     /// Information on the current user
     pub user: MyUserUserSuccess,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[serde_as]
@@ -5921,4 +6142,2669 @@ pub struct MyUserUserSuccess {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub signup_via_your_integration: String,
+    /// Fields returned by the API that aren't in the API documentation.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl TryFrom<TradesShowSuccess> for TradesUpdateParameters {
+    type Error = IntoParametersError;
+
+    fn try_from(value: TradesShowSuccess) -> Result<Self, Self::Error> {
+        Ok(TradesUpdateParameters {
+            id: value
+                .id
+                .parse()
+                .map_err(|e| IntoParametersError::Invalid("id", e))?,
+            trade: TradesUpdateTradeParameters {
+                transaction_date: Some(value.transaction_date.to_string()),
+                quantity: Some(value.quantity),
+                price: Some(value.price),
+                cost_base: Some(value.cost_base),
+                exchange_rate: Some(value.exchange_rate),
+                brokerage: Some(value.brokerage),
+                brokerage_currency_code: Some(value.brokerage_currency_code),
+                adjust_cost_base_value: value_for_types(
+                    value.transaction_type,
+                    &[TradeDescription::AdjustCostBase],
+                    &value.value,
+                ),
+                capital_return_value: value_for_types(
+                    value.transaction_type,
+                    &[
+                        TradeDescription::CapitalReturn,
+                        TradeDescription::CapitalCall,
+                    ],
+                    &value.value,
+                ),
+                paid_on: Some(value.paid_on),
+                comments: Some(value.comments),
+                instrument_id: Some(value.instrument_id.to_string()),
+                symbol: Some(value.symbol),
+                market: Some(value.market),
+                market_country_code: None,
+                transaction_type: Some(value.transaction_type),
+                attachment: None,
+                attachment_filename: value.attachment_filename,
+            },
+        })
+    }
+}
+
+impl TryFrom<TradesTradesSuccess> for TradesUpdateParameters {
+    type Error = IntoParametersError;
+
+    fn try_from(value: TradesTradesSuccess) -> Result<Self, Self::Error> {
+        Ok(TradesUpdateParameters {
+            id: value.id.ok_or(IntoParametersError::Missing("id"))?,
+            trade: TradesUpdateTradeParameters {
+                transaction_date: Some(value.transaction_date.to_string()),
+                quantity: Some(value.quantity),
+                price: Some(value.price),
+                cost_base: value.cost_base,
+                exchange_rate: Some(value.exchange_rate),
+                brokerage: Some(value.brokerage),
+                brokerage_currency_code: value.brokerage_currency_code,
+                adjust_cost_base_value: value_for_types(
+                    value.transaction_type,
+                    &[TradeDescription::AdjustCostBase],
+                    &value.value,
+                ),
+                capital_return_value: value_for_types(
+                    value.transaction_type,
+                    &[
+                        TradeDescription::CapitalReturn,
+                        TradeDescription::CapitalCall,
+                    ],
+                    &value.value,
+                ),
+                paid_on: value.paid_on,
+                comments: Some(value.comments),
+                instrument_id: Some(value.instrument_id.to_string()),
+                symbol: Some(value.symbol),
+                market: Some(value.market),
+                market_country_code: None,
+                transaction_type: Some(value.transaction_type),
+                attachment: None,
+                attachment_filename: value.attachment_filename,
+            },
+        })
+    }
+}
+
+impl TryFrom<Trade> for TradesUpdateParameters {
+    type Error = IntoParametersError;
+
+    fn try_from(value: Trade) -> Result<Self, Self::Error> {
+        Ok(TradesUpdateParameters {
+            id: value
+                .id
+                .parse()
+                .map_err(|e| IntoParametersError::Invalid("id", e))?,
+            trade: TradesUpdateTradeParameters {
+                transaction_date: Some(value.transaction_date.to_string()),
+                quantity: Some(value.quantity),
+                price: Some(value.price),
+                cost_base: Some(value.cost_base),
+                exchange_rate: Some(value.exchange_rate),
+                brokerage: Some(value.brokerage),
+                brokerage_currency_code: Some(value.brokerage_currency_code),
+                adjust_cost_base_value: value_for_types(
+                    value.transaction_type,
+                    &[TradeDescription::AdjustCostBase],
+                    &value.value,
+                ),
+                capital_return_value: value_for_types(
+                    value.transaction_type,
+                    &[
+                        TradeDescription::CapitalReturn,
+                        TradeDescription::CapitalCall,
+                    ],
+                    &value.value,
+                ),
+                paid_on: Some(value.paid_on),
+                comments: Some(value.comments),
+                instrument_id: Some(value.instrument_id.to_string()),
+                symbol: Some(value.symbol),
+                market: Some(value.market),
+                market_country_code: None,
+                transaction_type: Some(value.transaction_type),
+                attachment: None,
+                attachment_filename: value.attachment_filename,
+            },
+        })
+    }
+}
+
+impl From<PayoutShowDrpTradeAttributesSuccess> for PayoutUpdatePayoutDrpTradeAttributesParameters {
+    fn from(value: PayoutShowDrpTradeAttributesSuccess) -> Self {
+        PayoutUpdatePayoutDrpTradeAttributesParameters {
+            dividend_reinvested: Some(value.dividend_reinvested),
+            quantity: Some(value.quantity),
+            price: Some(value.price),
+            source_adjustment_id: value.source_adjustment_id,
+        }
+    }
+}
+
+impl From<PayoutShowSuccess> for PayoutUpdateParameters {
+    fn from(value: PayoutShowSuccess) -> Self {
+        PayoutUpdateParameters {
+            id: value.id,
+            payout: PayoutUpdatePayoutParameters {
+                paid_on: value.paid_on,
+                goes_ex_on: value.ex_date,
+                resident_withholding_tax: value.resident_withholding_tax,
+                non_resident_withholding_tax: value.non_resident_withholding_tax,
+                tax_credit: value.tax_credit,
+                exchange_rate: Some(value.exchange_rate),
+                amount: Some(value.amount),
+                adjustment_id: None,
+                comments: Some(value.comments),
+                non_taxable: Some(value.non_taxable),
+                currency_code: Some(value.currency),
+                source_payment_date: None,
+                send_to_xero: None,
+                banked_amount: None,
+                source_adjustment_id: None,
+                drp_trade_attributes: value.drp_trade_attributes.map(|v| v.into()),
+                franked_amount: value.franked_amount,
+                unfranked_amount: value.unfranked_amount,
+                trust: value.trust,
+                extra_interest_payment_amount: value.extra_interest_payment_amount,
+                capital_gains: value.capital_gains,
+                discounted_capital_gains: value.discounted_capital_gains,
+                foreign_source_income: value.foreign_source_income,
+                lic_capital_gain: Some(value.lic_capital_gain),
+                non_assessable: value.non_assessable,
+                deferred_income: value.deferred_income,
+                cgt_concession_amount: value.cgt_concession_amount,
+                amit_decrease_amount: value.amit_decrease_amount,
+                amit_increase_amount: value.amit_increase_amount,
+            },
+        }
+    }
+}
+
+impl From<ListPortfolioPayoutsPayoutsDrpTradeAttributesSuccess>
+    for PayoutUpdatePayoutDrpTradeAttributesParameters
+{
+    fn from(value: ListPortfolioPayoutsPayoutsDrpTradeAttributesSuccess) -> Self {
+        PayoutUpdatePayoutDrpTradeAttributesParameters {
+            dividend_reinvested: Some(value.dividend_reinvested),
+            quantity: Some(value.quantity),
+            price: Some(value.price),
+            source_adjustment_id: value.source_adjustment_id,
+        }
+    }
+}
+
+impl TryFrom<ListPortfolioPayoutsPayoutsSuccess> for PayoutUpdateParameters {
+    type Error = IntoParametersError;
+
+    fn try_from(value: ListPortfolioPayoutsPayoutsSuccess) -> Result<Self, Self::Error> {
+        Ok(PayoutUpdateParameters {
+            id: value.id.ok_or(IntoParametersError::Missing("id"))?,
+            payout: PayoutUpdatePayoutParameters {
+                paid_on: value.paid_on,
+                goes_ex_on: value.ex_date,
+                resident_withholding_tax: value.resident_withholding_tax,
+                non_resident_withholding_tax: value.non_resident_withholding_tax,
+                tax_credit: value.tax_credit,
+                exchange_rate: Some(value.exchange_rate),
+                amount: Some(value.amount),
+                adjustment_id: None,
+                comments: Some(value.comments),
+                non_taxable: Some(value.non_taxable),
+                currency_code: Some(value.currency),
+                source_payment_date: None,
+                send_to_xero: None,
+                banked_amount: None,
+                source_adjustment_id: None,
+                drp_trade_attributes: value.drp_trade_attributes.map(|v| v.into()),
+                franked_amount: value.franked_amount,
+                unfranked_amount: value.unfranked_amount,
+                trust: value.trust,
+                extra_interest_payment_amount: value.extra_interest_payment_amount,
+                capital_gains: value.capital_gains,
+                discounted_capital_gains: value.discounted_capital_gains,
+                foreign_source_income: value.foreign_source_income,
+                lic_capital_gain: None,
+                non_assessable: value.non_assessable,
+                deferred_income: value.deferred_income,
+                cgt_concession_amount: None,
+                amit_decrease_amount: value.amit_decrease_amount,
+                amit_increase_amount: value.amit_increase_amount,
+            },
+        })
+    }
+}
+
+impl From<ListHoldingPayoutsPayoutsDrpTradeAttributesSuccess>
+    for PayoutUpdatePayoutDrpTradeAttributesParameters
+{
+    fn from(value: ListHoldingPayoutsPayoutsDrpTradeAttributesSuccess) -> Self {
+        PayoutUpdatePayoutDrpTradeAttributesParameters {
+            dividend_reinvested: Some(value.dividend_reinvested),
+            quantity: Some(value.quantity),
+            price: Some(value.price),
+            source_adjustment_id: value.source_adjustment_id,
+        }
+    }
+}
+
+impl TryFrom<ListHoldingPayoutsPayoutsSuccess> for PayoutUpdateParameters {
+    type Error = IntoParametersError;
+
+    fn try_from(value: ListHoldingPayoutsPayoutsSuccess) -> Result<Self, Self::Error> {
+        Ok(PayoutUpdateParameters {
+            id: value.id.ok_or(IntoParametersError::Missing("id"))?,
+            payout: PayoutUpdatePayoutParameters {
+                paid_on: value.paid_on,
+                goes_ex_on: value.ex_date,
+                resident_withholding_tax: value.resident_withholding_tax,
+                non_resident_withholding_tax: value.non_resident_withholding_tax,
+                tax_credit: value.tax_credit,
+                exchange_rate: Some(value.exchange_rate),
+                amount: Some(value.amount),
+                adjustment_id: None,
+                comments: Some(value.comments),
+                non_taxable: Some(value.non_taxable),
+                currency_code: Some(value.currency),
+                source_payment_date: None,
+                send_to_xero: None,
+                banked_amount: None,
+                source_adjustment_id: None,
+                drp_trade_attributes: value.drp_trade_attributes.map(|v| v.into()),
+                franked_amount: value.franked_amount,
+                unfranked_amount: value.unfranked_amount,
+                trust: value.trust,
+                extra_interest_payment_amount: value.extra_interest_payment_amount,
+                capital_gains: value.capital_gains,
+                discounted_capital_gains: value.discounted_capital_gains,
+                foreign_source_income: value.foreign_source_income,
+                lic_capital_gain: Some(value.lic_capital_gain),
+                non_assessable: value.non_assessable,
+                deferred_income: value.deferred_income,
+                cgt_concession_amount: value.cgt_concession_amount,
+                amit_decrease_amount: value.amit_decrease_amount,
+                amit_increase_amount: value.amit_increase_amount,
+            },
+        })
+    }
+}
+
+impl From<Payout> for PayoutUpdateParameters {
+    fn from(value: Payout) -> Self {
+        PayoutUpdateParameters {
+            id: value.id,
+            payout: PayoutUpdatePayoutParameters {
+                paid_on: value.paid_on,
+                goes_ex_on: value.ex_date,
+                resident_withholding_tax: value.resident_withholding_tax,
+                non_resident_withholding_tax: value.non_resident_withholding_tax,
+                tax_credit: value.tax_credit,
+                exchange_rate: Some(value.exchange_rate),
+                amount: Some(value.amount),
+                adjustment_id: None,
+                comments: Some(value.comments),
+                non_taxable: Some(value.non_taxable),
+                currency_code: Some(value.currency),
+                source_payment_date: None,
+                send_to_xero: None,
+                banked_amount: None,
+                source_adjustment_id: None,
+                drp_trade_attributes: None,
+                franked_amount: None,
+                unfranked_amount: None,
+                trust: None,
+                extra_interest_payment_amount: None,
+                capital_gains: None,
+                discounted_capital_gains: None,
+                foreign_source_income: None,
+                lic_capital_gain: None,
+                non_assessable: None,
+                deferred_income: None,
+                cgt_concession_amount: None,
+                amit_decrease_amount: None,
+                amit_increase_amount: None,
+            },
+        }
+    }
+}
+
+impl From<CashAccountShowSuccess> for CashAccountUpdateParameters {
+    fn from(value: CashAccountShowSuccess) -> Self {
+        CashAccountUpdateParameters {
+            id: value.id,
+            name: value.name,
+            currency: value.currency,
+        }
+    }
+}
+
+impl From<CashAccount> for CashAccountUpdateParameters {
+    fn from(value: CashAccount) -> Self {
+        CashAccountUpdateParameters {
+            id: value.id,
+            name: value.name,
+            currency: value.currency,
+        }
+    }
+}
+
+impl Validate for HoldingMergesCreateParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if matches!(self.unique_identifier, Some(ref v) if v.chars().count() > 255) {
+            return Err(ValidationError::TooLong("unique_identifier", 255));
+        }
+        if self.attachment.is_some() && self.attachment_filename.is_none() {
+            return Err(ValidationError::Missing("attachment_filename"));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for HoldingMergesUpdateParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if matches!(self.unique_identifier, Some(ref v) if v.chars().count() > 255) {
+            return Err(ValidationError::TooLong("unique_identifier", 255));
+        }
+        if self.attachment.is_some() && self.attachment_filename.is_none() {
+            return Err(ValidationError::Missing("attachment_filename"));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for PayoutCreatePayoutParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.amount < Number::default() {
+            return Err(ValidationError::Negative("amount"));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for PayoutCreateParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.payout.validate()?;
+        Ok(())
+    }
+}
+
+impl Validate for PayoutUpdatePayoutParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if matches!(self.amount, Some(ref v) if *v < Number::default()) {
+            return Err(ValidationError::Negative("amount"));
+        }
+        Ok(())
+    }
 }
+
+impl Validate for PayoutUpdateParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.payout.validate()?;
+        Ok(())
+    }
+}
+
+impl Validate for TradesCreateTradeParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if matches!(self.unique_identifier, Some(ref v) if v.chars().count() > 255) {
+            return Err(ValidationError::TooLong("unique_identifier", 255));
+        }
+        if matches!(self.price, Some(ref v) if *v < Number::default()) {
+            return Err(ValidationError::Negative("price"));
+        }
+        if matches!(self.brokerage, Some(ref v) if *v < Number::default()) {
+            return Err(ValidationError::Negative("brokerage"));
+        }
+        if self.symbol.is_some() && self.market.is_none() {
+            return Err(ValidationError::Missing("market"));
+        }
+        if self.market.is_some() && self.symbol.is_none() {
+            return Err(ValidationError::Missing("symbol"));
+        }
+        if self.market_country_code.is_some() && self.symbol.is_none() {
+            return Err(ValidationError::Missing("symbol"));
+        }
+        if self.attachment.is_some() && self.attachment_filename.is_none() {
+            return Err(ValidationError::Missing("attachment_filename"));
+        }
+        if self.holding_id.is_none()
+            && self.instrument_id.is_none()
+            && self.symbol.is_none()
+            && self.market.is_none()
+        {
+            return Err(ValidationError::MissingOneOf(&[
+                "holding_id",
+                "instrument_id",
+                "symbol",
+                "market",
+            ]));
+        }
+        Ok(())
+    }
+
+    fn validate_quantity(&self, decimal_markets: &[Market]) -> Result<(), ValidationError> {
+        if let (Some(market), Some(v)) = (self.market.as_ref(), self.quantity.as_ref()) {
+            if !is_whole(v) && !decimal_markets.contains(market) {
+                return Err(ValidationError::NotWhole("quantity"));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_brokerage_currency(&self, currencies: &[Currency]) -> Result<(), ValidationError> {
+        if matches!(self.brokerage_currency_code, Some(ref v) if !currencies.contains(v)) {
+            return Err(ValidationError::Currency("brokerage_currency_code"));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for TradesCreateParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.trade.validate()?;
+        Ok(())
+    }
+
+    fn validate_quantity(&self, decimal_markets: &[Market]) -> Result<(), ValidationError> {
+        self.trade.validate_quantity(decimal_markets)?;
+        Ok(())
+    }
+
+    fn validate_brokerage_currency(&self, currencies: &[Currency]) -> Result<(), ValidationError> {
+        self.trade.validate_brokerage_currency(currencies)?;
+        Ok(())
+    }
+}
+
+impl Validate for TradesUpdateTradeParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if matches!(self.price, Some(ref v) if *v < Number::default()) {
+            return Err(ValidationError::Negative("price"));
+        }
+        if matches!(self.brokerage, Some(ref v) if *v < Number::default()) {
+            return Err(ValidationError::Negative("brokerage"));
+        }
+        if self.symbol.is_some() && self.market.is_none() {
+            return Err(ValidationError::Missing("market"));
+        }
+        if self.market.is_some() && self.symbol.is_none() {
+            return Err(ValidationError::Missing("symbol"));
+        }
+        if self.market_country_code.is_some() && self.symbol.is_none() {
+            return Err(ValidationError::Missing("symbol"));
+        }
+        if self.attachment.is_some() && self.attachment_filename.is_none() {
+            return Err(ValidationError::Missing("attachment_filename"));
+        }
+        Ok(())
+    }
+
+    fn validate_quantity(&self, decimal_markets: &[Market]) -> Result<(), ValidationError> {
+        if let (Some(market), Some(v)) = (self.market.as_ref(), self.quantity.as_ref()) {
+            if !is_whole(v) && !decimal_markets.contains(market) {
+                return Err(ValidationError::NotWhole("quantity"));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_brokerage_currency(&self, currencies: &[Currency]) -> Result<(), ValidationError> {
+        if matches!(self.brokerage_currency_code, Some(ref v) if !currencies.contains(v)) {
+            return Err(ValidationError::Currency("brokerage_currency_code"));
+        }
+        Ok(())
+    }
+}
+
+impl Validate for TradesUpdateParameters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.trade.validate()?;
+        Ok(())
+    }
+
+    fn validate_quantity(&self, decimal_markets: &[Market]) -> Result<(), ValidationError> {
+        self.trade.validate_quantity(decimal_markets)?;
+        Ok(())
+    }
+
+    fn validate_brokerage_currency(&self, currencies: &[Currency]) -> Result<(), ValidationError> {
+        self.trade.validate_brokerage_currency(currencies)?;
+        Ok(())
+    }
+}
+
+pub const API_ENDPOINTS: &[EndpointManifest] = &[
+    EndpointManifest {
+        name: "CashAccountCreate",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/portfolios/:portfolio_id/cash_accounts.json",
+        parameters: &[
+            ParameterManifest {
+                field: "portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "cash_account",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "cash_account.name",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "cash_account.currency",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "CashAccountDelete",
+        http_method: ApiHttpMethod::Delete,
+        url_path: "/cash_accounts/:id.json",
+        parameters: &[ParameterManifest {
+            field: "id",
+            kind: ParameterKind::Integer,
+            array: false,
+            optional: false,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "CashAccountShow",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/cash_accounts/:id.json",
+        parameters: &[
+            ParameterManifest {
+                field: "id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "CashAccountTransactionCreate",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/cash_accounts/:cash_account_id/cash_account_transactions.json",
+        parameters: &[
+            ParameterManifest {
+                field: "cash_account_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "description",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "type_name",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "date_time",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "foreign_identifier",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "CashAccountTransactionDelete",
+        http_method: ApiHttpMethod::Delete,
+        url_path: "/cash_account_transactions/:id.json",
+        parameters: &[ParameterManifest {
+            field: "id",
+            kind: ParameterKind::Integer,
+            array: false,
+            optional: false,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "CashAccountTransactionUpdate",
+        http_method: ApiHttpMethod::Put,
+        url_path: "/cash_account_transactions/:id.json",
+        parameters: &[
+            ParameterManifest {
+                field: "id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "description",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "type_name",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "date_time",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "foreign_identifier",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "CashAccountTransactionsList",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/cash_accounts/:cash_account_id/cash_account_transactions.json",
+        parameters: &[
+            ParameterManifest {
+                field: "cash_account_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "from",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "to",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "description",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "foreign_identifier",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "CashAccountUpdate",
+        http_method: ApiHttpMethod::Put,
+        url_path: "/cash_accounts/:id.json",
+        parameters: &[
+            ParameterManifest {
+                field: "id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "name",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "currency",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "CashAccountsList",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/cash_accounts.json",
+        parameters: &[ParameterManifest {
+            field: "date",
+            kind: ParameterKind::Date,
+            array: false,
+            optional: true,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "DocumentShow",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/documents/:id.json",
+        parameters: &[ParameterManifest {
+            field: "id",
+            kind: ParameterKind::Integer,
+            array: false,
+            optional: false,
+        }],
+        file: true,
+    },
+    EndpointManifest {
+        name: "GroupsList",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/groups.json",
+        parameters: &[],
+        file: false,
+    },
+    EndpointManifest {
+        name: "HoldingMergesCreate",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/portfolios/:portfolio_id/holding_merges.json",
+        parameters: &[
+            ParameterManifest {
+                field: "portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "holding_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "merge_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "quantity",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "symbol",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "market",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "cancelled_price",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "comments",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "unique_identifier",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "attachment",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "attachment_filename",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "HoldingMergesUpdate",
+        http_method: ApiHttpMethod::Put,
+        url_path: "/portfolios/:portfolio_id/holding_merges/:id.json",
+        parameters: &[
+            ParameterManifest {
+                field: "portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "merge_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "quantity",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "symbol",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "market",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "cancelled_price",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "comments",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "unique_identifier",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "attachment",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "attachment_filename",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "HoldingTrades",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/holdings/:holding_id/trades.json",
+        parameters: &[
+            ParameterManifest {
+                field: "holding_id",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "unique_identifier",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "HoldingTradesRejected",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/holdings/:holding_id/rejected_trades.json",
+        parameters: &[ParameterManifest {
+            field: "holding_id",
+            kind: ParameterKind::String,
+            array: false,
+            optional: false,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "IdentityByToken",
+        http_method: ApiHttpMethod::Get,
+        url_path: ".1-mobile/identity/by_token.json",
+        parameters: &[
+            ParameterManifest {
+                field: "id_token",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "client_id",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "IdentitySignupByToken",
+        http_method: ApiHttpMethod::Get,
+        url_path: ".1-mobile/identity/signup_by_token.json",
+        parameters: &[
+            ParameterManifest {
+                field: "id_token",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "client_id",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "country_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "ListUserInstruments",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/user_instruments.json",
+        parameters: &[],
+        file: false,
+    },
+    EndpointManifest {
+        name: "MembershipCreate",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/memberships.json",
+        parameters: &[
+            ParameterManifest {
+                field: "membership",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "membership.portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "membership.access_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "membership.user_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "user",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "user.email",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "user.first_name",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "user.last_name",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "invitation",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "invitation.text",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "invitation.no_email",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "MembershipDelete",
+        http_method: ApiHttpMethod::Delete,
+        url_path: "/memberships/:id.json",
+        parameters: &[ParameterManifest {
+            field: "id",
+            kind: ParameterKind::Integer,
+            array: false,
+            optional: false,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "MembershipList",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/memberships.json",
+        parameters: &[],
+        file: false,
+    },
+    EndpointManifest {
+        name: "MembershipUpdate",
+        http_method: ApiHttpMethod::Put,
+        url_path: "/memberships/:id.json",
+        parameters: &[
+            ParameterManifest {
+                field: "id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "access_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "Currencies",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/currencies.json",
+        parameters: &[],
+        file: false,
+    },
+    EndpointManifest {
+        name: "ListHoldingPayouts",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/holdings/:holding_id/payouts.json",
+        parameters: &[
+            ParameterManifest {
+                field: "holding_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "start_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "end_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "use_date",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "ListPortfolioPayouts",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/portfolios/:portfolio_id/payouts.json",
+        parameters: &[
+            ParameterManifest {
+                field: "portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "start_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "end_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "use_date",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PayoutConfirm",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/payouts.json",
+        parameters: &[
+            ParameterManifest {
+                field: "payout.holding_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout.company_event_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout.paid_on",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout.state",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.dividend_reinvested",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.quantity",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.price",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.source_adjustment_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PayoutCreate",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/payouts",
+        parameters: &[
+            ParameterManifest {
+                field: "payout",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout.portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.holding_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.symbol",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.market",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.paid_on",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout.amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout.currency_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout.goes_ex_on",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.resident_withholding_tax",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.non_resident_withholding_tax",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.tax_credit",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.exchange_rate",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.adjustment_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.comments",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.non_taxable",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.source_payment_date",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.send_to_xero",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.banked_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.dividend_reinvested",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.quantity",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.price",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.source_adjustment_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.franked_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.unfranked_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.trust",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.extra_interest_payment_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.capital_gains",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.discounted_capital_gains",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.foreign_source_income",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.lic_capital_gain",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.non_assessable",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.deferred_income",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.cgt_concession_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.amit_decrease_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.amit_increase_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.file_name",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.file_attachment",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PayoutDelete",
+        http_method: ApiHttpMethod::Delete,
+        url_path: "/payouts/:id.json",
+        parameters: &[ParameterManifest {
+            field: "id",
+            kind: ParameterKind::Integer,
+            array: false,
+            optional: false,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PayoutReject",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/payouts.json",
+        parameters: &[
+            ParameterManifest {
+                field: "holding_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "company_event_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "state",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PayoutShow",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/payouts/:id.json",
+        parameters: &[ParameterManifest {
+            field: "id",
+            kind: ParameterKind::Integer,
+            array: false,
+            optional: false,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PayoutUpdate",
+        http_method: ApiHttpMethod::Put,
+        url_path: "/payouts/:id.json",
+        parameters: &[
+            ParameterManifest {
+                field: "id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout.paid_on",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "payout.goes_ex_on",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.resident_withholding_tax",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.non_resident_withholding_tax",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.tax_credit",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.exchange_rate",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.adjustment_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.comments",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.non_taxable",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.currency_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.source_payment_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.send_to_xero",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.banked_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.source_adjustment_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.dividend_reinvested",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.quantity",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.price",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.drp_trade_attributes.source_adjustment_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.franked_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.unfranked_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.trust",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.extra_interest_payment_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.capital_gains",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.discounted_capital_gains",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.foreign_source_income",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.lic_capital_gain",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.non_assessable",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.deferred_income",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.cgt_concession_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.amit_decrease_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "payout.amit_increase_amount",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PortfolioCreate",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/portfolios.json",
+        parameters: &[
+            ParameterManifest {
+                field: "portfolio",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.name",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.broker_email_api_enabled",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.financial_year_end_month_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.financial_year_end",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.default_sale_allocation_method",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.interest_method",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.trader",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.tax_entity_type",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.disable_automatic_transactions",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.cg_discount_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.rwtr_rate",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.country_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.apply_cash_account_adjustments",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.buy_trade_settlement_delay",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.sell_trade_settlement_delay",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.account_for_delayed_cash_transactions",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.external_identifier",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PortfolioDelete",
+        http_method: ApiHttpMethod::Delete,
+        url_path: "/portfolios/:id.json",
+        parameters: &[ParameterManifest {
+            field: "id",
+            kind: ParameterKind::Integer,
+            array: false,
+            optional: false,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PortfolioList",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/portfolios.json",
+        parameters: &[],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PortfolioShow",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/portfolios/:id.json",
+        parameters: &[ParameterManifest {
+            field: "id",
+            kind: ParameterKind::Integer,
+            array: false,
+            optional: false,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "PortfolioUpdate",
+        http_method: ApiHttpMethod::Put,
+        url_path: "/portfolios/:id.json",
+        parameters: &[
+            ParameterManifest {
+                field: "portfolio",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.name",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.broker_email_api_enabled",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.financial_year_end_month_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.financial_year_end",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.default_sale_allocation_method",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.interest_method",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.trader",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.tax_entity_type",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.disable_automatic_transactions",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.cg_discount_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.rwtr_rate",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "portfolio.country_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.apply_cash_account_adjustments",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.buy_trade_settlement_delay",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.sell_trade_settlement_delay",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.account_for_delayed_cash_transactions",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.trade_sync_cash_account_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.payout_sync_cash_account_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio.external_identifier",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "CapitalGains",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/portfolios/:portfolio_id/capital_gains.json",
+        parameters: &[
+            ParameterManifest {
+                field: "portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "start_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "end_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "Diversity",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/portfolios/:portfolio_id/diversity.json",
+        parameters: &[
+            ParameterManifest {
+                field: "portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "consolidated",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "grouping",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "custom_group_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "Performance",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/portfolios/:portfolio_id/performance.json",
+        parameters: &[
+            ParameterManifest {
+                field: "start_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "end_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "consolidated",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "include_sales",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "grouping",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "custom_group_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "UnrealisedCgt",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/portfolios/:portfolio_id/unrealised_cgt.json",
+        parameters: &[
+            ParameterManifest {
+                field: "portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "balance_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "Valuation",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/portfolios/:portfolio_id/valuation.json",
+        parameters: &[
+            ParameterManifest {
+                field: "balance_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "consolidated",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "include_sales",
+                kind: ParameterKind::Boolean,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "grouping",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "custom_group_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "RequestSingleSignOn",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/single_sign_on.json",
+        parameters: &[],
+        file: false,
+    },
+    EndpointManifest {
+        name: "TradeConfirm",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/trades.json",
+        parameters: &[
+            ParameterManifest {
+                field: "holding_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "company_event_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "transaction_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "state",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "TradeReject",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/trades.json",
+        parameters: &[
+            ParameterManifest {
+                field: "holding_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "company_event_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "transaction_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "state",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "Trades",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/portfolios/:portfolio_id/trades.json",
+        parameters: &[
+            ParameterManifest {
+                field: "portfolio_id",
+                kind: ParameterKind::String,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "start_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "end_date",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "unique_identifier",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "TradesCreate",
+        http_method: ApiHttpMethod::Post,
+        url_path: "/trades.json",
+        parameters: &[
+            ParameterManifest {
+                field: "trade",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "trade.portfolio_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.holding_id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.unique_identifier",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.company_event_id",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.state",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.transaction_date",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.quantity",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.price",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.cost_base",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.exchange_rate",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.brokerage",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.brokerage_currency_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.adjust_cost_base_value",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.capital_return_value",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.paid_on",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.comments",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.instrument_id",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.symbol",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.market",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.market_country_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.transaction_type",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.attachment",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.attachment_filename",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "TradesDestroy",
+        http_method: ApiHttpMethod::Delete,
+        url_path: "/trades/:id.json",
+        parameters: &[ParameterManifest {
+            field: "id",
+            kind: ParameterKind::Integer,
+            array: false,
+            optional: false,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "TradesShow",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/trades/:id.json",
+        parameters: &[ParameterManifest {
+            field: "id",
+            kind: ParameterKind::String,
+            array: false,
+            optional: false,
+        }],
+        file: false,
+    },
+    EndpointManifest {
+        name: "TradesUpdate",
+        http_method: ApiHttpMethod::Put,
+        url_path: "/trades/:id.json",
+        parameters: &[
+            ParameterManifest {
+                field: "id",
+                kind: ParameterKind::Integer,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "trade",
+                kind: ParameterKind::Object,
+                array: false,
+                optional: false,
+            },
+            ParameterManifest {
+                field: "trade.transaction_date",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.quantity",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.price",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.cost_base",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.exchange_rate",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.brokerage",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.brokerage_currency_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.adjust_cost_base_value",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.capital_return_value",
+                kind: ParameterKind::Number,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.paid_on",
+                kind: ParameterKind::Date,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.comments",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.instrument_id",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.symbol",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.market",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.market_country_code",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.transaction_type",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.attachment",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+            ParameterManifest {
+                field: "trade.attachment_filename",
+                kind: ParameterKind::String,
+                array: false,
+                optional: true,
+            },
+        ],
+        file: false,
+    },
+    EndpointManifest {
+        name: "MyUser",
+        http_method: ApiHttpMethod::Get,
+        url_path: "/my_user.json",
+        parameters: &[],
+        file: false,
+    },
+];
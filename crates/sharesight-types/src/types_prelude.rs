@@ -1,6 +1,8 @@
 use serde_with::{DeserializeAs, SerializeAs};
 
+pub use std::convert::TryFrom;
 pub use std::fmt;
+use std::num::ParseIntError;
 
 pub use chrono::{DateTime, FixedOffset, NaiveDate};
 pub use serde::de::DeserializeOwned;
@@ -8,6 +10,7 @@ pub use serde::{Deserialize, Serialize};
 pub use serde_with::{serde_as, DefaultOnNull, DisplayFromStr, PickFirst};
 
 pub use crate::codes::*;
+pub use crate::manifest::{EndpointManifest, ParameterKind, ParameterManifest};
 
 #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
 pub type Number = rust_decimal::Decimal;
@@ -20,6 +23,7 @@ compile_error!(
     "sharesight: Features rust_decimal and bigdecimal are mutually exclusive. Pick one."
 );
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ApiHttpMethod {
     Get,
     Post,
@@ -40,8 +44,40 @@ pub trait ApiEndpoint<'a> {
     fn url(api_host: &'a str, parameters: &'a Self::Parameters) -> ApiUrl<'a, Self> {
         ApiUrl(api_host, parameters)
     }
+
+    /// The URL of the endpoint under a base URL such as `https://api.sharesight.com/api/v2`.
+    fn url_with_base(base_url: &'a str, parameters: &'a Self::Parameters) -> ApiBaseUrl<'a, Self> {
+        ApiBaseUrl(base_url, parameters)
+    }
+
+    /// Check the parameters for mistakes the API would reject, before they are sent.
+    fn validate(_parameters: &Self::Parameters) -> Result<(), ValidationError> {
+        Ok(())
+    }
+
+    /// Check that the quantities of the parameters are whole numbers, unless their market is one
+    /// of the markets allowing decimal quantities.
+    fn validate_quantity(
+        _parameters: &Self::Parameters,
+        _decimal_markets: &[Market],
+    ) -> Result<(), ValidationError> {
+        Ok(())
+    }
+
+    /// Check that the brokerage currencies of the parameters are one of `currencies`, the
+    /// currencies of the portfolio and of the instrument.
+    fn validate_brokerage_currency(
+        _parameters: &Self::Parameters,
+        _currencies: &[Currency],
+    ) -> Result<(), ValidationError> {
+        Ok(())
+    }
 }
 
+/// An endpoint that returns a file rather than JSON, so its response is downloaded instead of
+/// deserialized.
+pub trait ApiFileEndpoint<'a>: ApiEndpoint<'a> {}
+
 pub struct ApiUrl<'a, T: ApiEndpoint<'a> + ?Sized>(&'a str, &'a T::Parameters);
 
 impl<'a, T: ApiEndpoint<'a>> fmt::Display for ApiUrl<'a, T> {
@@ -52,6 +88,21 @@ impl<'a, T: ApiEndpoint<'a>> fmt::Display for ApiUrl<'a, T> {
     }
 }
 
+pub struct ApiBaseUrl<'a, T: ApiEndpoint<'a> + ?Sized>(&'a str, &'a T::Parameters);
+
+impl<'a, T: ApiEndpoint<'a>> fmt::Display for ApiBaseUrl<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(base_url, parameters) = self;
+
+        write!(
+            f,
+            "{}{}",
+            base_url.trim_end_matches('/'),
+            T::url_path(parameters)
+        )
+    }
+}
+
 pub struct DeserializeDate;
 
 impl<'de> DeserializeAs<'de, NaiveDate> for DeserializeDate {
@@ -75,21 +126,124 @@ impl<T: serde::Serialize> SerializeAs<T> for DeserializeDate {
     }
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize)]
-pub struct Auth {
-    access_token: String,
-    expires_in: u32,
-    refresh_token: Option<String>,
-    created_at: i64,
+/// An error converting a read model into the parameters of an update.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IntoParametersError {
+    /// A field required by the parameters was not returned in the read model.
+    Missing(&'static str),
+    /// A field of the read model could not be parsed into the type the parameters require.
+    Invalid(&'static str, ParseIntError),
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize)]
-pub struct AuthWithHost {
-    #[serde(flatten)]
-    auth: Auth,
-    host: String,
+impl fmt::Display for IntoParametersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntoParametersError::Missing(field) => write!(f, "Field {} is missing", field),
+            IntoParametersError::Invalid(field, e) => {
+                write!(f, "Field {} is invalid: {}", field, e)
+            }
+        }
+    }
 }
 
+impl std::error::Error for IntoParametersError {}
+
+/// The value for a parameter that only applies to some types of trade, such as the
+/// `capital_return_value` of a capital return, taken from the `value` of the trade.
+pub(crate) fn value_for_types<T: Clone>(
+    transaction_type: TradeDescription,
+    types: &[TradeDescription],
+    value: &T,
+) -> Option<T> {
+    if types.contains(&transaction_type) {
+        Some(value.clone())
+    } else {
+        None
+    }
+}
+
+/// Parameters that can be checked locally using the rules in the API documentation.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationError>;
+
+    /// Check that the quantities are whole numbers unless their market is one of the markets
+    /// allowing decimal quantities. The API only gives which markets these are at run time, in
+    /// the `allow_decimal_quantities` field of its market list.
+    fn validate_quantity(&self, _decimal_markets: &[Market]) -> Result<(), ValidationError> {
+        Ok(())
+    }
+
+    /// Check that the brokerage currencies are one of `currencies`, which should be the
+    /// currencies of the portfolio and of the instrument. The parameters only identify the
+    /// instrument, so the caller has to look these up. Don't use this for a cryptocurrency, which
+    /// the API allows any brokerage currency for.
+    fn validate_brokerage_currency(&self, _currencies: &[Currency]) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+/// Whether the number has no fractional part.
+#[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
+pub(crate) fn is_whole(number: &Number) -> bool {
+    number.fract().is_zero()
+}
+#[cfg(all(feature = "bigdecimal", not(feature = "rust_decimal")))]
+pub(crate) fn is_whole(number: &Number) -> bool {
+    number.is_integer()
+}
+#[cfg(all(not(feature = "bigdecimal"), not(feature = "rust_decimal")))]
+pub(crate) fn is_whole(number: &Number) -> bool {
+    number.fract() == 0.0
+}
+
+/// Parameters that the API would reject.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The field is required by another field that is set.
+    Missing(&'static str),
+    /// At least one of the fields must be set.
+    MissingOneOf(&'static [&'static str]),
+    /// The field must not be empty.
+    Empty(&'static str),
+    /// The field must have at most the given number of characters.
+    TooLong(&'static str, usize),
+    /// The field must not be less than zero.
+    Negative(&'static str),
+    /// The field must be greater than zero.
+    NotPositive(&'static str),
+    /// The field must be a whole number, as its market doesn't allow decimal quantities.
+    NotWhole(&'static str),
+    /// The field must be the currency of the portfolio or of the instrument.
+    Currency(&'static str),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Missing(field) => write!(f, "Field {} is missing", field),
+            ValidationError::MissingOneOf(fields) => {
+                write!(f, "One of the fields {} must be set", fields.join(", "))
+            }
+            ValidationError::Empty(field) => write!(f, "Field {} must not be empty", field),
+            ValidationError::TooLong(field, max) => {
+                write!(f, "Field {} must be at most {} characters", field, max)
+            }
+            ValidationError::Negative(field) => write!(f, "Field {} must not be negative", field),
+            ValidationError::NotPositive(field) => write!(f, "Field {} must be positive", field),
+            ValidationError::NotWhole(field) => {
+                write!(f, "Field {} must be a whole number on its market", field)
+            }
+            ValidationError::Currency(field) => write!(
+                f,
+                "Field {} must be the portfolio or instrument currency",
+                field
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(untagged)]
 pub enum IdOrName {